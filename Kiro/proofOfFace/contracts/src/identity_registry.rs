@@ -3,8 +3,12 @@
 #[ink::contract]
 pub mod identity_registry {
     use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
 
+    /// Highest reputation score an identity can reach, as `MaxReputation` in the pallet
+    pub const MAX_REPUTATION: u32 = 1_000;
+
     #[derive(Debug, PartialEq, Eq, Clone)]
     #[ink::scale_derive(Encode, Decode, TypeInfo)]
     #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
@@ -15,24 +19,101 @@ pub mod identity_registry {
         pub reputation_score: u32,
     }
 
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+    pub struct DisputeData {
+        pub creator: AccountId,
+        pub target: AccountId,
+        pub evidence: String,
+        pub timestamp: u64,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum Error {
+        /// The registry already holds `max_identities` identities
+        MaxIdentitiesReached,
+        /// Only the registry's owner may do this
+        NotOwner,
+        /// The account has no registered identity
+        IdentityNotFound,
+        /// An account cannot dispute its own identity
+        CannotDisputeSelf,
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// The owner changed an identity's reputation
+    #[ink(event)]
+    pub struct ReputationAdjusted {
+        #[ink(topic)]
+        account: AccountId,
+        delta: i32,
+        new_score: u32,
+    }
+
+    /// A dispute was raised against an identity
+    #[ink(event)]
+    pub struct DisputeRaised {
+        #[ink(topic)]
+        dispute_id: u64,
+        #[ink(topic)]
+        target: AccountId,
+        creator: AccountId,
+    }
+
+    /// The registry's owner handed ownership to another account
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
     #[ink(storage)]
     pub struct IdentityRegistry {
         identities: Mapping<AccountId, IdentityData>,
         total_identities: u64,
+        max_identities: u64,
+        verified_count: u64,
+        owner: AccountId,
+        disputes: Mapping<u64, DisputeData>,
+        next_dispute_id: u64,
     }
 
     impl IdentityRegistry {
+        /// Create a registry that accepts at most `max_identities` registrations
+        ///
+        /// The caller becomes the owner, who alone may mark identities verified and can hand
+        /// ownership on with `transfer_ownership`.
         #[ink(constructor)]
-        pub fn new() -> Self {
+        pub fn new(max_identities: u64) -> Self {
             Self {
                 identities: Mapping::default(),
                 total_identities: 0,
+                max_identities,
+                verified_count: 0,
+                owner: Self::env().caller(),
+                disputes: Mapping::default(),
+                next_dispute_id: 0,
             }
         }
 
         #[ink(message)]
-        pub fn register_identity(&mut self, ipfs_hash: String) {
+        pub fn register_identity(&mut self, ipfs_hash: String) -> Result<()> {
             let caller = self.env().caller();
+            // Re-registering replaces the caller's identity, so only new ones count
+            let existing = self.identities.get(caller);
+            if existing.is_none() && self.total_identities >= self.max_identities {
+                return Err(Error::MaxIdentitiesReached);
+            }
+
+            // Registering again starts over unverified
+            if existing.as_ref().map_or(false, |identity| identity.verified) {
+                self.verified_count = self.verified_count.saturating_sub(1);
+            }
             let identity_data = IdentityData {
                 ipfs_hash,
                 timestamp: self.env().block_timestamp(),
@@ -40,12 +121,441 @@ pub mod identity_registry {
                 reputation_score: 100,
             };
             self.identities.insert(&caller, &identity_data);
-            self.total_identities += 1;
+            if existing.is_none() {
+                // Below `max_identities`, so this cannot overflow
+                self.total_identities = self.total_identities.saturating_add(1);
+            }
+            Ok(())
+        }
+
+        /// Register identities for many accounts at once (owner only)
+        ///
+        /// Accounts that already have an identity are skipped, and insertion stops once
+        /// `max_identities` is reached. Returns the number of identities inserted.
+        #[ink(message)]
+        pub fn register_batch(&mut self, entries: Vec<(AccountId, String)>) -> Result<u32> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+
+            let timestamp = self.env().block_timestamp();
+            let mut inserted: u32 = 0;
+            for (account, ipfs_hash) in entries {
+                if self.total_identities >= self.max_identities {
+                    break;
+                }
+                if self.identities.contains(account) {
+                    continue;
+                }
+                let identity_data = IdentityData {
+                    ipfs_hash,
+                    timestamp,
+                    verified: false,
+                    reputation_score: 100,
+                };
+                self.identities.insert(account, &identity_data);
+                self.total_identities = self.total_identities.saturating_add(1);
+                inserted = inserted.saturating_add(1);
+            }
+            Ok(inserted)
+        }
+
+        /// Mark `account`'s identity verified or unverified (owner only)
+        #[ink(message)]
+        pub fn set_verified(&mut self, account: AccountId, verified: bool) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let mut identity = self.identities.get(account).ok_or(Error::IdentityNotFound)?;
+
+            if identity.verified != verified {
+                self.verified_count = if verified {
+                    self.verified_count.saturating_add(1)
+                } else {
+                    self.verified_count.saturating_sub(1)
+                };
+                identity.verified = verified;
+                self.identities.insert(account, &identity);
+            }
+            Ok(())
+        }
+
+        /// Change `account`'s reputation by `delta` (owner only)
+        ///
+        /// The score floors at zero and is clamped to `MAX_REPUTATION`.
+        #[ink(message)]
+        pub fn adjust_reputation(&mut self, account: AccountId, delta: i32) -> Result<u32> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            let mut identity = self.identities.get(account).ok_or(Error::IdentityNotFound)?;
+
+            let score = if delta >= 0 {
+                identity.reputation_score.saturating_add(delta.unsigned_abs())
+            } else {
+                identity.reputation_score.saturating_sub(delta.unsigned_abs())
+            };
+            identity.reputation_score = score.min(MAX_REPUTATION);
+            self.identities.insert(account, &identity);
+
+            self.env().emit_event(ReputationAdjusted {
+                account,
+                delta,
+                new_score: identity.reputation_score,
+            });
+            Ok(identity.reputation_score)
+        }
+
+        /// Raise a dispute against `target`'s identity, returning the new dispute's id
+        #[ink(message)]
+        pub fn raise_dispute(&mut self, target: AccountId, evidence: String) -> Result<u64> {
+            let caller = self.env().caller();
+            if caller == target {
+                return Err(Error::CannotDisputeSelf);
+            }
+            if !self.identities.contains(target) {
+                return Err(Error::IdentityNotFound);
+            }
+
+            let dispute_id = self.next_dispute_id;
+            let dispute = DisputeData {
+                creator: caller,
+                target,
+                evidence,
+                timestamp: self.env().block_timestamp(),
+            };
+            self.disputes.insert(dispute_id, &dispute);
+            self.next_dispute_id = dispute_id.saturating_add(1);
+
+            self.env().emit_event(DisputeRaised {
+                dispute_id,
+                target,
+                creator: caller,
+            });
+            Ok(dispute_id)
+        }
+
+        #[ink(message)]
+        pub fn get_dispute(&self, dispute_id: u64) -> Option<DisputeData> {
+            self.disputes.get(dispute_id)
+        }
+
+        /// Hand ownership of the registry to `new_owner` (owner only)
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::NotOwner);
+            }
+
+            self.owner = new_owner;
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner: caller,
+                new_owner,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
+        }
+
+        /// Remove the caller's identity
+        #[ink(message)]
+        pub fn remove_identity(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            let identity = self.identities.take(caller).ok_or(Error::IdentityNotFound)?;
+
+            if identity.verified {
+                self.verified_count = self.verified_count.saturating_sub(1);
+            }
+            self.total_identities = self.total_identities.saturating_sub(1);
+            Ok(())
         }
 
         #[ink(message)]
         pub fn get_total_identities(&self) -> u64 {
             self.total_identities
         }
+
+        #[ink(message)]
+        pub fn get_verified_count(&self) -> u64 {
+            self.verified_count
+        }
+
+        #[ink(message)]
+        pub fn get_max_identities(&self) -> u64 {
+            self.max_identities
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn register_as(caller: AccountId, registry: &mut IdentityRegistry) -> Result<()> {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+            registry.register_identity(String::from("QmTestHash123456789abcdef"))
+        }
+
+        #[ink::test]
+        fn registration_succeeds_up_to_the_cap() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(2);
+
+            assert_eq!(register_as(accounts.alice, &mut registry), Ok(()));
+            assert_eq!(register_as(accounts.bob, &mut registry), Ok(()));
+            assert_eq!(registry.get_total_identities(), 2);
+        }
+
+        #[ink::test]
+        fn registration_reverts_beyond_the_cap() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(1);
+            assert_eq!(register_as(accounts.alice, &mut registry), Ok(()));
+
+            assert_eq!(
+                register_as(accounts.bob, &mut registry),
+                Err(Error::MaxIdentitiesReached)
+            );
+            assert_eq!(registry.get_total_identities(), 1);
+        }
+
+        #[ink::test]
+        fn re_registration_is_not_counted_or_capped() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(1);
+            assert_eq!(register_as(accounts.alice, &mut registry), Ok(()));
+
+            // At the cap, the existing identity can still be replaced
+            assert_eq!(register_as(accounts.alice, &mut registry), Ok(()));
+            assert_eq!(registry.get_total_identities(), 1);
+        }
+
+        #[ink::test]
+        fn zero_cap_rejects_every_registration() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(0);
+
+            assert_eq!(
+                register_as(accounts.alice, &mut registry),
+                Err(Error::MaxIdentitiesReached)
+            );
+            assert_eq!(registry.get_max_identities(), 0);
+        }
+
+        #[ink::test]
+        fn verified_count_follows_verify_and_unverify() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(register_as(accounts.bob, &mut registry), Ok(()));
+            assert_eq!(register_as(accounts.charlie, &mut registry), Ok(()));
+
+            // Alice deployed the registry and owns it
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(registry.set_verified(accounts.bob, true), Ok(()));
+            assert_eq!(registry.set_verified(accounts.charlie, true), Ok(()));
+            assert_eq!(registry.get_verified_count(), 2);
+
+            // Setting the same flag again does not count twice
+            assert_eq!(registry.set_verified(accounts.bob, true), Ok(()));
+            assert_eq!(registry.get_verified_count(), 2);
+
+            assert_eq!(registry.set_verified(accounts.bob, false), Ok(()));
+            assert_eq!(registry.set_verified(accounts.bob, false), Ok(()));
+            assert_eq!(registry.get_verified_count(), 1);
+
+            assert_eq!(
+                registry.set_verified(accounts.django, true),
+                Err(Error::IdentityNotFound)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(registry.set_verified(accounts.bob, true), Err(Error::NotOwner));
+        }
+
+        #[ink::test]
+        fn removing_identities_never_underflows_the_counts() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(register_as(accounts.bob, &mut registry), Ok(()));
+            assert_eq!(register_as(accounts.charlie, &mut registry), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(registry.set_verified(accounts.bob, true), Ok(()));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(registry.remove_identity(), Ok(()));
+            assert_eq!(registry.get_verified_count(), 1);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(registry.remove_identity(), Ok(()));
+            assert_eq!(registry.get_verified_count(), 0);
+            assert_eq!(registry.get_total_identities(), 0);
+
+            assert_eq!(registry.remove_identity(), Err(Error::IdentityNotFound));
+            assert_eq!(registry.get_verified_count(), 0);
+        }
+
+        #[ink::test]
+        fn owner_transfers_ownership() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(registry.get_owner(), accounts.alice);
+
+            assert_eq!(registry.transfer_ownership(accounts.bob), Ok(()));
+
+            assert_eq!(registry.get_owner(), accounts.bob);
+            assert_eq!(ink::env::test::recorded_events().count(), 1);
+        }
+
+        #[ink::test]
+        fn non_owner_cannot_transfer_ownership() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(registry.transfer_ownership(accounts.bob), Err(Error::NotOwner));
+
+            assert_eq!(registry.get_owner(), accounts.alice);
+            assert_eq!(ink::env::test::recorded_events().count(), 0);
+        }
+
+        #[ink::test]
+        fn admin_calls_follow_the_new_owner() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(register_as(accounts.charlie, &mut registry), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(registry.transfer_ownership(accounts.bob), Ok(()));
+
+            assert_eq!(registry.set_verified(accounts.charlie, true), Err(Error::NotOwner));
+            assert_eq!(registry.transfer_ownership(accounts.alice), Err(Error::NotOwner));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(registry.set_verified(accounts.charlie, true), Ok(()));
+            assert_eq!(registry.get_verified_count(), 1);
+        }
+
+        #[ink::test]
+        fn adjust_reputation_raises_and_lowers_the_score() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(register_as(accounts.bob, &mut registry), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            assert_eq!(registry.adjust_reputation(accounts.bob, 25), Ok(125));
+            assert_eq!(registry.adjust_reputation(accounts.bob, -5), Ok(120));
+            assert_eq!(ink::env::test::recorded_events().count(), 2);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(registry.adjust_reputation(accounts.bob, 1), Err(Error::NotOwner));
+        }
+
+        #[ink::test]
+        fn adjust_reputation_floors_at_zero() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(register_as(accounts.bob, &mut registry), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            assert_eq!(registry.adjust_reputation(accounts.bob, -150), Ok(0));
+            assert_eq!(registry.adjust_reputation(accounts.bob, i32::MIN), Ok(0));
+        }
+
+        #[ink::test]
+        fn adjust_reputation_clamps_at_max() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(register_as(accounts.bob, &mut registry), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            assert_eq!(registry.adjust_reputation(accounts.bob, 5_000), Ok(MAX_REPUTATION));
+            assert_eq!(registry.adjust_reputation(accounts.bob, i32::MAX), Ok(MAX_REPUTATION));
+            assert_eq!(
+                registry.adjust_reputation(accounts.charlie, 1),
+                Err(Error::IdentityNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn raise_dispute_stores_and_returns_dispute() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(register_as(accounts.bob, &mut registry), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+
+            let evidence = String::from("https://evidence.example/1");
+            assert_eq!(registry.raise_dispute(accounts.bob, evidence.clone()), Ok(0));
+            assert_eq!(registry.raise_dispute(accounts.bob, evidence.clone()), Ok(1));
+
+            assert_eq!(
+                registry.get_dispute(0),
+                Some(DisputeData {
+                    creator: accounts.charlie,
+                    target: accounts.bob,
+                    evidence,
+                    timestamp: 0,
+                })
+            );
+            assert_eq!(registry.get_dispute(2), None);
+        }
+
+        #[ink::test]
+        fn raise_dispute_rejects_self_and_unregistered_targets() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(register_as(accounts.bob, &mut registry), Ok(()));
+
+            assert_eq!(
+                registry.raise_dispute(accounts.bob, String::from("evidence")),
+                Err(Error::CannotDisputeSelf)
+            );
+            assert_eq!(
+                registry.raise_dispute(accounts.django, String::from("evidence")),
+                Err(Error::IdentityNotFound)
+            );
+            assert_eq!(registry.get_dispute(0), None);
+        }
+
+        #[ink::test]
+        fn register_batch_skips_existing_accounts() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(10);
+            assert_eq!(register_as(accounts.bob, &mut registry), Ok(()));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let entries = vec![
+                (accounts.bob, String::from("QmBob")),
+                (accounts.charlie, String::from("QmCharlie")),
+                (accounts.django, String::from("QmDjango")),
+            ];
+            assert_eq!(registry.register_batch(entries), Ok(2));
+            assert_eq!(registry.get_total_identities(), 3);
+            assert_eq!(
+                registry.identities.get(accounts.bob).map(|identity| identity.ipfs_hash),
+                Some(String::from("QmTestHash123456789abcdef"))
+            );
+            assert_eq!(
+                registry.identities.get(accounts.charlie).map(|identity| identity.ipfs_hash),
+                Some(String::from("QmCharlie"))
+            );
+        }
+
+        #[ink::test]
+        fn register_batch_is_owner_only_and_capped() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut registry = IdentityRegistry::new(1);
+            let entries = vec![
+                (accounts.charlie, String::from("QmCharlie")),
+                (accounts.django, String::from("QmDjango")),
+            ];
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(registry.register_batch(entries.clone()), Err(Error::NotOwner));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(registry.register_batch(entries), Ok(1));
+            assert_eq!(registry.get_total_identities(), 1);
+        }
     }
-}
\ No newline at end of file
+}
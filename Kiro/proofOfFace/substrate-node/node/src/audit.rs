@@ -0,0 +1,304 @@
+//! The `audit-registrations` subcommand.
+//!
+//! Replays the `IdentityRegistered` events of a block range and checks that each registered
+//! hash is still mapped in the best block's `BiometricHashToOwner`, unless a later event
+//! purged it, re-enrolled its identity under a new hash, or released it along with the
+//! identity it was merged into. Prints a reconciliation report and fails if any hash is
+//! missing.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use codec::Decode;
+use proofofface_runtime::{
+	pallet_proofofface::Event as ProofOfFaceEvent, AccountId, BlockNumber, Hash, RuntimeEvent,
+};
+use sc_cli::{CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::StorageProvider;
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	hashing::{blake2_128, twox_128},
+	hexdisplay::HexDisplay,
+	storage::StorageKey,
+};
+
+use crate::service::FullClient;
+
+/// The `audit-registrations` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct AuditRegistrationsCmd {
+	/// First block whose registrations are audited.
+	#[arg(long, value_name = "BLOCK")]
+	pub from: BlockNumber,
+
+	/// Last block whose registrations are audited.
+	#[arg(long, value_name = "BLOCK")]
+	pub to: BlockNumber,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+/// A registry change relevant to the audit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryChange {
+	/// `account` registered `hash`.
+	Registered(AccountId, Hash),
+	/// `hash` left the registry on purpose, by a purge, a re-enrollment or the purge of the
+	/// identity it was merged into.
+	Removed(Hash),
+}
+
+impl RegistryChange {
+	/// The change recorded by a runtime event, if it is one the audit follows.
+	pub fn from_event(event: RuntimeEvent) -> Option<Self> {
+		match event {
+			RuntimeEvent::ProofOfFace(ProofOfFaceEvent::IdentityRegistered(_, account, hash)) =>
+				Some(Self::Registered(account, hash)),
+			RuntimeEvent::ProofOfFace(ProofOfFaceEvent::IdentityPurged(_, _, hash)) |
+			RuntimeEvent::ProofOfFace(ProofOfFaceEvent::IdentityReenrolled(_, _, hash, _)) |
+			RuntimeEvent::ProofOfFace(ProofOfFaceEvent::MergedHashReleased(_, _, hash)) =>
+				Some(Self::Removed(hash)),
+			_ => None,
+		}
+	}
+}
+
+/// A registration whose hash is no longer in the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingRegistration {
+	/// Block the registration happened in.
+	pub block: BlockNumber,
+	/// Account that registered.
+	pub account: AccountId,
+	/// Registered biometric hash.
+	pub hash: Hash,
+}
+
+/// Outcome of reconciling registrations with the registry.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AuditReport {
+	/// Registrations found in the audited range.
+	pub registered: usize,
+	/// Audited registrations whose hash was later purged, re-enrolled or released.
+	pub removed: usize,
+	/// Audited registrations whose hash is missing without explanation.
+	pub missing: Vec<MissingRegistration>,
+}
+
+impl AuditReport {
+	/// Reconcile `changes`, in block order, against the current registry.
+	///
+	/// Only registrations in `from..=to` are audited; removals count from any block after
+	/// them. `is_registered` tells whether a hash is in the current registry.
+	pub fn reconcile(
+		changes: impl IntoIterator<Item = (BlockNumber, RegistryChange)>,
+		from: BlockNumber,
+		to: BlockNumber,
+		is_registered: impl Fn(&Hash) -> bool,
+	) -> Self {
+		let mut report = Self::default();
+		let mut expected = BTreeMap::new();
+
+		for (block, change) in changes {
+			match change {
+				RegistryChange::Registered(account, hash) if (from..=to).contains(&block) => {
+					report.registered += 1;
+					expected.insert(hash, (block, account));
+				},
+				RegistryChange::Registered(..) => {},
+				RegistryChange::Removed(hash) =>
+					if expected.remove(&hash).is_some() {
+						report.removed += 1;
+					},
+			}
+		}
+
+		report.missing = expected
+			.into_iter()
+			.filter(|(hash, _)| !is_registered(hash))
+			.map(|(hash, (block, account))| MissingRegistration { block, account, hash })
+			.collect();
+		report.missing.sort_by_key(|missing| missing.block);
+		report
+	}
+
+	/// Human-readable reconciliation report.
+	pub fn render(&self) -> String {
+		let mut lines = vec![
+			format!("registrations audited: {}", self.registered),
+			format!("purged, re-enrolled or released since: {}", self.removed),
+			format!("still registered: {}", self.registered - self.removed - self.missing.len()),
+			format!("missing: {}", self.missing.len()),
+		];
+		lines.extend(self.missing.iter().map(|missing| {
+			format!(
+				"  block {}: {} registered by {}",
+				missing.block,
+				hex(missing.hash.as_bytes()),
+				missing.account
+			)
+		}));
+		lines.join("\n")
+	}
+}
+
+impl AuditRegistrationsCmd {
+	/// Run the command.
+	pub fn run(&self, client: Arc<FullClient>) -> sc_cli::Result<()> {
+		if self.from > self.to {
+			return Err(sc_cli::Error::Input("--from must not be after --to".into()))
+		}
+
+		let best = client.info().best_hash;
+		let best_number = client.info().best_number;
+		if self.to > best_number {
+			return Err(sc_cli::Error::Input(format!("--to is past best block {}", best_number)))
+		}
+
+		// Removals after the range still explain a missing hash, so read up to the best block
+		let mut changes = Vec::new();
+		for number in self.from..=best_number {
+			let block = client
+				.hash(number)
+				.map_err(|e| sc_cli::Error::Application(Box::new(e)))?
+				.ok_or_else(|| sc_cli::Error::Input(format!("block {} not found", number)))?;
+			let events = client
+				.storage(block, &StorageKey(events_key()))
+				.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+			let records = match events {
+				Some(data) => Vec::<frame_system::EventRecord<RuntimeEvent, Hash>>::decode(
+					&mut &data.0[..],
+				)
+				.map_err(|e| sc_cli::Error::Application(Box::new(e)))?,
+				None => continue,
+			};
+			changes.extend(
+				records
+					.into_iter()
+					.filter_map(|record| RegistryChange::from_event(record.event))
+					.map(|change| (number, change)),
+			);
+		}
+
+		let report = AuditReport::reconcile(changes, self.from, self.to, |hash| {
+			matches!(client.storage(best, &StorageKey(hash_to_owner_key(hash))), Ok(Some(_)))
+		});
+		println!("{}", report.render());
+
+		if report.missing.is_empty() {
+			Ok(())
+		} else {
+			Err(sc_cli::Error::Input(format!(
+				"{} registered hashes are missing from the registry",
+				report.missing.len()
+			)))
+		}
+	}
+}
+
+impl CliConfiguration for AuditRegistrationsCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// Storage key of `System::Events`.
+fn events_key() -> Vec<u8> {
+	[twox_128(b"System"), twox_128(b"Events")].concat()
+}
+
+/// Storage key of `ProofOfFace::BiometricHashToOwner` for `hash`.
+fn hash_to_owner_key(hash: &Hash) -> Vec<u8> {
+	[
+		&twox_128(b"ProofOfFace")[..],
+		&twox_128(b"BiometricHashToOwner")[..],
+		&blake2_128(hash.as_bytes())[..],
+		hash.as_bytes(),
+	]
+	.concat()
+}
+
+fn hex(bytes: &[u8]) -> String {
+	format!("0x{}", HexDisplay::from(&bytes))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn account(seed: u8) -> AccountId {
+		AccountId::new([seed; 32])
+	}
+
+	#[test]
+	fn reconcile_flags_only_unexplained_missing_hashes() {
+		let kept = Hash::repeat_byte(1);
+		let purged = Hash::repeat_byte(2);
+		let lost = Hash::repeat_byte(3);
+		let outside = Hash::repeat_byte(4);
+		let changes = vec![
+			(4, RegistryChange::Registered(account(9), outside)),
+			(5, RegistryChange::Registered(account(1), kept)),
+			(6, RegistryChange::Registered(account(2), purged)),
+			(7, RegistryChange::Registered(account(3), lost)),
+			(12, RegistryChange::Removed(purged)),
+		];
+
+		let report = AuditReport::reconcile(changes, 5, 10, |hash| *hash == kept);
+
+		assert_eq!(report.registered, 3);
+		assert_eq!(report.removed, 1);
+		assert_eq!(
+			report.missing,
+			vec![MissingRegistration { block: 7, account: account(3), hash: lost }]
+		);
+		assert!(report.render().contains("missing: 1"));
+	}
+
+	#[test]
+	fn released_merged_hash_is_not_missing() {
+		let merged = Hash::repeat_byte(1);
+		let event = RuntimeEvent::ProofOfFace(ProofOfFaceEvent::MergedHashReleased(
+			3,
+			account(1),
+			merged,
+		));
+		let changes = vec![
+			(1, RegistryChange::Registered(account(2), merged)),
+			(2, RegistryChange::from_event(event).expect("released hashes are followed")),
+		];
+
+		let report = AuditReport::reconcile(changes, 1, 1, |_| false);
+
+		assert_eq!(report.removed, 1);
+		assert!(report.missing.is_empty());
+	}
+
+	#[test]
+	fn reconcile_passes_when_every_hash_is_accounted_for() {
+		let hash = Hash::repeat_byte(1);
+		let changes = vec![(1, RegistryChange::Registered(account(1), hash))];
+
+		let report = AuditReport::reconcile(changes, 1, 1, |_| true);
+
+		assert!(report.missing.is_empty());
+		assert!(report.render().contains("still registered: 1"));
+	}
+}
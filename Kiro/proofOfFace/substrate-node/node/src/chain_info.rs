@@ -0,0 +1,126 @@
+//! The `chain-info` subcommand.
+//!
+//! Prints the runtime version and the ProofOfFace pallet constants compiled into this node,
+//! optionally as JSON for operators scripting around the node.
+
+use proofofface_runtime::{
+	pallet_proofofface::{self, Config as ProofOfFaceConfig},
+	Runtime, VERSION,
+};
+use serde::Serialize;
+use sp_core::Get;
+use sp_runtime::PerThing;
+
+/// The `chain-info` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ChainInfoCmd {
+	/// Print the information as JSON.
+	#[arg(long)]
+	pub json: bool,
+}
+
+/// Runtime identity and ProofOfFace configuration.
+#[derive(Debug, Serialize)]
+pub struct ChainInfo {
+	/// Runtime spec name.
+	pub spec_name: String,
+	/// Runtime spec version.
+	pub spec_version: u32,
+	/// ProofOfFace pallet constants.
+	pub proof_of_face: ProofOfFaceInfo,
+}
+
+/// ProofOfFace pallet constants.
+#[derive(Debug, Serialize)]
+pub struct ProofOfFaceInfo {
+	/// Maximum length of an IPFS CID.
+	pub max_ipfs_cid_length: u32,
+	/// Maximum length of a dispute evidence URL.
+	pub max_evidence_url_length: u32,
+	/// Fewest votes an open-voting dispute needs to be settled.
+	pub dispute_quorum_floor: u32,
+	/// Share of stored identities, in parts per million, whose votes an open-voting dispute
+	/// needs when that is more than `dispute_quorum_floor`.
+	pub quorum_share_ppm: u32,
+	/// Whether a tied dispute is resolved rather than rejected.
+	pub tie_resolves_in_favor: bool,
+	/// Maximum number of disputes a single account can open.
+	pub max_created_disputes: u32,
+	/// Blocks that must pass after registration before an identity can be disputed.
+	pub min_dispute_age: u32,
+	/// Jurors drawn per dispute; zero means open voting.
+	pub jury_size: u32,
+}
+
+impl ChainInfo {
+	/// Collect the information from the native runtime.
+	pub fn from_runtime() -> Self {
+		Self {
+			spec_name: VERSION.spec_name.to_string(),
+			spec_version: VERSION.spec_version,
+			proof_of_face: ProofOfFaceInfo {
+				max_ipfs_cid_length: <Runtime as ProofOfFaceConfig>::MaxIpfsCidLength::get(),
+				max_evidence_url_length: <Runtime as ProofOfFaceConfig>::MaxEvidenceUrlLength::get(),
+				dispute_quorum_floor: pallet_proofofface::DISPUTE_QUORUM,
+				quorum_share_ppm: <Runtime as ProofOfFaceConfig>::QuorumShare::get().deconstruct(),
+				tie_resolves_in_favor: <Runtime as ProofOfFaceConfig>::TieResolvesInFavor::get(),
+				max_created_disputes: <Runtime as ProofOfFaceConfig>::MaxCreatedDisputes::get(),
+				min_dispute_age: <Runtime as ProofOfFaceConfig>::MinDisputeAge::get(),
+				jury_size: <Runtime as ProofOfFaceConfig>::JurySize::get(),
+			},
+		}
+	}
+}
+
+impl ChainInfoCmd {
+	/// Run the command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let info = ChainInfo::from_runtime();
+
+		if self.json {
+			let json = serde_json::to_string_pretty(&info)
+				.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+			println!("{}", json);
+		} else {
+			let constants = &info.proof_of_face;
+			println!("Runtime: {} v{}", info.spec_name, info.spec_version);
+			println!("MaxIpfsCidLength: {}", constants.max_ipfs_cid_length);
+			println!("MaxEvidenceUrlLength: {}", constants.max_evidence_url_length);
+			println!("DisputeQuorumFloor: {}", constants.dispute_quorum_floor);
+			println!("QuorumShare: {} ppm", constants.quorum_share_ppm);
+			println!("TieResolvesInFavor: {}", constants.tie_resolves_in_favor);
+			println!("MaxCreatedDisputes: {}", constants.max_created_disputes);
+			println!("MinDisputeAge: {}", constants.min_dispute_age);
+			println!("JurySize: {}", constants.jury_size);
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn json_output_contains_pallet_constants() {
+		let json = serde_json::to_string(&ChainInfo::from_runtime()).unwrap();
+		let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(value["spec_name"], "proofofface");
+		let constants = &value["proof_of_face"];
+		for key in [
+			"max_ipfs_cid_length",
+			"max_evidence_url_length",
+			"dispute_quorum_floor",
+			"quorum_share_ppm",
+			"tie_resolves_in_favor",
+			"max_created_disputes",
+			"min_dispute_age",
+			"jury_size",
+		] {
+			assert!(!constants[key].is_null(), "missing key {}", key);
+		}
+		assert_eq!(constants["max_ipfs_cid_length"], 100);
+	}
+}
@@ -37,6 +37,15 @@ pub enum Subcommand {
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
 
+	/// Print the runtime's ProofOfFace configuration.
+	ChainInfo(crate::chain_info::ChainInfoCmd),
+
+	/// Write a canonical JSON snapshot of the identity registry at a block.
+	SnapshotRegistry(crate::snapshot::SnapshotRegistryCmd),
+
+	/// Check that the identities registered in a block range are still in the registry.
+	AuditRegistrations(crate::audit::AuditRegistrationsCmd),
+
 	/// Sub-commands concerned with benchmarking.
 	#[command(subcommand)]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
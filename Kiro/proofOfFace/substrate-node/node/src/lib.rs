@@ -2,10 +2,13 @@
 //! 
 //! This library provides the core functionality for the ProofOfFace blockchain node.
 
+pub mod audit;
+pub mod chain_info;
 pub mod chain_spec;
 pub mod cli;
 pub mod command;
 pub mod rpc;
 pub mod service;
+pub mod snapshot;
 
 pub use sc_cli::{Error, Result};
\ No newline at end of file
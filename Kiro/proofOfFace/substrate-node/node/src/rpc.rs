@@ -7,7 +7,13 @@
 
 use std::sync::Arc;
 
-use proofofface_runtime::{opaque::Block, AccountId, Balance, Index};
+use proofofface_runtime::{
+	opaque::Block,
+	pallet_proofofface::{BiometricProofOf, ConstantsOf, Dispute},
+	AccountId, Balance, BlockNumber, Hash, Index, Runtime,
+};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
@@ -23,6 +29,8 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// Executor that drives RPC subscriptions
+	pub subscription_executor: SubscriptionTaskExecutor,
 }
 
 /// Instantiate all full RPC extensions.
@@ -32,20 +40,39 @@ pub fn create_full<C, P>(
 where
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+	C: BlockchainEvents<Block>,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_proofofface_rpc::ProofOfFaceRuntimeApi<
+		Block,
+		AccountId,
+		BlockNumber,
+		Balance,
+		Hash,
+		Dispute<Runtime>,
+		BiometricProofOf<Runtime>,
+		ConstantsOf<Runtime>,
+	>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use pallet_proofofface_rpc::{ProofOfFace, ProofOfFaceApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = jsonrpsee::RpcModule::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps { client, pool, deny_unsafe, subscription_executor } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(
+		ProofOfFace::<_, _, Dispute<Runtime>, BiometricProofOf<Runtime>>::new(
+			client,
+			subscription_executor,
+		)
+		.into_rpc(),
+	)?;
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
@@ -0,0 +1,197 @@
+//! The `snapshot-registry` subcommand.
+//!
+//! Reads every `IdentityProofs` entry at a given block and writes a canonical JSON snapshot
+//! for audits. Entries are sorted by account and the snapshot carries a Blake2-256 content
+//! hash of the identities, so the same registry state always yields the same bytes.
+
+use std::{path::PathBuf, sync::Arc};
+
+use codec::Decode;
+use proofofface_runtime::{
+	pallet_proofofface::BiometricProofOf, AccountId, BlockNumber, Hash, Runtime,
+};
+use sc_cli::{CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::StorageProvider;
+use serde::Serialize;
+use sp_core::{
+	hashing::{blake2_256, twox_128},
+	hexdisplay::HexDisplay,
+	storage::StorageKey,
+};
+
+use crate::service::FullClient;
+
+/// Length of the `Blake2_128Concat` hash that precedes the account in a storage key.
+const BLAKE2_128_LEN: usize = 16;
+
+/// The `snapshot-registry` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct SnapshotRegistryCmd {
+	/// Hash of the block to snapshot the registry at.
+	#[arg(long, value_name = "BLOCK_HASH")]
+	pub at: Hash,
+
+	/// File to write the snapshot to.
+	#[arg(long, value_name = "PATH")]
+	pub output: PathBuf,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+/// A single identity in the snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SnapshotEntry {
+	/// Hex-encoded owner account.
+	pub account: String,
+	/// Hex-encoded biometric hash.
+	pub biometric_hash: String,
+	/// IPFS CID, decoded as UTF-8 (lossy).
+	pub ipfs_cid: String,
+	/// Registration block.
+	pub timestamp: BlockNumber,
+	/// Whether the identity is active.
+	pub is_active: bool,
+	/// Block at which the identity expires, if any.
+	pub expires_at: Option<BlockNumber>,
+}
+
+impl SnapshotEntry {
+	/// Build an entry from a stored proof.
+	pub fn from_proof(account: &AccountId, proof: &BiometricProofOf<Runtime>) -> Self {
+		Self {
+			account: hex(account.as_ref()),
+			biometric_hash: hex(proof.biometric_hash.as_bytes()),
+			ipfs_cid: String::from_utf8_lossy(&proof.ipfs_cid).into_owned(),
+			timestamp: proof.timestamp,
+			is_active: proof.is_active,
+			expires_at: proof.expires_at,
+		}
+	}
+}
+
+/// A canonical snapshot of the identity registry.
+#[derive(Debug, Serialize)]
+pub struct RegistrySnapshot {
+	/// Hash of the block the snapshot was taken at.
+	pub block: String,
+	/// Identities, sorted by account.
+	pub identities: Vec<SnapshotEntry>,
+	/// Blake2-256 of the JSON-encoded `identities`.
+	pub content_hash: String,
+}
+
+impl RegistrySnapshot {
+	/// Build a snapshot, sorting the entries and computing the content hash.
+	pub fn new(block: Hash, mut identities: Vec<SnapshotEntry>) -> serde_json::Result<Self> {
+		identities.sort_by(|a, b| a.account.cmp(&b.account));
+		let content_hash = hex(&blake2_256(&serde_json::to_vec(&identities)?));
+
+		Ok(Self { block: hex(block.as_bytes()), identities, content_hash })
+	}
+
+	/// Serialize the snapshot to its canonical byte representation.
+	pub fn to_bytes(&self) -> serde_json::Result<Vec<u8>> {
+		let mut bytes = serde_json::to_vec_pretty(self)?;
+		bytes.push(b'\n');
+		Ok(bytes)
+	}
+}
+
+impl SnapshotRegistryCmd {
+	/// Run the command.
+	pub fn run(&self, client: Arc<FullClient>) -> sc_cli::Result<()> {
+		let prefix = identity_proofs_prefix();
+		let pairs = client
+			.storage_pairs(self.at, Some(&StorageKey(prefix.clone())), None)
+			.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+
+		let mut identities = Vec::new();
+		for (key, value) in pairs {
+			let account = AccountId::decode(&mut &key.0[prefix.len() + BLAKE2_128_LEN..])
+				.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+			let proof = BiometricProofOf::<Runtime>::decode(&mut &value.0[..])
+				.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+			identities.push(SnapshotEntry::from_proof(&account, &proof));
+		}
+
+		let bytes = RegistrySnapshot::new(self.at, identities)
+			.and_then(|snapshot| snapshot.to_bytes())
+			.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+		std::fs::write(&self.output, bytes)?;
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for SnapshotRegistryCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+/// Storage prefix of `ProofOfFace::IdentityProofs`.
+fn identity_proofs_prefix() -> Vec<u8> {
+	[twox_128(b"ProofOfFace"), twox_128(b"IdentityProofs")].concat()
+}
+
+fn hex(bytes: &[u8]) -> String {
+	format!("0x{}", HexDisplay::from(&bytes))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn entry(account: u8, active: bool) -> SnapshotEntry {
+		SnapshotEntry {
+			account: hex(&[account; 32]),
+			biometric_hash: hex(&[account.wrapping_add(100); 32]),
+			ipfs_cid: format!("QmSnapshot{}", account),
+			timestamp: account as BlockNumber,
+			is_active: active,
+			expires_at: None,
+		}
+	}
+
+	#[test]
+	fn snapshots_of_same_state_are_identical() {
+		let block = Hash::repeat_byte(7);
+		let first = RegistrySnapshot::new(block, vec![entry(3, true), entry(1, false), entry(2, true)])
+			.unwrap()
+			.to_bytes()
+			.unwrap();
+		let second = RegistrySnapshot::new(block, vec![entry(2, true), entry(3, true), entry(1, false)])
+			.unwrap()
+			.to_bytes()
+			.unwrap();
+
+		assert_eq!(first, second);
+	}
+
+	#[test]
+	fn content_hash_changes_with_state() {
+		let block = Hash::repeat_byte(7);
+		let active = RegistrySnapshot::new(block, vec![entry(1, true)]).unwrap();
+		let inactive = RegistrySnapshot::new(block, vec![entry(1, false)]).unwrap();
+
+		assert_ne!(active.content_hash, inactive.content_hash);
+	}
+}
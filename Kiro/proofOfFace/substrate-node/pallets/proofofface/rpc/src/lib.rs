@@ -0,0 +1,565 @@
+//! RPC interface for the ProofOfFace pallet.
+//!
+//! Exposes the `ProofOfFaceApi` runtime API over JSON-RPC under the `proofOfFace_` prefix.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use futures::{future, stream, FutureExt, StreamExt};
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorObject},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
+};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, Get};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+
+pub use pallet_proofofface_runtime_api::{
+	BiometricProof, ProofOfFaceApi as ProofOfFaceRuntimeApi, ProofOfFaceConstants,
+	RegisterEligibility,
+};
+
+/// Client-facing view of a `BiometricProof`.
+///
+/// The IPFS CID and gateway are rendered as text (invalid UTF-8 is replaced lossily) and the
+/// biometric hash as `0x`-prefixed hex, instead of the raw byte arrays SCALE types serialize to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofView<AccountId, BlockNumber> {
+	/// Owner of the biometric proof
+	pub owner: AccountId,
+	/// Hex-encoded hash of the face embeddings
+	pub biometric_hash: String,
+	/// IPFS content identifier for the stored face data
+	pub ipfs_cid: String,
+	/// Block number when the proof was created
+	pub timestamp: BlockNumber,
+	/// Whether the proof is currently active
+	pub is_active: bool,
+	/// Block number at which the proof is deactivated unless refreshed
+	pub expires_at: Option<BlockNumber>,
+	/// URL prefix of the IPFS gateway the owner pins through, if they set one
+	pub gateway: Option<String>,
+}
+
+impl<AccountId, Hash, BlockNumber, MaxGatewayLen>
+	From<BiometricProof<AccountId, Hash, BlockNumber, MaxGatewayLen>>
+	for ProofView<AccountId, BlockNumber>
+where
+	Hash: AsRef<[u8]>,
+	MaxGatewayLen: Get<u32>,
+{
+	fn from(proof: BiometricProof<AccountId, Hash, BlockNumber, MaxGatewayLen>) -> Self {
+		Self {
+			owner: proof.owner,
+			biometric_hash: sp_core::bytes::to_hex(proof.biometric_hash.as_ref(), false),
+			ipfs_cid: String::from_utf8_lossy(&proof.ipfs_cid).into_owned(),
+			timestamp: proof.timestamp,
+			is_active: proof.is_active,
+			expires_at: proof.expires_at,
+			gateway: proof
+				.gateway
+				.map(|gateway| String::from_utf8_lossy(&gateway).into_owned()),
+		}
+	}
+}
+
+/// Dispute totals by status, for dashboards.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisputeStats {
+	/// Open disputes, including those waiting out the resolution delay
+	pub pending: u32,
+	/// Disputes settled as resolved
+	pub resolved: u32,
+	/// Disputes settled as rejected, including expired and overturned ones
+	pub rejected: u32,
+}
+
+impl From<(u32, u32, u32)> for DisputeStats {
+	fn from((pending, resolved, rejected): (u32, u32, u32)) -> Self {
+		Self { pending, resolved, rejected }
+	}
+}
+
+/// A verification included in a new best block, as pushed to subscribers.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationNotification<BlockHash, Hash> {
+	/// Block the verification was included in
+	pub block: BlockHash,
+	/// Biometric hash that was verified
+	pub biometric_hash: Hash,
+	/// Whether the hash was registered
+	pub result: bool,
+}
+
+/// A pallet event included in a finalized block, as pushed to subscribers.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventNotification<BlockHash> {
+	/// Block the event was deposited in
+	pub block: BlockHash,
+	/// Name of the event's variant, such as `DisputeResolved`
+	pub variant: String,
+	/// SCALE encoding of the event
+	pub data: Bytes,
+}
+
+/// Keep the `(variant_name, encoded_event)` pairs whose variant is one of `variants`.
+fn matching_events(
+	events: Vec<(Vec<u8>, Vec<u8>)>,
+	variants: &[String],
+) -> Vec<(String, Vec<u8>)> {
+	events
+		.into_iter()
+		.map(|(name, data)| (String::from_utf8_lossy(&name).into_owned(), data))
+		.filter(|(name, _)| variants.contains(name))
+		.collect()
+}
+
+/// Keep the verifications of `filter`, or all of them when there is no filter.
+fn matching_verifications<Hash: PartialEq>(
+	verifications: Vec<(Hash, bool)>,
+	filter: Option<&Hash>,
+) -> Vec<(Hash, bool)> {
+	verifications
+		.into_iter()
+		.filter(|(biometric_hash, _)| filter.map_or(true, |wanted| wanted == biometric_hash))
+		.collect()
+}
+
+/// ProofOfFace RPC methods.
+#[rpc(client, server)]
+pub trait ProofOfFaceApi<BlockHash, BlockNumber, AccountId, Hash, Balance> {
+	/// Configured values of the pallet's constants.
+	#[method(name = "proofOfFace_constants")]
+	fn constants(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<ProofOfFaceConstants<BlockNumber, Balance>>;
+
+	/// Check whether `account` may register `biometric_hash`, without submitting a transaction.
+	#[method(name = "proofOfFace_canRegister")]
+	fn can_register(
+		&self,
+		account: AccountId,
+		biometric_hash: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<RegisterEligibility>;
+
+	/// Fee, without tip, for registering an identity with an IPFS CID of `cid_len` bytes.
+	///
+	/// Priced as an sr25519-signed extrinsic with a zero nonce, so it can fall a few bytes'
+	/// worth of length fee short for accounts with a higher nonce.
+	#[method(name = "proofOfFace_registerFeeEstimate")]
+	fn register_fee_estimate(&self, cid_len: u32, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	/// Whether `biometric_hash` belongs to an identity that is currently active.
+	#[method(name = "proofOfFace_hashIsActive")]
+	fn hash_is_active(&self, biometric_hash: Hash, at: Option<BlockHash>) -> RpcResult<bool>;
+
+	/// Whether each of `hashes` belongs to an active identity, in the order given.
+	///
+	/// Only the first `MaxQueryBatch` hashes are looked up.
+	#[method(name = "proofOfFace_activeStatusOf")]
+	fn active_status_of(
+		&self,
+		hashes: Vec<Hash>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(Hash, bool)>>;
+
+	/// Owner of `biometric_hash` and whether their identity is active, `[null, false]` for an
+	/// unknown hash or, under the `ActiveAndPublic` verification policy, a private identity.
+	#[method(name = "proofOfFace_hashOwnerAndStatus")]
+	fn hash_owner_and_status(
+		&self,
+		biometric_hash: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Option<AccountId>, bool)>;
+
+	/// The identity registered by `account`, if any.
+	#[method(name = "proofOfFace_identityOf")]
+	fn identity_of(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ProofView<AccountId, BlockNumber>>>;
+
+	/// Identities of `accounts` in the order given, `null` for those without one.
+	///
+	/// Only the first `MaxQueryBatch` accounts are looked up.
+	#[method(name = "proofOfFace_identitiesOf")]
+	fn identities_of(
+		&self,
+		accounts: Vec<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, Option<ProofView<AccountId, BlockNumber>>)>>;
+
+	/// Block at which `account` registered its identity, or `null` if it has none.
+	#[method(name = "proofOfFace_registeredAt")]
+	fn registered_at(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<BlockNumber>>;
+
+	/// IPFS CID of `account`'s identity as text, or `null` if it has none.
+	///
+	/// Bytes that are not valid UTF-8 are replaced with U+FFFD.
+	#[method(name = "proofOfFace_ipfsCidOf")]
+	fn ipfs_cid_of(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Option<String>>;
+
+	/// Number of pending disputes against `account`'s identity.
+	#[method(name = "proofOfFace_openDisputesAgainst")]
+	fn open_disputes_against(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<u32>;
+
+	/// Numbers of pending, resolved and rejected disputes.
+	#[method(name = "proofOfFace_disputeStats")]
+	fn dispute_stats(&self, at: Option<BlockHash>) -> RpcResult<DisputeStats>;
+
+	/// Verifications in new best blocks, optionally only those of biometric hash `hash`.
+	#[subscription(
+		name = "proofOfFace_subscribeVerifications" => "proofOfFace_verification",
+		unsubscribe = "proofOfFace_unsubscribeVerifications",
+		item = VerificationNotification<BlockHash, Hash>
+	)]
+	fn subscribe_verifications(&self, hash: Option<Hash>);
+
+	/// Pallet events in newly finalized blocks whose variant name is one of `variants`.
+	#[subscription(
+		name = "proofOfFace_subscribeEvents" => "proofOfFace_event",
+		unsubscribe = "proofOfFace_unsubscribeEvents",
+		item = EventNotification<BlockHash>
+	)]
+	fn subscribe_events(&self, variants: Vec<String>);
+}
+
+/// Provides RPC methods to query the ProofOfFace pallet.
+///
+/// `Dispute` and `Proof` are the runtime's dispute and identity proof types, as used by its
+/// `ProofOfFaceApi`.
+pub struct ProofOfFace<C, Block, Dispute, Proof> {
+	client: Arc<C>,
+	executor: SubscriptionTaskExecutor,
+	_marker: std::marker::PhantomData<(Block, Dispute, Proof)>,
+}
+
+impl<C, Block, Dispute, Proof> ProofOfFace<C, Block, Dispute, Proof> {
+	/// Create new `ProofOfFace` with the given reference to the client and the executor
+	/// that drives its subscriptions.
+	pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+		Self { client, executor, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to the runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Map a runtime API failure into an RPC error.
+fn runtime_error(e: impl std::fmt::Display) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the ProofOfFace runtime API.",
+		Some(e.to_string()),
+	))
+	.into()
+}
+
+impl<C, Block, Dispute, Proof, AccountId, Hash, Balance>
+	ProofOfFaceApiServer<<Block as BlockT>::Hash, NumberFor<Block>, AccountId, Hash, Balance>
+	for ProofOfFace<C, Block, Dispute, Proof>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C: Send + Sync + 'static,
+	C::Api: ProofOfFaceRuntimeApi<
+		Block,
+		AccountId,
+		NumberFor<Block>,
+		Balance,
+		Hash,
+		Dispute,
+		Proof,
+		ProofOfFaceConstants<NumberFor<Block>, Balance>,
+	>,
+	AccountId: Codec,
+	Hash: Codec + PartialEq + Send + 'static,
+	Balance: Codec,
+	Dispute: Codec + Send + Sync + 'static,
+	Proof: Codec + Into<ProofView<AccountId, NumberFor<Block>>> + Send + Sync + 'static,
+{
+	fn constants(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<ProofOfFaceConstants<NumberFor<Block>, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.config_constants(at).map_err(runtime_error)
+	}
+
+	fn can_register(
+		&self,
+		account: AccountId,
+		biometric_hash: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RegisterEligibility> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.can_register(at, account, biometric_hash).map_err(runtime_error)
+	}
+
+	fn register_fee_estimate(
+		&self,
+		cid_len: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Balance> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.register_fee_estimate(at, cid_len).map_err(runtime_error)
+	}
+
+	fn hash_is_active(
+		&self,
+		biometric_hash: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.hash_is_active(at, biometric_hash).map_err(runtime_error)
+	}
+
+	fn active_status_of(
+		&self,
+		hashes: Vec<Hash>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(Hash, bool)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.active_status_of(at, hashes).map_err(runtime_error)
+	}
+
+	fn hash_owner_and_status(
+		&self,
+		biometric_hash: Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(Option<AccountId>, bool)> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.hash_owner_and_status(at, biometric_hash).map_err(runtime_error)
+	}
+
+	fn identity_of(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<ProofView<AccountId, NumberFor<Block>>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		Ok(api.identity_of(at, account).map_err(runtime_error)?.map(Into::into))
+	}
+
+	fn identities_of(
+		&self,
+		accounts: Vec<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, Option<ProofView<AccountId, NumberFor<Block>>>)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		Ok(api
+			.identities_of(at, accounts)
+			.map_err(runtime_error)?
+			.into_iter()
+			.map(|(account, proof)| (account, proof.map(Into::into)))
+			.collect())
+	}
+
+	fn registered_at(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<NumberFor<Block>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.registered_at(at, account).map_err(runtime_error)
+	}
+
+	fn ipfs_cid_of(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<String>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		Ok(api
+			.ipfs_cid_of(at, account)
+			.map_err(runtime_error)?
+			.map(|cid| String::from_utf8_lossy(&cid).into_owned()))
+	}
+
+	fn open_disputes_against(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<u32> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.open_disputes_against(at, account).map_err(runtime_error)
+	}
+
+	fn dispute_stats(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<DisputeStats> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		Ok(api.dispute_status_counts(at).map_err(runtime_error)?.into())
+	}
+
+	fn subscribe_verifications(
+		&self,
+		sink: SubscriptionSink,
+		hash: Option<Hash>,
+	) -> SubscriptionResult {
+		let client = self.client.clone();
+		let notifications = self
+			.client
+			.import_notification_stream()
+			.filter(|notification| future::ready(notification.is_new_best))
+			.flat_map(move |notification| {
+				let block = notification.hash;
+				// A block whose events cannot be read is skipped rather than ending the stream
+				let verifications =
+					client.runtime_api().verifications_in_block(block).unwrap_or_default();
+				let matching = matching_verifications(verifications, hash.as_ref())
+					.into_iter()
+					.map(|(biometric_hash, result)| VerificationNotification {
+						block,
+						biometric_hash,
+						result,
+					})
+					.collect::<Vec<_>>();
+				stream::iter(matching)
+			});
+
+		let fut = async move {
+			sink.pipe_from_stream(notifications).await;
+		};
+		self.executor.spawn("proofofface-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+
+	fn subscribe_events(&self, sink: SubscriptionSink, variants: Vec<String>) -> SubscriptionResult {
+		let client = self.client.clone();
+		let notifications =
+			self.client.finality_notification_stream().flat_map(move |notification| {
+				let block = notification.hash;
+				// A block whose events cannot be read is skipped rather than ending the stream
+				let events = client.runtime_api().events_in_block(block).unwrap_or_default();
+				let matching = matching_events(events, &variants)
+					.into_iter()
+					.map(|(variant, data)| EventNotification {
+						block,
+						variant,
+						data: data.into(),
+					})
+					.collect::<Vec<_>>();
+				stream::iter(matching)
+			});
+
+		let fut = async move {
+			sink.pipe_from_stream(notifications).await;
+		};
+		self.executor.spawn("proofofface-rpc-subscription", Some("rpc"), fut.boxed());
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::{ConstU32, H256};
+	use sp_runtime::BoundedVec;
+
+	#[test]
+	fn proof_view_renders_cid_and_hash_as_text() {
+		let cid = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+		let proof: BiometricProof<u64, H256, u32, ConstU32<64>> = BiometricProof {
+			owner: 1u64,
+			biometric_hash: H256::repeat_byte(0xab),
+			ipfs_cid: BoundedVec::truncate_from(cid.as_bytes().to_vec()),
+			timestamp: 7u32,
+			is_active: true,
+			expires_at: Some(107u32),
+			gateway: Some(BoundedVec::truncate_from(b"https://ipfs.io/ipfs/".to_vec())),
+			private: false,
+		};
+
+		let view = ProofView::from(proof);
+
+		assert_eq!(view.ipfs_cid, cid);
+		assert_eq!(view.biometric_hash, format!("0x{}", "ab".repeat(32)));
+		assert_eq!(view.owner, 1);
+		assert_eq!(view.expires_at, Some(107));
+		assert_eq!(view.gateway.as_deref(), Some("https://ipfs.io/ipfs/"));
+	}
+
+	#[test]
+	fn verification_filter_forwards_only_matching_hash() {
+		let wanted = H256::repeat_byte(1);
+		let other = H256::repeat_byte(2);
+		let verifications = vec![(wanted, true), (other, false), (wanted, false)];
+
+		assert_eq!(
+			matching_verifications(verifications.clone(), Some(&wanted)),
+			vec![(wanted, true), (wanted, false)]
+		);
+		assert!(matching_verifications(verifications.clone(), Some(&H256::zero())).is_empty());
+		assert_eq!(matching_verifications(verifications.clone(), None), verifications);
+	}
+
+	#[test]
+	fn event_filter_forwards_only_requested_variants() {
+		let events = vec![
+			(b"IdentityRegistered".to_vec(), vec![0]),
+			(b"DisputeResolved".to_vec(), vec![1]),
+			(b"IdentityRegistered".to_vec(), vec![2]),
+			(b"DisputeResolved".to_vec(), vec![3]),
+		];
+
+		assert_eq!(
+			matching_events(events.clone(), &["DisputeResolved".to_string()]),
+			vec![("DisputeResolved".to_string(), vec![1]), ("DisputeResolved".to_string(), vec![3])]
+		);
+		assert!(matching_events(events, &[]).is_empty());
+	}
+}
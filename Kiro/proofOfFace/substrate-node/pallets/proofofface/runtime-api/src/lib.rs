@@ -0,0 +1,115 @@
+//! Runtime API definition for the ProofOfFace pallet.
+//!
+//! These calls are read-only views over the identity registry, used by the node RPC
+//! and by frontends that want to pre-validate actions before submitting extrinsics.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_proofofface::{
+	BiometricProof, DisputeStatus, ProofOfFaceConstants, RegisterEligibility,
+};
+
+sp_api::decl_runtime_apis! {
+	/// Queries against the ProofOfFace identity registry.
+	///
+	/// `Dispute` is the runtime's `pallet_proofofface::Dispute<Runtime>`, `Proof` its
+	/// `pallet_proofofface::BiometricProofOf<Runtime>` and `Constants` its
+	/// `pallet_proofofface::ConstantsOf<Runtime>`.
+	pub trait ProofOfFaceApi<AccountId, BlockNumber, Balance, Hash, Dispute, Proof, Constants>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+		Hash: Codec,
+		Dispute: Codec,
+		Proof: Codec,
+		Constants: Codec,
+	{
+		/// Configured values of the pallet's constants.
+		fn config_constants() -> Constants;
+
+		/// Whether `account` could register `biometric_hash` right now.
+		fn can_register(account: AccountId, biometric_hash: Hash) -> RegisterEligibility;
+
+		/// Fee, without tip, for a `register_identity` with an IPFS CID of `cid_len` bytes,
+		/// signed with sr25519 and a zero nonce. `cid_len` is capped at `MaxIpfsCidLength`.
+		fn register_fee_estimate(cid_len: u32) -> Balance;
+
+		/// Whether `biometric_hash` is registered, without recording a verification.
+		fn dry_run_verify(biometric_hash: Hash) -> bool;
+
+		/// Whether `biometric_hash` belongs to an identity that is currently active.
+		fn hash_is_active(biometric_hash: Hash) -> bool;
+
+		/// Active status of each of `hashes` in the order given, for at most `MaxQueryBatch`.
+		fn active_status_of(hashes: Vec<Hash>) -> Vec<(Hash, bool)>;
+
+		/// Owner of `biometric_hash` and whether their identity is active, `(None, false)` for
+		/// an unknown hash or, under `VerificationPolicy::ActiveAndPublic`, a private identity.
+		fn hash_owner_and_status(biometric_hash: Hash) -> (Option<AccountId>, bool);
+
+		/// Owner of `biometric_hash` and their public handle, without recording a verification.
+		fn verify_with_handle(biometric_hash: Hash) -> Option<(AccountId, Option<Vec<u8>>)>;
+
+		/// Verifications recorded in this block, as `(biometric_hash, result)` in event order.
+		fn verifications_in_block() -> Vec<(Hash, bool)>;
+
+		/// Pallet events in this block, as `(variant_name, encoded_event)` in event order.
+		fn events_in_block() -> Vec<(Vec<u8>, Vec<u8>)>;
+
+		/// The identity proof registered by `account`, if any.
+		fn identity_of(account: AccountId) -> Option<Proof>;
+
+		/// Identities of `accounts` in the order given, for at most `MaxQueryBatch` of them.
+		fn identities_of(accounts: Vec<AccountId>) -> Vec<(AccountId, Option<Proof>)>;
+
+		/// Block at which `account` registered its identity, if it has one.
+		fn registered_at(account: AccountId) -> Option<BlockNumber>;
+
+		/// IPFS CID of the identity registered by `account`, if it has one.
+		fn ipfs_cid_of(account: AccountId) -> Option<Vec<u8>>;
+
+		/// IDs of the disputes opened by `account`, oldest first.
+		fn disputes_created_by(account: AccountId) -> Vec<u64>;
+
+		/// Number of disputes opened by each of `accounts`, for at most the first 100 of them.
+		fn creator_dispute_counts(accounts: Vec<AccountId>) -> Vec<(AccountId, u32)>;
+
+		/// Up to `limit` disputes with `status` and an ID of at least `start`, in ID order.
+		fn disputes_by_status(
+			status: DisputeStatus,
+			start: u64,
+			limit: u32,
+		) -> Vec<(u64, Dispute)>;
+
+		/// Net votes of an open dispute and whether it would resolve in favor if settled now.
+		fn dispute_lean(dispute_id: u64) -> Option<(i64, bool)>;
+
+		/// Number of pending disputes against `account`'s identity.
+		fn open_disputes_against(account: AccountId) -> u32;
+
+		/// Numbers of open, resolved and rejected disputes.
+		fn dispute_status_counts() -> (u32, u32, u32);
+
+		/// Secondary accounts linked to `primary`'s identity.
+		fn linked_accounts(primary: AccountId) -> Vec<AccountId>;
+
+		/// Up to `limit` comments on a dispute as `(index, author, text)`, from index `start`.
+		fn dispute_comments(
+			dispute_id: u64,
+			start: u32,
+			limit: u32,
+		) -> Vec<(u32, AccountId, Vec<u8>)>;
+
+		/// Up to `limit` voters of a dispute and their votes in the order cast, continuing
+		/// after `start`.
+		fn dispute_voters(
+			dispute_id: u64,
+			start: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, bool)>;
+	}
+}
@@ -4,72 +4,659 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as ProofOfFace;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_system::RawOrigin;
-use sp_std::vec;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, EnsureOrigin},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{Hash, One, Zero};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+fn biometric_hash<T: Config>(index: u32) -> T::Hash {
+	T::Hashing::hash_of(&index)
+}
+
+fn ipfs_cid() -> BoundedVec<u8, ConstU32<100>> {
+	BoundedVec::truncate_from(vec![b'Q'; 46])
+}
+
+fn evidence_url<T: Config>() -> EvidenceUrlOf<T> {
+	BoundedVec::truncate_from(b"https://evidence.example/benchmark".to_vec())
+}
+
+/// Give `who` enough free balance to reserve the registration deposit
+fn fund<T: Config>(who: &T::AccountId) {
+	let balance = T::RegistrationDeposit::get() + T::Currency::minimum_balance() * 10u32.into();
+	T::Currency::make_free_balance_be(who, balance);
+}
+
+/// Register an identity for `who` with the hash derived from `index`
+fn register<T: Config>(who: &T::AccountId, index: u32) -> T::Hash {
+	let hash = biometric_hash::<T>(index);
+	fund::<T>(who);
+	ProofOfFace::<T>::register_identity(RawOrigin::Signed(who.clone()).into(), hash, ipfs_cid())
+		.expect("fresh account and hash register");
+	hash
+}
+
+/// An origin `ForceOrigin` accepts, if it has one
+fn force_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+	T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+/// An origin `VerifierOrigin` accepts, with the account it resolves to
+fn verifier_origin<T: Config>() -> Result<(T::RuntimeOrigin, T::AccountId), BenchmarkError> {
+	let origin =
+		T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let verifier = T::VerifierOrigin::ensure_origin(origin.clone())
+		.map_err(|_| BenchmarkError::Weightless)?;
+	Ok((origin, verifier))
+}
+
+/// Register identities for the accounts `("juror", 0..n)`, one block apart so neither the
+/// per-block registration limit nor a block's expiry queue fills up
+fn register_jurors<T: Config>(n: u32) {
+	for i in 0..n {
+		frame_system::Pallet::<T>::set_block_number(i.into());
+		RegistrationsThisBlock::<T>::kill();
+		register::<T>(&account("juror", i, SEED), i + 1);
+	}
+}
+
+/// Register identities for the accounts `("voter", 0..n)`, one block apart from the next
+/// block on, and return the accounts
+fn register_voters<T: Config>(n: u32) -> Vec<T::AccountId> {
+	let start = frame_system::Pallet::<T>::block_number();
+	(0..n)
+		.map(|i| {
+			frame_system::Pallet::<T>::set_block_number(start + (i + 1).into());
+			RegistrationsThisBlock::<T>::kill();
+			let voter = account("voter", i, SEED);
+			register::<T>(&voter, 1_000_000 + i);
+			voter
+		})
+		.collect()
+}
+
+/// Open a dispute by `creator` against `hash`, which must be old enough to be disputed
+fn open_dispute<T: Config>(creator: T::AccountId, hash: T::Hash) -> u64 {
+	if T::RequireVerificationBeforeDispute::get() {
+		let now = frame_system::Pallet::<T>::block_number();
+		RecentVerifications::<T>::insert(&creator, &hash, now);
+	}
+
+	let dispute_id = NextDisputeId::<T>::get();
+	ProofOfFace::<T>::create_dispute(
+		RawOrigin::Signed(creator).into(),
+		hash,
+		DisputeCategory::Impersonation,
+		evidence_url::<T>(),
+	)
+	.expect("dispute against an old enough identity is created");
+	dispute_id
+}
+
+/// Open a dispute by `("creator", 0)` against the identity of `("owner", 0)` and return its
+/// ID with the accounts that form its quorum (the drawn jury in jury mode, enough open
+/// voters otherwise)
+fn setup_dispute<T: Config>() -> (u64, Vec<T::AccountId>) {
+	let owner: T::AccountId = account("owner", 0, SEED);
+	let hash = register::<T>(&owner, 0);
+	register_jurors::<T>(T::JurySize::get());
+
+	let now: BlockNumberFor<T> =
+		T::MinDisputeAge::get() + T::JurySize::get().into() + One::one();
+	frame_system::Pallet::<T>::set_block_number(now);
+
+	let dispute_id = open_dispute::<T>(account("creator", 0, SEED), hash);
+
+	let voters = match DisputeJury::<T>::get(dispute_id) {
+		Some(jury) => jury.into_inner(),
+		None => {
+			let quorum = Disputes::<T>::get(dispute_id).expect("just created").quorum_snapshot;
+			let needed = quorum.max(T::MinDistinctVoters::get());
+			(0..needed).map(|i| account("voter", i, SEED)).collect()
+		},
+	};
+	(dispute_id, voters)
+}
+
+/// Record `vote` by each of `voters` on a dispute without letting it settle
+fn record_votes<T: Config>(dispute_id: u64, voters: &[T::AccountId], vote: bool) {
+	for voter in voters {
+		DisputeVotes::<T>::insert(dispute_id, voter, vote);
+	}
+	DisputeVoterOrder::<T>::insert(dispute_id, BoundedVec::truncate_from(voters.to_vec()));
+	Disputes::<T>::mutate(dispute_id, |maybe_dispute| {
+		if let Some(dispute) = maybe_dispute {
+			if vote {
+				dispute.votes_for += voters.len() as u32;
+			} else {
+				dispute.votes_against += voters.len() as u32;
+			}
+		}
+	});
+}
 
 benchmarks! {
 	register_identity {
+		let c in (T::MinIpfsCidLength::get().max(1)) .. 100;
 		let caller: T::AccountId = whitelisted_caller();
-		let ipfs_hash = vec![1u8; 32];
-	}: _(RawOrigin::Signed(caller.clone()), ipfs_hash.clone())
+		let hash = biometric_hash::<T>(0);
+		let ipfs_cid = BoundedVec::truncate_from(vec![b'Q'; c as usize]);
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), hash, ipfs_cid)
 	verify {
-		assert!(Identities::<T>::contains_key(&caller));
+		assert!(IdentityProofs::<T>::contains_key(&caller));
 	}
 
-	verify_identity {
+	purge_identity {
 		let caller: T::AccountId = whitelisted_caller();
-		let identity: T::AccountId = whitelisted_caller();
-		let ipfs_hash = vec![1u8; 32];
-		
-		// Setup: register identity first
-		let _ = ProofOfFace::<T>::register_identity(
-			RawOrigin::Signed(identity.clone()).into(),
-			ipfs_hash
-		);
-	}: _(RawOrigin::Signed(caller.clone()), identity.clone(), 9500u32, true, None)
+		let hash = register::<T>(&caller, 0);
+	}: deactivate_identity(RawOrigin::Signed(caller.clone()), true)
 	verify {
-		assert!(Verifications::<T>::contains_key(&identity, 0));
+		assert!(!IdentityProofs::<T>::contains_key(&caller));
+		assert!(!BiometricHashToOwner::<T>::contains_key(&hash));
 	}
 
+	verify_identity {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let hash = register::<T>(&owner, 0);
+
+		let (origin, verifier) = verifier_origin::<T>()?;
+		VerificationAllowance::<T>::insert(&verifier, 1);
+	}: _<T::RuntimeOrigin>(origin, hash)
+	verify {
+		frame_system::Pallet::<T>::assert_last_event(
+			<T as Config>::RuntimeEvent::from(Event::VerificationPerformed(
+				EventSeq::<T>::get() - 1,
+				hash,
+				true,
+				1,
+			))
+			.into(),
+		);
+	}
+
+	// `c` identities are there to draw a jury from, which only happens in jury mode
 	raise_dispute {
+		let c in (T::JurySize::get()) .. (T::MaxJuryCandidates::get().max(T::JurySize::get()));
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let hash = register::<T>(&owner, 0);
+		register_jurors::<T>(c);
+
+		let now: BlockNumberFor<T> = T::MinDisputeAge::get() + c.into() + One::one();
+		frame_system::Pallet::<T>::set_block_number(now);
+
 		let caller: T::AccountId = whitelisted_caller();
-		let identity: T::AccountId = whitelisted_caller();
-		let ipfs_hash = vec![1u8; 32];
-		let reason = vec![1u8; 100];
-		
-		// Setup: register identity first
-		let _ = ProofOfFace::<T>::register_identity(
-			RawOrigin::Signed(identity.clone()).into(),
-			ipfs_hash
-		);
-	}: _(RawOrigin::Signed(caller), identity, reason)
+		RecentVerifications::<T>::insert(&caller, &hash, now);
+	}: create_dispute(RawOrigin::Signed(caller), hash, DisputeCategory::Impersonation, evidence_url::<T>())
 	verify {
 		assert!(Disputes::<T>::contains_key(0));
 	}
 
-	vote_dispute {
+	vote_on_dispute_no_resolution {
+		let (dispute_id, voters) = setup_dispute::<T>();
+		let voter = voters[0].clone();
+	}: vote_on_dispute(RawOrigin::Signed(voter.clone()), dispute_id, true)
+	verify {
+		let dispute = Disputes::<T>::get(dispute_id).unwrap();
+		assert_eq!(dispute.votes_for, 1);
+		assert_eq!(dispute.status, DisputeStatus::Pending);
+	}
+
+	vote_on_dispute_resolving {
+		let (dispute_id, mut voters) = setup_dispute::<T>();
+		let last_voter = voters.pop().expect("quorum is never empty");
+		for voter in voters {
+			ProofOfFace::<T>::vote_on_dispute(RawOrigin::Signed(voter).into(), dispute_id, true)?;
+		}
+	}: vote_on_dispute(RawOrigin::Signed(last_voter), dispute_id, true)
+	verify {
+		// Settles right away, or is scheduled to settle once `ResolutionDelay` has passed
+		assert_ne!(Disputes::<T>::get(dispute_id).unwrap().status, DisputeStatus::Pending);
+	}
+
+	verify_identity_miss {
+		let hash = biometric_hash::<T>(0);
+		let (origin, verifier) = verifier_origin::<T>()?;
+		VerificationAllowance::<T>::insert(&verifier, 1);
+	}: verify_identity<T::RuntimeOrigin>(origin, hash)
+	verify {
+		frame_system::Pallet::<T>::assert_last_event(
+			<T as Config>::RuntimeEvent::from(Event::VerificationPerformed(
+				EventSeq::<T>::get() - 1,
+				hash,
+				false,
+				0,
+			))
+			.into(),
+		);
+	}
+
+	refresh_identity {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, 0);
+		frame_system::Pallet::<T>::set_block_number(One::one());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		let proof = IdentityProofs::<T>::get(&caller).unwrap();
+		assert_eq!(proof.expires_at.is_some(), !T::DefaultIdentityTtl::get().is_zero());
+	}
+
+	batch_register {
+		let n in 1 .. T::MaxImportBatch::get();
+		let items: Vec<_> = (0..n)
+			.map(|i| {
+				let owner: T::AccountId = account("import", i, SEED);
+				(owner, biometric_hash::<T>(i), ipfs_cid())
+			})
+			.collect();
+		let items = BoundedVec::truncate_from(items);
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, items)
+	verify {
+		assert_eq!(TotalIdentities::<T>::get(), n);
+	}
+
+	update_evidence {
+		let (dispute_id, _) = setup_dispute::<T>();
+		let creator: T::AccountId = account("creator", 0, SEED);
+		let url: EvidenceUrlOf<T> =
+			BoundedVec::truncate_from(b"https://evidence.example/new".to_vec());
+	}: _(RawOrigin::Signed(creator), dispute_id, url.clone())
+	verify {
+		assert_eq!(Disputes::<T>::get(dispute_id).unwrap().evidence[0], url);
+	}
+
+	buy_verifications {
 		let caller: T::AccountId = whitelisted_caller();
-		let identity: T::AccountId = whitelisted_caller();
-		let reporter: T::AccountId = whitelisted_caller();
-		let ipfs_hash = vec![1u8; 32];
-		let reason = vec![1u8; 100];
-		
-		// Setup: register identity and raise dispute
-		let _ = ProofOfFace::<T>::register_identity(
-			RawOrigin::Signed(identity.clone()).into(),
-			ipfs_hash
+		T::Currency::make_free_balance_be(
+			&caller,
+			T::PricePerVerification::get() + T::Currency::minimum_balance() * 10u32.into(),
 		);
-		let _ = ProofOfFace::<T>::raise_dispute(
-			RawOrigin::Signed(reporter).into(),
-			identity,
-			reason
+	}: _(RawOrigin::Signed(caller.clone()), 1)
+	verify {
+		assert_eq!(VerificationAllowance::<T>::get(&caller), 1);
+	}
+
+	acknowledge_dispute {
+		let (dispute_id, _) = setup_dispute::<T>();
+		let owner: T::AccountId = account("owner", 0, SEED);
+	}: _(RawOrigin::Signed(owner), dispute_id)
+	verify {
+		assert!(Disputes::<T>::get(dispute_id).unwrap().acknowledged_at.is_some());
+	}
+
+	add_evidence {
+		let (dispute_id, _) = setup_dispute::<T>();
+		let creator: T::AccountId = account("creator", 0, SEED);
+	}: _(RawOrigin::Signed(creator), dispute_id, evidence_url::<T>())
+	verify {
+		assert_eq!(Disputes::<T>::get(dispute_id).unwrap().evidence.len(), 2);
+	}
+
+	set_gateway {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, 0);
+		AllowedGateways::<T>::insert(GatewayOf::<T>::truncate_from(b"ipfs.io".to_vec()), ());
+		let gateway = GatewayOf::<T>::truncate_from(b"https://ipfs.io/ipfs/".to_vec());
+	}: _(RawOrigin::Signed(caller.clone()), Some(gateway.clone()))
+	verify {
+		assert_eq!(IdentityProofs::<T>::get(&caller).unwrap().gateway, Some(gateway));
+	}
+
+	// Unlinking from the secondary's side looks up both sides of the link
+	unlink_account {
+		let primary: T::AccountId = account("primary", 0, SEED);
+		register::<T>(&primary, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		LinkedAccounts::<T>::insert(&primary, &caller, ());
+		LinkedToPrimary::<T>::insert(&caller, &primary);
+	}: _(RawOrigin::Signed(caller.clone()), primary.clone())
+	verify {
+		assert!(!LinkedAccounts::<T>::contains_key(&primary, &caller));
+		assert!(!LinkedToPrimary::<T>::contains_key(&caller));
+	}
+
+	// Replacing a handle also releases the previous one
+	set_handle {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, 0);
+		let previous = HandleOf::<T>::truncate_from(b"previous".to_vec());
+		ProofOfFace::<T>::set_handle(RawOrigin::Signed(caller.clone()).into(), previous)?;
+		let handle = HandleOf::<T>::truncate_from(b"benchmark".to_vec());
+	}: _(RawOrigin::Signed(caller.clone()), handle.clone())
+	verify {
+		assert_eq!(HandleToAccount::<T>::get(&handle), Some(caller));
+	}
+
+	// `c` identities are there to draw a jury from, which only happens in jury mode
+	report_spoof {
+		let c in (T::JurySize::get()) .. (T::MaxJuryCandidates::get().max(T::JurySize::get()));
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let hash = register::<T>(&owner, 0);
+		register_jurors::<T>(c);
+
+		let now: BlockNumberFor<T> = T::MinDisputeAge::get() + c.into() + One::one();
+		frame_system::Pallet::<T>::set_block_number(now);
+
+		let (origin, verifier) = verifier_origin::<T>()?;
+		RecentVerifications::<T>::insert(&verifier, &hash, now);
+	}: _<T::RuntimeOrigin>(origin, hash, evidence_url::<T>())
+	verify {
+		assert!(Disputes::<T>::contains_key(0));
+		assert_eq!(FailedVerifications::<T>::get(&owner).0, 1);
+	}
+
+	add_comment {
+		let (dispute_id, _) = setup_dispute::<T>();
+		let creator: T::AccountId = account("creator", 0, SEED);
+		let text = CommentOf::<T>::truncate_from(vec![b'c'; T::MaxCommentLen::get() as usize]);
+	}: _(RawOrigin::Signed(creator), dispute_id, text)
+	verify {
+		assert_eq!(DisputeCommentCount::<T>::get(dispute_id), 1);
+	}
+
+	merge_identities {
+		let keep: T::AccountId = account("keep", 0, SEED);
+		let drop: T::AccountId = account("drop", 0, SEED);
+		register::<T>(&keep, 0);
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		RegistrationsThisBlock::<T>::kill();
+		let hash = register::<T>(&drop, 1);
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, keep.clone(), drop.clone())
+	verify {
+		assert!(!IdentityProofs::<T>::contains_key(&drop));
+		assert_eq!(BiometricHashToOwner::<T>::get(&hash), Some(keep));
+	}
+
+	// `n` expired disputes are inspected and closed, and the caller is paid for each
+	sweep_expired_disputes {
+		let n in 1 .. T::MaxSweepPerCall::get();
+		let period = T::DisputeVotingPeriod::get().max(T::VotingWindow::get());
+		if period.is_zero() {
+			return Err(BenchmarkError::Weightless)
+		}
+
+		// Each disputed identity leaves `JurySize` others to draw its jury from
+		let identities = n + T::JurySize::get();
+		register_jurors::<T>(identities);
+		let now: BlockNumberFor<T> = T::MinDisputeAge::get() + identities.into() + One::one();
+		frame_system::Pallet::<T>::set_block_number(now);
+		for i in 0..n {
+			open_dispute::<T>(account("creator", i, SEED), biometric_hash::<T>(i + 1));
+		}
+		frame_system::Pallet::<T>::set_block_number(now + period);
+
+		T::Currency::make_free_balance_be(
+			&ProofOfFace::<T>::account_id(),
+			T::SweepReward::get() * n.into() + T::Currency::minimum_balance(),
 		);
-	}: _(RawOrigin::Signed(caller), 0u32, true)
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), n)
 	verify {
-		let dispute = Disputes::<T>::get(0).unwrap();
-		assert_eq!(dispute.votes_for, 1);
+		assert_eq!(SweepCursor::<T>::get(), n as u64);
+		assert_eq!(PendingDisputeCount::<T>::get(), 0);
+	}
+
+	add_allowed_gateway {
+		let host = GatewayOf::<T>::truncate_from(b"ipfs.io".to_vec());
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, host.clone())
+	verify {
+		assert!(AllowedGateways::<T>::contains_key(&host));
+	}
+
+	remove_allowed_gateway {
+		let host = GatewayOf::<T>::truncate_from(b"ipfs.io".to_vec());
+		AllowedGateways::<T>::insert(&host, ());
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, host.clone())
+	verify {
+		assert!(!AllowedGateways::<T>::contains_key(&host));
+	}
+
+	commit_batch {
+		let root = biometric_hash::<T>(0);
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, root)
+	verify {
+		assert!(BatchCommitments::<T>::contains_key(root));
+	}
+
+	// The proof is as long as `MAX_MERKLE_PROOF_DEPTH` allows
+	register_from_commitment {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let hash = biometric_hash::<T>(0);
+		let siblings: Vec<_> =
+			(1..=MAX_MERKLE_PROOF_DEPTH).map(biometric_hash::<T>).collect();
+		let leaf = merkle::leaf::<T::Hashing>(hash.as_ref());
+		let root = merkle::root_from_proof::<T::Hashing>(leaf, &siblings);
+		BatchCommitments::<T>::insert(root, BlockNumberFor::<T>::zero());
+	}: _(RawOrigin::Signed(caller.clone()), hash, ipfs_cid(), BoundedVec::truncate_from(siblings))
+	verify {
+		assert_eq!(BiometricHashToOwner::<T>::get(&hash), Some(caller));
+	}
+
+	reenroll {
+		let caller: T::AccountId = whitelisted_caller();
+		let old_hash = register::<T>(&caller, 0);
+		let new_hash = biometric_hash::<T>(1);
+	}: _(RawOrigin::Signed(caller.clone()), new_hash, ipfs_cid())
+	verify {
+		assert!(!BiometricHashToOwner::<T>::contains_key(&old_hash));
+		assert_eq!(BiometricHashToOwner::<T>::get(&new_hash), Some(caller));
+	}
+
+	set_halted {
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, true)
+	verify {
+		assert!(Halted::<T>::get());
+	}
+
+	// Rewarding the voters is weighed separately by `reward_voters`
+	force_resolve_dispute {
+		let (dispute_id, _) = setup_dispute::<T>();
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, dispute_id, true)
+	verify {
+		assert_eq!(Disputes::<T>::get(dispute_id).unwrap().status, DisputeStatus::Resolved);
+	}
+
+	set_recovery {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, 0);
+		let recovery: T::AccountId = account("recovery", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), Some(recovery.clone()))
+	verify {
+		assert_eq!(RecoveryAccounts::<T>::get(&caller), Some(recovery));
+	}
+
+	initiate_recovery {
+		let lost: T::AccountId = account("lost", 0, SEED);
+		register::<T>(&lost, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		RecoveryAccounts::<T>::insert(&lost, &caller);
+	}: _(RawOrigin::Signed(caller), lost.clone())
+	verify {
+		assert!(PendingRecoveries::<T>::contains_key(&lost));
+	}
+
+	recover_identity {
+		let lost: T::AccountId = account("lost", 0, SEED);
+		let hash = register::<T>(&lost, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		RecoveryAccounts::<T>::insert(&lost, &caller);
+		PendingRecoveries::<T>::insert(&lost, BlockNumberFor::<T>::zero());
+		frame_system::Pallet::<T>::set_block_number(T::RecoveryDelay::get());
+	}: _(RawOrigin::Signed(caller.clone()), lost.clone())
+	verify {
+		assert!(!IdentityProofs::<T>::contains_key(&lost));
+		assert_eq!(BiometricHashToOwner::<T>::get(&hash), Some(caller));
+	}
+
+	cancel_recovery {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, 0);
+		RecoveryAccounts::<T>::insert(&caller, account::<T::AccountId>("recovery", 0, SEED));
+		PendingRecoveries::<T>::insert(&caller, BlockNumberFor::<T>::zero());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(!PendingRecoveries::<T>::contains_key(&caller));
+	}
+
+	freeze_dispute {
+		let (dispute_id, _) = setup_dispute::<T>();
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, dispute_id)
+	verify {
+		assert!(Disputes::<T>::get(dispute_id).unwrap().frozen);
+	}
+
+	unfreeze_dispute {
+		let (dispute_id, _) = setup_dispute::<T>();
+		Disputes::<T>::mutate(dispute_id, |dispute| {
+			dispute.as_mut().expect("just created").frozen = true
+		});
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, dispute_id)
+	verify {
+		assert!(!Disputes::<T>::get(dispute_id).unwrap().frozen);
+	}
+
+	set_private {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, 0);
+	}: _(RawOrigin::Signed(caller.clone()), true)
+	verify {
+		assert!(IdentityProofs::<T>::get(&caller).unwrap().private);
+	}
+
+	// The quorum is already met, as after `MinDistinctVoters` was lowered; rewarding the
+	// voters is weighed separately by `reward_voters`
+	finalize_dispute {
+		let (dispute_id, voters) = setup_dispute::<T>();
+		record_votes::<T>(dispute_id, &voters, true);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), dispute_id)
+	verify {
+		assert_ne!(Disputes::<T>::get(dispute_id).unwrap().status, DisputeStatus::Pending);
+	}
+
+	// Delegating again also drops the caller from their previous delegate's delegators
+	delegate_vote {
+		let caller: T::AccountId = whitelisted_caller();
+		let previous: T::AccountId = account("delegate", 0, SEED);
+		ProofOfFace::<T>::delegate_vote(RawOrigin::Signed(caller.clone()).into(), previous)?;
+		let delegate: T::AccountId = account("delegate", 1, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), delegate.clone())
+	verify {
+		assert_eq!(VoteDelegates::<T>::get(&caller), Some(delegate));
+	}
+
+	undelegate {
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		ProofOfFace::<T>::delegate_vote(RawOrigin::Signed(caller.clone()).into(), delegate)?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(!VoteDelegates::<T>::contains_key(&caller));
+	}
+
+	force_deactivate_hashes {
+		let n in 1 .. T::MaxTakedownBatch::get();
+		register_jurors::<T>(n);
+		let hashes: Vec<_> = (1..=n).map(biometric_hash::<T>).collect();
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, BoundedVec::truncate_from(hashes))
+	verify {
+		let juror: T::AccountId = account("juror", 0, SEED);
+		assert!(!IdentityProofs::<T>::get(&juror).unwrap().is_active);
+	}
+
+	lift_takedown {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		register::<T>(&owner, 0);
+		TakenDown::<T>::insert(&owner, ());
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, owner.clone())
+	verify {
+		assert!(!TakenDown::<T>::contains_key(&owner));
+	}
+
+	set_note {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller, 0);
+		let note = NoteOf::<T>::truncate_from(vec![1; T::MaxNoteLen::get() as usize]);
+	}: _(RawOrigin::Signed(caller.clone()), note.clone())
+	verify {
+		assert_eq!(IdentityNotes::<T>::get(&caller), Some(note));
+	}
+
+	force_unregister {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let hash = register::<T>(&owner, 0);
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, owner.clone())
+	verify {
+		assert!(!IdentityProofs::<T>::contains_key(&owner));
+		assert!(!BiometricHashToOwner::<T>::contains_key(&hash));
+	}
+
+	attest_batch {
+		let root = biometric_hash::<T>(0);
+		let (origin, _) = verifier_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, root)
+	verify {
+		assert!(AttestationRoots::<T>::contains_key(root));
+	}
+
+	// The overturned dispute is the one that deactivated the identity, which is reactivated
+	resolve_appeal {
+		let (dispute_id, _) = setup_dispute::<T>();
+		ProofOfFace::<T>::force_resolve_dispute(force_origin::<T>()?, dispute_id, true)?;
+		let origin = force_origin::<T>()?;
+	}: _<T::RuntimeOrigin>(origin, dispute_id)
+	verify {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		assert!(IdentityProofs::<T>::get(&owner).unwrap().is_active);
+	}
+
+	// `n` voters with an identity are rewarded or penalized as a dispute closes; nothing is
+	// done unless `HonestVoterReward` or `DishonestVoterPenalty` is set
+	reward_voters {
+		let n in 1 .. T::MaxVotersPerDispute::get();
+		let (dispute_id, _) = setup_dispute::<T>();
+		let voters = register_voters::<T>(n);
+		record_votes::<T>(dispute_id, &voters, true);
+	}: {
+		ProofOfFace::<T>::reward_voters(dispute_id, true);
+	}
+
+	// A delegate's vote is recorded for `n` delegators who have not voted yet
+	apply_delegated_votes {
+		let n in 1 .. T::MaxDelegators::get();
+		let (dispute_id, _) = setup_dispute::<T>();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		for i in 0..n {
+			let delegator: T::AccountId = account("delegator", i, SEED);
+			ProofOfFace::<T>::delegate_vote(RawOrigin::Signed(delegator).into(), delegate.clone())?;
+		}
+	}: {
+		ProofOfFace::<T>::apply_delegated_votes(dispute_id, &delegate, true);
+	}
+	verify {
+		let delegator: T::AccountId = account("delegator", 0, SEED);
+		assert_eq!(DelegatedVotes::<T>::get(dispute_id, &delegator), Some(true));
 	}
 
 	impl_benchmark_test_suite!(ProofOfFace, crate::mock::new_test_ext(), crate::mock::Test);
-}
\ No newline at end of file
+}
@@ -19,24 +19,114 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod merkle;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
+/// Log target used by this pallet
+const LOG_TARGET: &str = "runtime::proofofface";
+
+/// Number of votes after which a dispute is settled by simple majority
+pub const DISPUTE_QUORUM: u32 = 10;
+
+/// Domain separator signed into migration attestations
+pub const MIGRATION_CONTEXT: &[u8] = b"proofofface/migrate";
+
+/// Domain separator signed by a secondary account consenting to be linked to an identity
+pub const LINK_CONTEXT: &[u8] = b"proofofface/link";
+
+/// Domain separator signed by an account consenting to take over a dispute
+pub const REASSIGN_CONTEXT: &[u8] = b"proofofface/reassign";
+
+/// Upper bound on the number of disputes returned by a single `disputes_by_status` page
+pub const MAX_DISPUTES_PER_PAGE: u32 = 100;
+
+/// Upper bound on the number of votes returned by a single `dispute_voters` page
+pub const MAX_VOTERS_PER_PAGE: u32 = 100;
+
+/// Upper bound on the number of comments returned by a single `dispute_comments` page
+pub const MAX_COMMENTS_PER_PAGE: u32 = 100;
+
+/// Upper bound on the number of accounts counted by a single `creator_dispute_counts` query
+pub const MAX_CREATOR_COUNT_ACCOUNTS: u32 = 100;
+
+/// Maximum number of siblings in a batch commitment inclusion proof
+pub const MAX_MERKLE_PROOF_DEPTH: u32 = 32;
+
+/// Hook notified when an account's reputation crosses the configured threshold
+pub trait OnReputationThreshold<AccountId> {
+	/// `who`'s reputation changed to `score`, which is now at or above the threshold if
+	/// `above` is true and below it otherwise
+	fn on_reputation_threshold(who: &AccountId, score: u32, above: bool);
+}
+
+impl<AccountId> OnReputationThreshold<AccountId> for () {
+	fn on_reputation_threshold(_who: &AccountId, _score: u32, _above: bool) {}
+}
+
+/// Deployment-specific check of a biometric hash before it is stored
+pub trait ValidateBiometricHash<Hash> {
+	/// Whether `hash` is acceptable as a biometric hash
+	fn validate(hash: &Hash) -> bool;
+}
+
+/// Accepts every biometric hash
+pub struct AlwaysValid;
+
+impl<Hash> ValidateBiometricHash<Hash> for AlwaysValid {
+	fn validate(_hash: &Hash) -> bool {
+		true
+	}
+}
+
+/// Deployment-specific check of whether an account may register an identity
+pub trait EnsureRegistrationAllowed<AccountId> {
+	/// Whether `who` may register, e.g. because it holds a minimum balance or an attestation
+	fn is_allowed(who: &AccountId) -> bool;
+}
+
+/// Lets every account register
+pub struct AllowAll;
+
+impl<AccountId> EnsureRegistrationAllowed<AccountId> for AllowAll {
+	fn is_allowed(_who: &AccountId) -> bool {
+		true
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{
 		dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
-		traits::{Get, Randomness},
+		storage::StoragePrefixedMap,
+		CloneNoBound, EqNoBound, PalletId, PartialEqNoBound, RuntimeDebugNoBound,
+		traits::{
+			BalanceStatus, Currency, ExistenceRequirement, Get, Randomness, ReservableCurrency,
+			WithdrawReasons,
+		},
 	};
 	use frame_system::pallet_prelude::*;
-	
-	
+	use sp_runtime::traits::{
+		AccountIdConversion, Hash as HashT, IdentifyAccount, Saturating, TrailingZeroInput,
+		Verify, Zero,
+	};
+	use sp_runtime::Permill;
+	use sp_std::vec::Vec;
+
+	/// Storage layout version, see [`crate::migrations`]
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(12);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// Balance type of the currency used to pay for verifications
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -50,17 +140,291 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxIpfsCidLength: Get<u32>;
 
+		/// The minimum length of an IPFS CID; CIDs are never accepted empty
+		#[pallet::constant]
+		type MinIpfsCidLength: Get<u32>;
+
 		/// The maximum length of evidence URL
 		#[pallet::constant]
 		type MaxEvidenceUrlLength: Get<u32>;
 
+		/// The maximum length of an identity's preferred IPFS gateway URL prefix
+		#[pallet::constant]
+		type MaxGatewayLen: Get<u32>;
+
+		/// The maximum length of an identity's public handle
+		#[pallet::constant]
+		type MaxHandleLen: Get<u32>;
+
+		/// The maximum length of the note an identity owner can attach with `set_note`
+		#[pallet::constant]
+		type MaxNoteLen: Get<u32>;
+
+		/// The maximum number of evidence URLs attached to a single dispute
+		#[pallet::constant]
+		type MaxEvidenceItems: Get<u32>;
+
+		/// Whether a settled or expired dispute keeps only its first (canonical) evidence URL
+		#[pallet::constant]
+		type PruneEvidenceOnResolve: Get<bool>;
+
 		/// Randomness source for generating unique IDs
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// Number of blocks an identity stays valid before it must be refreshed (zero disables expiry)
+		#[pallet::constant]
+		type DefaultIdentityTtl: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of identities that can be scheduled to expire in a single block
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// Origin allowed to perform privileged operations such as bulk imports
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of identities imported by a single `batch_register` call
+		#[pallet::constant]
+		type MaxImportBatch: Get<u32>;
+
+		/// The maximum number of hashes taken down by a single `force_deactivate_hashes` call
+		#[pallet::constant]
+		type MaxTakedownBatch: Get<u32>;
+
+		/// The maximum number of accounts or hashes looked up by a single `identities_of` or
+		/// `active_status_of` query
+		#[pallet::constant]
+		type MaxQueryBatch: Get<u32>;
+
+		/// Upper bound for an account's reputation score
+		#[pallet::constant]
+		type MaxReputation: Get<u32>;
+
+		/// Reputation score whose crossing, in either direction, is reported to
+		/// `OnReputationThreshold`
+		#[pallet::constant]
+		type ReputationThreshold: Get<u32>;
+
+		/// Reputation gained by each voter with an identity who voted with a dispute's outcome
+		#[pallet::constant]
+		type HonestVoterReward: Get<u32>;
+
+		/// Reputation lost by each voter with an identity who voted against a dispute's outcome
+		#[pallet::constant]
+		type DishonestVoterPenalty: Get<u32>;
+
+		/// Handler called when an account's reputation crosses `ReputationThreshold`
+		type OnReputationThreshold: OnReputationThreshold<Self::AccountId>;
+
+		/// Check applied to every biometric hash before it is registered or re-enrolled
+		type HashValidator: ValidateBiometricHash<Self::Hash>;
+
+		/// Check applied to every account calling `register_identity`, against Sybil
+		/// registrations
+		type RegistrationGate: EnsureRegistrationAllowed<Self::AccountId>;
+
+		/// Whether a dispute whose votes are tied at quorum is resolved (true) or rejected (false)
+		#[pallet::constant]
+		type TieResolvesInFavor: Get<bool>;
+
+		/// The maximum number of disputes a single account can open
+		#[pallet::constant]
+		type MaxCreatedDisputes: Get<u32>;
+
+		/// Number of blocks that must pass after registration before an identity can be disputed
+		#[pallet::constant]
+		type MinDisputeAge: Get<BlockNumberFor<Self>>;
+
+		/// Number of jurors randomly drawn for each dispute (zero keeps voting open to everyone)
+		#[pallet::constant]
+		type JurySize: Get<u32>;
+
+		/// The maximum number of active identities considered when drawing a jury
+		#[pallet::constant]
+		type MaxJuryCandidates: Get<u32>;
+
+		/// Currency used to pay for prepaid verifications and to hold registration deposits
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Amount reserved from a registrant for as long as their identity is stored
+		#[pallet::constant]
+		type RegistrationDeposit: Get<BalanceOf<Self>>;
+
+		/// Price of a single prepaid verification
+		#[pallet::constant]
+		type PricePerVerification: Get<BalanceOf<Self>>;
+
+		/// Whether `verify_identity` is free for everyone instead of consuming prepaid allowance
+		#[pallet::constant]
+		type FreeVerificationTier: Get<bool>;
+
+		/// Whether a batch of verification results is reported as one
+		/// `BatchVerificationSummary` event instead of one `VerificationPerformed` per hash
+		///
+		/// Only read by `deposit_verification_results`, which no call reaches yet, so this takes
+		/// effect only once a batch verification call exists.
+		#[pallet::constant]
+		type SummarizeBatchVerifications: Get<bool>;
+
+		/// Whether an owner verifying their own hash does so without a `VerificationPerformed`
+		/// event
+		#[pallet::constant]
+		type SkipSelfVerificationEvents: Get<bool>;
+
+		/// Whether inactive and private identities still match in `verify_identity` and the
+		/// verification queries
+		#[pallet::constant]
+		type VerificationPolicy: Get<VerificationPolicy>;
+
+		/// Origin allowed to call `verify_identity`, resolving to the account that is charged
+		type VerifierOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// Whether a dispute may only be opened after its creator verified the disputed hash
+		#[pallet::constant]
+		type RequireVerificationBeforeDispute: Get<bool>;
+
+		/// Number of blocks a verification stays usable for opening a dispute
+		#[pallet::constant]
+		type VerificationValidity: Get<BlockNumberFor<Self>>;
+
+		/// Signature type of migration attestations
+		type MigrationSignature: Verify<Signer = Self::MigrationPublic> + Parameter;
+
+		/// Public key type of the migration authority
+		type MigrationPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Account whose key signs attestations of identities registered in the ink contract
+		type MigrationAuthority: Get<Self::AccountId>;
+
+		/// Signature type a secondary account uses to consent to being linked to an identity
+		type LinkSignature: Verify<Signer = Self::LinkPublic> + Parameter;
+
+		/// Public key type of accounts that can be linked to an identity
+		type LinkPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Signature type an account uses to consent to taking over a dispute
+		type ReassignSignature: Verify<Signer = Self::ReassignPublic> + Parameter;
+
+		/// Public key type of accounts that can take over a dispute
+		type ReassignPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The maximum number of secondary accounts linked to a single identity
+		#[pallet::constant]
+		type MaxLinkedAccounts: Get<u32>;
+
+		/// The maximum number of identities registered network-wide in a single block
+		/// (zero or `u32::MAX` disables the limit)
+		#[pallet::constant]
+		type MaxRegistrationsPerBlock: Get<u32>;
+
+		/// Failed face matches an identity may take within one `FailedVerificationWindow`
+		/// before it is flagged (zero disables flagging, though failures are still counted)
+		#[pallet::constant]
+		type MaxFailedVerifications: Get<u32>;
+
+		/// Number of blocks over which failed face matches are counted
+		#[pallet::constant]
+		type FailedVerificationWindow: Get<BlockNumberFor<Self>>;
+
+		/// Whether a flagged identity is also deactivated instead of only being reported
+		///
+		/// The owner cannot reactivate a deactivated identity until `FailedVerificationWindow`
+		/// has passed since it was flagged.
+		#[pallet::constant]
+		type DeactivateFlaggedIdentities: Get<bool>;
+
+		/// Number of blocks a dispute waits after reaching quorum before it settles, leaving
+		/// room for late evidence and votes (zero settles it as soon as quorum is reached)
+		#[pallet::constant]
+		type ResolutionDelay: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of disputes that can be scheduled to settle in a single block
+		#[pallet::constant]
+		type MaxScheduledResolutions: Get<u32>;
+
+		/// The maximum number of scheduled disputes `on_initialize` settles in a single block;
+		/// the rest move to the front of the next block's schedule, keeping their order
+		#[pallet::constant]
+		type MaxResolutionsPerBlock: Get<u32>;
+
+		/// Number of stored identities between two `IdentityMilestone` events
+		/// (zero disables milestones)
+		#[pallet::constant]
+		type MilestoneInterval: Get<u32>;
+
+		/// Whether identity gateways must have a host listed in `AllowedGateways`
+		#[pallet::constant]
+		type EnforceGatewayAllowlist: Get<bool>;
+
+		/// The maximum length of a dispute comment in bytes
+		#[pallet::constant]
+		type MaxCommentLen: Get<u32>;
+
+		/// The maximum number of comments a single dispute can hold
+		#[pallet::constant]
+		type MaxCommentsPerDispute: Get<u32>;
+
+		/// The maximum number of votes a single dispute can receive
+		#[pallet::constant]
+		type MaxVotersPerDispute: Get<u32>;
+
+		/// The maximum number of accounts that can delegate their vote to a single delegate
+		#[pallet::constant]
+		type MaxDelegators: Get<u32>;
+
+		/// The minimum number of distinct accounts that must vote before a dispute can settle,
+		/// on top of the vote-count quorum (capped at the jury size in jury mode)
+		#[pallet::constant]
+		type MinDistinctVoters: Get<u32>;
+
+		/// Share of stored identities whose votes an open-voting dispute needs to reach quorum,
+		/// never less than `DISPUTE_QUORUM` (zero keeps the quorum at `DISPUTE_QUORUM`)
+		///
+		/// Each dispute snapshots its quorum when it is created, so registrations and purges
+		/// made while it is open do not move it.
+		#[pallet::constant]
+		type QuorumShare: Get<Permill>;
+
+		/// Whether registering or re-enrolling onto a biometric hash that still has an open
+		/// dispute fails, so a disputed hash freed by its owner cannot be reclaimed mid-dispute
+		#[pallet::constant]
+		type RejectDisputedHashes: Get<bool>;
+
+		/// Number of blocks a dispute stays open for voting before anyone may sweep it as
+		/// expired (zero keeps disputes open until they reach quorum)
+		///
+		/// A dispute whose `VotingWindow` is longer only expires once the window has closed,
+		/// and one whose window closed can be swept even when this is zero.
+		#[pallet::constant]
+		type DisputeVotingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks after its creation during which a dispute takes votes, whether or
+		/// not it reaches quorum (zero never closes voting)
+		#[pallet::constant]
+		type VotingWindow: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks a recovery account must wait after initiating a recovery before it
+		/// can take over the identity, during which the owner can cancel it
+		#[pallet::constant]
+		type RecoveryDelay: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of disputes a single `sweep_expired_disputes` call inspects
+		#[pallet::constant]
+		type MaxSweepPerCall: Get<u32>;
+
+		/// Paid from the pallet account to the caller of `sweep_expired_disputes` for each
+		/// dispute it closes (zero pays nothing)
+		#[pallet::constant]
+		type SweepReward: Get<BalanceOf<Self>>;
+
+		/// Identifier of the pallet account that funds sweep rewards
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
 	}
 
 	/// Biometric proof structure containing face identity data
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-	pub struct BiometricProof<AccountId, Hash, BlockNumber> {
+	#[scale_info(skip_type_params(MaxGatewayLen))]
+	pub struct BiometricProof<AccountId, Hash, BlockNumber, MaxGatewayLen: Get<u32>> {
 		/// Owner of the biometric proof
 		pub owner: AccountId,
 		/// SHA-256 hash of face embeddings
@@ -71,19 +435,61 @@ pub mod pallet {
 		pub timestamp: BlockNumber,
 		/// Whether the proof is currently active
 		pub is_active: bool,
+		/// Block number at which the proof is deactivated unless refreshed
+		pub expires_at: Option<BlockNumber>,
+		/// URL prefix of the IPFS gateway the owner pins through, if they set one
+		pub gateway: Option<BoundedVec<u8, MaxGatewayLen>>,
+		/// Whether the owner opted out of being verified, so their hashes verify as not found
+		pub private: bool,
 	}
 
+	/// Biometric proof as stored for this runtime
+	pub type BiometricProofOf<T> = BiometricProof<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+		BlockNumberFor<T>,
+		<T as Config>::MaxGatewayLen,
+	>;
+
+	/// Preferred IPFS gateway URL prefix of an identity
+	pub type GatewayOf<T> = BoundedVec<u8, <T as Config>::MaxGatewayLen>;
+
+	/// Public display handle of an identity
+	pub type HandleOf<T> = BoundedVec<u8, <T as Config>::MaxHandleLen>;
+
+	/// Opaque note attached to an identity by its owner
+	pub type NoteOf<T> = BoundedVec<u8, <T as Config>::MaxNoteLen>;
+
+	/// URL pointing at a piece of dispute evidence
+	pub type EvidenceUrlOf<T> = BoundedVec<u8, <T as Config>::MaxEvidenceUrlLength>;
+
+	/// Free-form rationale left on a dispute by one of its participants
+	pub type CommentOf<T> = BoundedVec<u8, <T as Config>::MaxCommentLen>;
+
 	/// Dispute structure for challenging biometric proofs
-	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-	pub struct Dispute<AccountId, Hash, BlockNumber> {
+	#[derive(
+		Encode,
+		Decode,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Dispute<T: Config> {
 		/// Unique dispute identifier
 		pub dispute_id: u64,
 		/// Hash of the face proof being disputed
-		pub face_proof_id: Hash,
+		pub face_proof_id: T::Hash,
 		/// Account that created the dispute
-		pub creator: AccountId,
-		/// URL to evidence supporting the dispute
-		pub evidence_url: BoundedVec<u8, ConstU32<256>>,
+		pub creator: T::AccountId,
+		/// Kind of problem the dispute reports
+		pub category: DisputeCategory,
+		/// URLs to evidence supporting the dispute, starting with the one given at creation
+		pub evidence: BoundedVec<EvidenceUrlOf<T>, T::MaxEvidenceItems>,
 		/// Number of votes supporting the dispute
 		pub votes_for: u32,
 		/// Number of votes against the dispute
@@ -91,7 +497,60 @@ pub mod pallet {
 		/// Current status of the dispute
 		pub status: DisputeStatus,
 		/// Block number when dispute was created
-		pub created_at: BlockNumber,
+		pub created_at: BlockNumberFor<T>,
+		/// Block at which the disputed identity's owner acknowledged the dispute, if they have
+		pub acknowledged_at: Option<BlockNumberFor<T>>,
+		/// Block at which the dispute reached quorum, if it is waiting out `ResolutionDelay`
+		pub ready_at: Option<BlockNumberFor<T>>,
+		/// Whether `ForceOrigin` froze the dispute, blocking votes and expiry
+		pub frozen: bool,
+		/// What settled the dispute, once it is no longer open
+		pub resolution_reason: Option<ResolutionReason>,
+		/// Votes the dispute needs to reach quorum, fixed when it was created
+		pub quorum_snapshot: u32,
+	}
+
+	impl<T: Config> Dispute<T> {
+		/// The evidence URL given when the dispute was created (or its replacement)
+		pub fn evidence_url(&self) -> Option<&EvidenceUrlOf<T>> {
+			self.evidence.first()
+		}
+	}
+
+	/// Which registered identities `verify_identity` reports as a match
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum VerificationPolicy {
+		/// Every registered identity, whether active or not and private or not
+		MatchAll,
+		/// Active identities, including those in private mode
+		ActiveOnly,
+		/// Active identities that are not in private mode
+		ActiveAndPublic,
+	}
+
+	impl VerificationPolicy {
+		/// Whether an identity with these flags is reported as a match
+		pub fn matches(&self, is_active: bool, private: bool) -> bool {
+			match self {
+				VerificationPolicy::MatchAll => true,
+				VerificationPolicy::ActiveOnly => is_active,
+				VerificationPolicy::ActiveAndPublic => is_active && !private,
+			}
+		}
+	}
+
+	/// What settled a dispute
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ResolutionReason {
+		/// The tally once quorum was reached
+		Vote,
+		/// `ForceOrigin`, regardless of the tally
+		Force,
+		/// `DisputeVotingPeriod` or `VotingWindow` ran out before quorum
+		Expiry,
+		/// A successful appeal overturned the outcome
+		Appeal,
 	}
 
 	/// Dispute status enumeration
@@ -103,8 +562,111 @@ pub mod pallet {
 		Resolved,
 		/// Dispute has been rejected
 		Rejected,
+		/// Dispute reached quorum and settles once `ResolutionDelay` has passed; it still
+		/// accepts votes and evidence until then
+		ReadyToResolve,
+	}
+
+	impl DisputeStatus {
+		/// Whether the dispute is not settled yet and still accepts votes and evidence
+		pub fn is_open(&self) -> bool {
+			matches!(self, DisputeStatus::Pending | DisputeStatus::ReadyToResolve)
+		}
+	}
+
+	/// Kind of problem a dispute reports
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum DisputeCategory {
+		/// The identity was registered by someone other than the face's owner
+		Impersonation,
+		/// The same person holds more than one identity
+		Duplicate,
+		/// The biometric data or IPFS content was fabricated
+		Fraud,
+		/// Anything not covered above; see the evidence
+		Other,
+	}
+
+	/// Outcome of checking whether an account may register a biometric hash
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub enum RegisterEligibility {
+		/// Registration would pass all pre-checks
+		Eligible,
+		/// The account already has a registered identity
+		AccountHasIdentity,
+		/// The biometric hash is already registered to another account
+		HashTaken,
+		/// Registrations are temporarily throttled; retry in a later block
+		CooldownActive,
+	}
+
+	/// Configured values of every `#[pallet::constant]`, for clients that should not hardcode them
+	///
+	/// Each field mirrors the `Config` constant of the same name; `dispute_quorum` is
+	/// `DISPUTE_QUORUM`, the lowest open-voting quorum used while `jury_size` is zero.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	pub struct ProofOfFaceConstants<BlockNumber, Balance> {
+		pub max_ipfs_cid_length: u32,
+		pub min_ipfs_cid_length: u32,
+		pub max_evidence_url_length: u32,
+		pub max_gateway_len: u32,
+		pub max_handle_len: u32,
+		pub max_note_len: u32,
+		pub max_evidence_items: u32,
+		pub prune_evidence_on_resolve: bool,
+		pub default_identity_ttl: BlockNumber,
+		pub max_expiries_per_block: u32,
+		pub max_import_batch: u32,
+		pub max_takedown_batch: u32,
+		pub max_query_batch: u32,
+		pub max_reputation: u32,
+		pub reputation_threshold: u32,
+		pub honest_voter_reward: u32,
+		pub dishonest_voter_penalty: u32,
+		pub dispute_quorum: u32,
+		pub quorum_share: Permill,
+		pub reject_disputed_hashes: bool,
+		pub tie_resolves_in_favor: bool,
+		pub max_created_disputes: u32,
+		pub min_dispute_age: BlockNumber,
+		pub jury_size: u32,
+		pub max_jury_candidates: u32,
+		pub registration_deposit: Balance,
+		pub price_per_verification: Balance,
+		pub free_verification_tier: bool,
+		pub summarize_batch_verifications: bool,
+		pub skip_self_verification_events: bool,
+		pub verification_policy: VerificationPolicy,
+		pub require_verification_before_dispute: bool,
+		pub verification_validity: BlockNumber,
+		pub max_linked_accounts: u32,
+		pub max_registrations_per_block: u32,
+		pub max_failed_verifications: u32,
+		pub failed_verification_window: BlockNumber,
+		pub deactivate_flagged_identities: bool,
+		pub resolution_delay: BlockNumber,
+		pub max_scheduled_resolutions: u32,
+		pub max_resolutions_per_block: u32,
+		pub milestone_interval: u32,
+		pub enforce_gateway_allowlist: bool,
+		pub max_comment_len: u32,
+		pub max_comments_per_dispute: u32,
+		pub max_voters_per_dispute: u32,
+		pub max_delegators: u32,
+		pub min_distinct_voters: u32,
+		pub dispute_voting_period: BlockNumber,
+		pub voting_window: BlockNumber,
+		pub recovery_delay: BlockNumber,
+		pub max_sweep_per_call: u32,
+		pub sweep_reward: Balance,
 	}
 
+	/// Pallet constants as configured for runtime `T`
+	pub type ConstantsOf<T> = ProofOfFaceConstants<BlockNumberFor<T>, BalanceOf<T>>;
+
 	// Storage for identity proofs mapped by account ID
 	#[pallet::storage]
 	#[pallet::getter(fn identity_proofs)]
@@ -112,7 +674,7 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		BiometricProof<T::AccountId, T::Hash, BlockNumberFor<T>>,
+		BiometricProofOf<T>,
 		OptionQuery,
 	>;
 
@@ -127,6 +689,39 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Expiry index: block number to the accounts whose identities expire at that block
+	#[pallet::storage]
+	#[pallet::getter(fn identity_expiries)]
+	pub type IdentityExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::AccountId, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Resolution index: block number to the disputes that settle at that block
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_resolutions)]
+	pub type ScheduledResolutions<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u64, T::MaxScheduledResolutions>,
+		ValueQuery,
+	>;
+
+	/// Reputation score per account, always within `[0, MaxReputation]`
+	#[pallet::storage]
+	#[pallet::getter(fn reputation)]
+	pub type Reputation<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
 	/// Storage for disputes mapped by dispute ID
 	#[pallet::storage]
 	#[pallet::getter(fn disputes)]
@@ -134,15 +729,41 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		u64,
-		Dispute<T::AccountId, T::Hash, BlockNumberFor<T>>,
+		Dispute<T>,
 		OptionQuery,
 	>;
 
+	/// Number of open disputes, whether pending or waiting out `ResolutionDelay`
+	#[pallet::storage]
+	#[pallet::getter(fn pending_dispute_count)]
+	pub type PendingDisputeCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of disputes settled as `Resolved`
+	#[pallet::storage]
+	#[pallet::getter(fn resolved_dispute_count)]
+	pub type ResolvedDisputeCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of disputes settled as `Rejected`, including expired and overturned ones
+	#[pallet::storage]
+	#[pallet::getter(fn rejected_dispute_count)]
+	pub type RejectedDisputeCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// Counter for generating unique dispute IDs
 	#[pallet::storage]
 	#[pallet::getter(fn next_dispute_id)]
 	pub type NextDisputeId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// IDs of the disputes opened by each account, in creation order
+	#[pallet::storage]
+	#[pallet::getter(fn created_disputes)]
+	pub type CreatedDisputes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u64, T::MaxCreatedDisputes>,
+		ValueQuery,
+	>;
+
 	/// Tracking votes per dispute per account to prevent double voting
 	#[pallet::storage]
 	#[pallet::getter(fn dispute_votes)]
@@ -156,64 +777,727 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
-	// Pallets use events to inform users when important changes are made.
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// Identity registered successfully
-		/// [account_id, biometric_hash]
-		IdentityRegistered(T::AccountId, T::Hash),
-		
-		/// Verification performed against a biometric hash
-		/// [biometric_hash, verification_result]
-		VerificationPerformed(T::Hash, bool),
-		
-		/// Dispute created against an identity
-		/// [dispute_id, creator_account]
-		DisputeCreated(u64, T::AccountId),
-		
-		/// Vote cast on a dispute
-		/// [dispute_id, voter_account, vote_for]
-		DisputeVoted(u64, T::AccountId, bool),
-		
-		/// Dispute resolved with final status
-		/// [dispute_id, final_status]
-		DisputeResolved(u64, DisputeStatus),
-	}
+	/// Voters of each dispute in the order their votes were cast, alongside `DisputeVotes`
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_voter_order)]
+	pub type DisputeVoterOrder<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u64,
+		BoundedVec<T::AccountId, T::MaxVotersPerDispute>,
+		ValueQuery,
+	>;
 
-	// Errors inform users that something went wrong.
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Identity already exists for this account
-		IdentityAlreadyExists,
-		/// Identity not found
-		IdentityNotFound,
-		/// Invalid biometric hash format or content (hash already registered to another account)
-		InvalidBiometricHash,
-		/// Dispute not found
-		DisputeNotFound,
-		/// Not authorized to perform this action
-		NotAuthorized,
-		/// Dispute has already been resolved
-		DisputeAlreadyResolved,
-		/// Account has already voted on this dispute
-		AlreadyVoted,
-		/// Cannot dispute your own identity
-		CannotDisputeOwnIdentity,
-		/// Invalid IPFS CID format or empty CID
-		InvalidIpfsCid,
-		/// Invalid evidence URL format
-		InvalidEvidenceUrl,
-	}
-	
-	// Dispatchable functions allow users to interact with the pallet and invoke state changes.
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Register a new biometric proof (face identity)
-		///
-		/// This function allows users to register their face identity on-chain by providing
-		/// a biometric hash (SHA-256 of face embeddings) and an IPFS CID pointing to
-		/// the encrypted face data stored off-chain.
+	/// The delegate each account has handed its dispute vote to
+	#[pallet::storage]
+	#[pallet::getter(fn vote_delegate)]
+	pub type VoteDelegates<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// Accounts delegating their dispute vote to each delegate, alongside `VoteDelegates`
+	#[pallet::storage]
+	#[pallet::getter(fn delegators_of)]
+	pub type Delegators<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxDelegators>,
+		ValueQuery,
+	>;
+
+	/// Delegators whose weight a delegate's vote carried on each dispute, with that vote
+	///
+	/// A delegator who then votes directly takes their weight back out of the tally.
+	#[pallet::storage]
+	#[pallet::getter(fn delegated_votes)]
+	pub type DelegatedVotes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64, // Dispute ID
+		Blake2_128Concat,
+		T::AccountId, // Delegator
+		bool, // Vote cast on their behalf
+		OptionQuery,
+	>;
+
+	/// Jurors drawn for each dispute when jury mode is enabled
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_jury)]
+	pub type DisputeJury<T: Config> =
+		StorageMap<_, Blake2_128Concat, u64, BoundedVec<T::AccountId, T::JurySize>, OptionQuery>;
+
+	/// Prepaid verifications remaining for each account
+	#[pallet::storage]
+	#[pallet::getter(fn verification_allowance)]
+	pub type VerificationAllowance<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Number of pending disputes against each account's identity
+	#[pallet::storage]
+	#[pallet::getter(fn open_disputes)]
+	pub type OpenDisputesAgainst<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Number of pending disputes against each biometric hash, kept even once the hash is
+	/// released by its owner
+	#[pallet::storage]
+	#[pallet::getter(fn open_disputes_by_hash)]
+	pub type OpenDisputesByHash<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, u32, ValueQuery>;
+
+	/// Block of each account's latest verification of a hash, kept only while
+	/// `RequireVerificationBeforeDispute` is on and consumed when a dispute is opened
+	#[pallet::storage]
+	#[pallet::getter(fn recent_verification)]
+	pub type RecentVerifications<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::Hash,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Failed face matches against each identity in its current window, with the block the
+	/// window started at
+	#[pallet::storage]
+	#[pallet::getter(fn failed_verifications)]
+	pub type FailedVerifications<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (u32, BlockNumberFor<T>), ValueQuery>;
+
+	/// Public handle chosen by each identity owner
+	#[pallet::storage]
+	#[pallet::getter(fn handles)]
+	pub type Handles<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, HandleOf<T>, OptionQuery>;
+
+	/// Note each identity owner attached with `set_note`, such as an encrypted recovery hint
+	///
+	/// The pallet stores the bytes as given and never interprets them.
+	#[pallet::storage]
+	#[pallet::getter(fn note_of)]
+	pub type IdentityNotes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, NoteOf<T>, OptionQuery>;
+
+	/// Reverse lookup: handle to the account that holds it, keeping handles unique
+	#[pallet::storage]
+	#[pallet::getter(fn handle_to_account)]
+	pub type HandleToAccount<T: Config> =
+		StorageMap<_, Blake2_128Concat, HandleOf<T>, T::AccountId, OptionQuery>;
+
+	/// Secondary accounts linked to each identity owner, recognized as the same person
+	#[pallet::storage]
+	pub type LinkedAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId, // Primary (identity owner)
+		Blake2_128Concat,
+		T::AccountId, // Secondary
+		(),
+		OptionQuery,
+	>;
+
+	/// Reverse lookup: linked secondary account to the identity owner it resolves to
+	#[pallet::storage]
+	#[pallet::getter(fn linked_to_primary)]
+	pub type LinkedToPrimary<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// Number of times each secondary account was linked to each primary, signed into the
+	/// secondary's consent so that a consent cannot be replayed after an unlink
+	#[pallet::storage]
+	#[pallet::getter(fn link_nonce)]
+	pub type LinkNonces<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Deposit currently reserved from each registrant, released when the identity is purged
+	#[pallet::storage]
+	#[pallet::getter(fn registration_deposit)]
+	pub type RegistrationDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	/// Identities registered in the current block, reset in `on_initialize`
+	#[pallet::storage]
+	#[pallet::getter(fn registrations_this_block)]
+	pub type RegistrationsThisBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of identities currently stored
+	#[pallet::storage]
+	#[pallet::getter(fn total_identities)]
+	pub type TotalIdentities<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The account behind the most recent registration, and the block it registered in
+	#[pallet::storage]
+	#[pallet::getter(fn last_registered)]
+	pub type LastRegistered<T: Config> =
+		StorageValue<_, (T::AccountId, BlockNumberFor<T>), OptionQuery>;
+
+	/// Highest identity count for which `IdentityMilestone` was emitted
+	#[pallet::storage]
+	#[pallet::getter(fn last_milestone)]
+	pub type LastMilestone<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of successful verifications of each account's identity, reported as the nonce of
+	/// `VerificationPerformed`
+	#[pallet::storage]
+	#[pallet::getter(fn verification_nonce)]
+	pub type VerificationNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// Accounts that have successfully verified each identity, keyed by owner then verifier
+	#[pallet::storage]
+	pub type IdentityVerifiers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Number of distinct verifiers in `IdentityVerifiers` for each identity
+	#[pallet::storage]
+	#[pallet::getter(fn unique_verifier_count)]
+	pub type UniqueVerifierCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Recovery account designated by each identity owner
+	#[pallet::storage]
+	#[pallet::getter(fn recovery_account)]
+	pub type RecoveryAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// Block in which the recovery account of each identity initiated taking it over
+	#[pallet::storage]
+	#[pallet::getter(fn pending_recovery)]
+	pub type PendingRecoveries<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Lowest dispute ID that `sweep_expired_disputes` has not yet looked past
+	#[pallet::storage]
+	#[pallet::getter(fn sweep_cursor)]
+	pub type SweepCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Merkle roots of biometric hash batches committed by `ForceOrigin`, with the block they
+	/// were committed in
+	#[pallet::storage]
+	#[pallet::getter(fn batch_commitment)]
+	pub type BatchCommitments<T: Config> =
+		StorageMap<_, Identity, T::Hash, BlockNumberFor<T>, OptionQuery>;
+
+	/// Merkle roots of result batches attested by verifiers, with the block they were
+	/// attested in
+	#[pallet::storage]
+	#[pallet::getter(fn attestation_root)]
+	pub type AttestationRoots<T: Config> =
+		StorageMap<_, Identity, T::Hash, BlockNumberFor<T>, OptionQuery>;
+
+	/// Emergency pause: while set, every extrinsic except the `ForceOrigin` ones fails with
+	/// `PalletHalted`
+	#[pallet::storage]
+	#[pallet::getter(fn halted)]
+	pub type Halted<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Gateway hosts identities may use when `EnforceGatewayAllowlist` is set
+	#[pallet::storage]
+	#[pallet::getter(fn allowed_gateway)]
+	pub type AllowedGateways<T: Config> =
+		StorageMap<_, Blake2_128Concat, GatewayOf<T>, (), OptionQuery>;
+
+	/// Comments on each dispute by index, with their author
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_comment)]
+	pub type DisputeComments<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64, // Dispute ID
+		Twox64Concat,
+		u32, // Comment index
+		(T::AccountId, CommentOf<T>),
+		OptionQuery,
+	>;
+
+	/// Number of comments left on each dispute, which is also the next comment's index
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_comment_count)]
+	pub type DisputeCommentCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, u64, u32, ValueQuery>;
+
+	/// Dispute whose resolution deactivated each account's identity, while it stays inactive
+	#[pallet::storage]
+	#[pallet::getter(fn deactivated_by_dispute)]
+	pub type DeactivatedByDispute<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
+	/// Block each account's identity was deactivated for being flagged, while its owner may
+	/// not reactivate it yet
+	#[pallet::storage]
+	#[pallet::getter(fn deactivated_by_flag)]
+	pub type DeactivatedByFlag<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Accounts whose identity a `force_deactivate_hashes` takedown deactivated, until
+	/// `lift_takedown`
+	#[pallet::storage]
+	#[pallet::getter(fn taken_down)]
+	pub type TakenDown<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Hashes of identities merged into another: surviving account, merged hash to the
+	/// account that originally registered it
+	#[pallet::storage]
+	pub type MergedHashes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::Hash,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// Sequence number the next pallet event will carry
+	///
+	/// Counting starts at 0 with the first event after the runtime upgrade that introduced
+	/// sequence numbers; events from before that upgrade carry none.
+	/// Nothing needs migrating, as the value is absent until then and reads as 0.
+	#[pallet::storage]
+	#[pallet::getter(fn event_seq)]
+	pub type EventSeq<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	// Pallets use events to inform users when important changes are made.
+	//
+	// Every event carries a global sequence number as its first field, incremented by one
+	// per event, so indexers can spot gaps and order events across reorgs. On a chain that
+	// predates sequence numbers, `EventSeq` restarts at 0 from the upgrade that added them.
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Identity registered successfully
+		/// [seq, account_id, biometric_hash]
+		IdentityRegistered(u64, T::AccountId, T::Hash),
+		
+		/// Verification performed against a biometric hash; `nonce` counts the verifications
+		/// of the matched identity from 1, so gaps reveal missed events (zero on a miss)
+		/// [seq, biometric_hash, verification_result, nonce]
+		VerificationPerformed(u64, T::Hash, bool, u64),
+		
+		/// Dispute created against an identity
+		/// [seq, dispute_id, creator_account, category]
+		DisputeCreated(u64, u64, T::AccountId, DisputeCategory),
+		
+		/// Vote cast on a dispute
+		/// [seq, dispute_id, voter_account, vote_for]
+		DisputeVoted(u64, u64, T::AccountId, bool),
+		
+		/// Dispute resolved with final status, vote tally and what settled it
+		/// [seq, dispute_id, final_status, votes_for, votes_against, reason]
+		DisputeResolved(u64, u64, DisputeStatus, u32, u32, ResolutionReason),
+
+		/// Identity expiry extended by its owner
+		/// [seq, account_id, expires_at]
+		IdentityRefreshed(u64, T::AccountId, BlockNumberFor<T>),
+
+		/// Identity deactivated because it was not refreshed in time
+		/// [seq, account_id]
+		IdentityExpired(u64, T::AccountId),
+
+		/// Bulk import finished
+		/// [seq, inserted, skipped]
+		BatchRegistered(u64, u32, u32),
+
+		/// Batch of verifications summarized by the ordered trie root of their
+		/// `(biometric_hash, matched, nonce)` results
+		/// [seq, root_hash, matched_count, total]
+		BatchVerificationSummary(u64, T::Hash, u32, u32),
+
+		/// Dispute reached quorum with equal votes; the tie rule decides the outcome
+		/// [seq, dispute_id]
+		DisputeTied(u64, u64),
+
+		/// Evidence URL of a pending dispute replaced by its creator
+		/// [seq, dispute_id]
+		EvidenceUpdated(u64, u64),
+
+		/// Additional evidence attached to a pending dispute
+		/// [seq, dispute_id, evidence_count]
+		EvidenceAdded(u64, u64, u32),
+
+		/// Prepaid verifications bought
+		/// [seq, account_id, count, cost]
+		VerificationsPurchased(u64, T::AccountId, u32, BalanceOf<T>),
+
+		/// Owner of a disputed identity acknowledged the dispute
+		/// [seq, dispute_id, owner]
+		DisputeAcknowledged(u64, u64, T::AccountId),
+
+		/// Identity registered in the ink contract claimed into the pallet
+		/// [seq, account_id, biometric_hash]
+		IdentityMigrated(u64, T::AccountId, T::Hash),
+
+		/// Preferred IPFS gateway of an identity set or cleared by its owner
+		/// [seq, account_id]
+		GatewayUpdated(u64, T::AccountId),
+
+		/// Gateway host added to the allowlist
+		/// [seq, host]
+		GatewayAllowed(u64, GatewayOf<T>),
+
+		/// Gateway host removed from the allowlist
+		/// [seq, host]
+		GatewayDisallowed(u64, GatewayOf<T>),
+
+		/// Identity failed too many face matches within one window and may be a spoofing target
+		/// [seq, account_id]
+		IdentityFlagged(u64, T::AccountId),
+
+		/// Dispute reached quorum and settles on its tally at the given block
+		/// [seq, dispute_id, resolves_at]
+		DisputeReadyToResolve(u64, u64, BlockNumberFor<T>),
+
+		/// Secondary account linked to an identity owner
+		/// [seq, primary, secondary]
+		AccountLinked(u64, T::AccountId, T::AccountId),
+
+		/// Secondary account unlinked from an identity owner
+		/// [seq, primary, secondary]
+		AccountUnlinked(u64, T::AccountId, T::AccountId),
+
+		/// Identity owner set their public handle
+		/// [seq, account_id, handle]
+		HandleSet(u64, T::AccountId, HandleOf<T>),
+
+		/// Identity removed by its owner or `ForceOrigin`, freeing its hash and returning its
+		/// deposit
+		/// [seq, account_id, biometric_hash]
+		IdentityPurged(u64, T::AccountId, T::Hash),
+
+		/// Number of stored identities reached a multiple of `MilestoneInterval`
+		/// [seq, count]
+		IdentityMilestone(u64, u32),
+
+		/// Verifier reported a spoofed match and disputed the matched identity
+		/// [seq, reporter, biometric_hash, dispute_id]
+		SpoofReported(u64, T::AccountId, T::Hash, u64),
+
+		/// Participant commented on an open dispute
+		/// [seq, dispute_id, author, index]
+		CommentAdded(u64, u64, T::AccountId, u32),
+
+		/// Identity reactivated because the dispute that deactivated it was overturned
+		/// [seq, account_id, dispute_id]
+		IdentityReactivatedByAppeal(u64, T::AccountId, u64),
+
+		/// Second identity of a person folded into their first by `ForceOrigin`
+		/// [seq, keep, drop]
+		IdentitiesMerged(u64, T::AccountId, T::AccountId),
+
+		/// Pending dispute outlived `DisputeVotingPeriod` and `VotingWindow` without reaching
+		/// quorum and was rejected
+		/// [seq, dispute_id]
+		DisputeExpired(u64, u64),
+
+		/// Creator of a pending dispute handed it over to another account
+		/// [seq, dispute_id, old_creator, new_creator]
+		DisputeReassigned(u64, u64, T::AccountId, T::AccountId),
+
+		/// Merkle root of a batch of biometric hashes committed for later registration
+		/// [seq, root]
+		BatchCommitted(u64, T::Hash),
+
+		/// Identity moved to a new biometric hash and IPFS CID in one step
+		/// [seq, account_id, old_hash, new_hash]
+		IdentityReenrolled(u64, T::AccountId, T::Hash, T::Hash),
+
+		/// Pallet halted or resumed by `ForceOrigin`
+		/// [seq, halted]
+		HaltedSet(u64, bool),
+
+		/// Open dispute settled by `ForceOrigin` regardless of its tally
+		/// [seq, dispute_id, in_favor]
+		DisputeForceResolved(u64, u64, bool),
+
+		/// Identity owner designated a recovery account, or removed it if `None`
+		/// [seq, account_id, recovery]
+		RecoverySet(u64, T::AccountId, Option<T::AccountId>),
+
+		/// Identity moved from a lost account to its recovery account
+		/// [seq, lost_account, recovery]
+		IdentityRecovered(u64, T::AccountId, T::AccountId),
+
+		/// Identity owner switched private mode on or off
+		/// [seq, account_id, private]
+		PrivacySet(u64, T::AccountId, bool),
+
+		/// Open dispute frozen by `ForceOrigin`
+		/// [seq, dispute_id]
+		DisputeFrozen(u64, u64),
+
+		/// Frozen dispute released by `ForceOrigin`
+		/// [seq, dispute_id]
+		DisputeUnfrozen(u64, u64),
+
+		/// Account delegated its dispute vote
+		/// [seq, delegator, delegate]
+		VoteDelegated(u64, T::AccountId, T::AccountId),
+
+		/// Account took its dispute vote back from its delegate
+		/// [seq, delegator, delegate]
+		VoteUndelegated(u64, T::AccountId, T::AccountId),
+
+		/// Takedown list processed by `ForceOrigin`
+		/// [seq, deactivated]
+		HashesForceDeactivated(u64, u32),
+
+		/// Identity owner set or cleared their note
+		/// [seq, account_id]
+		NoteSet(u64, T::AccountId),
+
+		/// Verifier attested a batch of off-chain results by their Merkle root
+		/// [seq, results_root, block_number]
+		BatchAttested(u64, T::Hash, BlockNumberFor<T>),
+
+		/// Resolved dispute overturned to `Rejected` on appeal
+		/// [seq, dispute_id]
+		DisputeOverturned(u64, u64),
+
+		/// Recovery account started the `RecoveryDelay` to take over a lost identity
+		/// [seq, lost_account, recovery]
+		RecoveryInitiated(u64, T::AccountId, T::AccountId),
+
+		/// Identity owner cancelled a pending recovery of their identity
+		/// [seq, account_id]
+		RecoveryCancelled(u64, T::AccountId),
+
+		/// Hash merged into an identity freed along with that identity
+		/// [seq, account_id, biometric_hash]
+		MergedHashReleased(u64, T::AccountId, T::Hash),
+
+		/// Takedown of an identity lifted, so its owner can reactivate it
+		/// [seq, account_id]
+		TakedownLifted(u64, T::AccountId),
+	}
+
+	impl<T: Config> Event<T> {
+		/// Name of the event's variant, as listed in the pallet metadata
+		pub fn variant_name(&self) -> Option<&'static str> {
+			let index = *self.encode().first()?;
+			match Self::type_info().type_def {
+				scale_info::TypeDef::Variant(def) => def
+					.variants
+					.into_iter()
+					.find(|variant| variant.index == index)
+					.map(|variant| variant.name),
+				_ => None,
+			}
+		}
+
+		/// Sequence number the event was emitted with
+		pub fn seq(&self) -> u64 {
+			// Every variant starts with the sequence number, right after the variant index
+			let encoded = self.encode();
+			u64::decode(&mut encoded.get(1..).unwrap_or_default()).unwrap_or_default()
+		}
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Identity already exists for this account
+		IdentityAlreadyExists,
+		/// Identity not found
+		IdentityNotFound,
+		/// Invalid biometric hash format or content (hash already registered to another account)
+		InvalidBiometricHash,
+		/// Dispute not found
+		DisputeNotFound,
+		/// Not authorized to perform this action
+		NotAuthorized,
+		/// Dispute has already been resolved
+		DisputeAlreadyResolved,
+		/// Account has already voted on this dispute
+		AlreadyVoted,
+		/// Cannot dispute your own identity
+		CannotDisputeOwnIdentity,
+		/// Invalid IPFS CID format or empty CID
+		InvalidIpfsCid,
+		/// Invalid evidence URL format
+		InvalidEvidenceUrl,
+		/// Too many identities are already scheduled to expire in the target block
+		ExpiryQueueFull,
+		/// Account has reached the maximum number of disputes it can open
+		TooManyDisputes,
+		/// Identity was registered too recently to be disputed
+		IdentityTooNew,
+		/// Not enough eligible identities to draw a full jury
+		NotEnoughJurors,
+		/// Caller has no prepaid verifications left
+		NoVerificationAllowance,
+		/// Dispute has already been acknowledged by the identity owner
+		AlreadyAcknowledged,
+		/// Dispute already holds the maximum number of evidence items
+		TooManyEvidenceItems,
+		/// Caller must verify the disputed hash shortly before opening a dispute
+		VerificationRequired,
+		/// Biometric hash is registered to an account that has no stored proof
+		ProofMissing,
+		/// Migration attestation is not signed by the migration authority for this registration
+		InvalidMigrationProof,
+		/// The network-wide registration limit for this block has been reached
+		RegistrationRateLimited,
+		/// Gateway is not an http(s) URL prefix with a host
+		InvalidGateway,
+		/// Gateway host is not in the allowlist
+		GatewayNotAllowed,
+		/// Too many disputes are already scheduled to settle in the target block
+		ResolutionQueueFull,
+		/// Account is already linked to an identity
+		AccountAlreadyLinked,
+		/// An account cannot be linked to itself
+		CannotLinkSelf,
+		/// Link consent is not signed by the secondary account for this primary
+		InvalidLinkProof,
+		/// Identity already has the maximum number of linked accounts
+		TooManyLinkedAccounts,
+		/// Handle is already held by another account
+		HandleTaken,
+		/// Handle is empty or contains whitespace or control bytes
+		InvalidHandle,
+		/// Identity cannot be purged while disputes against it are still open
+		IdentityUnderDispute,
+		/// Comment is empty
+		InvalidComment,
+		/// Dispute already holds `MaxCommentsPerDispute` comments
+		TooManyComments,
+		/// An identity cannot be merged into itself
+		CannotMergeSelf,
+		/// Dispute already received `MaxVotersPerDispute` votes
+		TooManyVoters,
+		/// Reassignment proof is not the new creator's consent to take over this dispute
+		InvalidReassignProof,
+		/// Inclusion proof does not lead to a committed batch root
+		InvalidMerkleProof,
+		/// The pallet is halted and only accepts `ForceOrigin` calls
+		PalletHalted,
+		/// An identity cannot designate its own account for recovery
+		InvalidRecoveryAccount,
+		/// `RecoveryDelay` has not passed since the recovery was initiated
+		RecoveryTooEarly,
+		/// The dispute is frozen
+		DisputeFrozen,
+		/// The dispute is not frozen
+		DisputeNotFrozen,
+		/// The dispute's `VotingWindow` has closed
+		VotingClosed,
+		/// The dispute's votes do not meet the quorum
+		QuorumNotReached,
+		/// An account cannot delegate its vote to itself
+		CannotDelegateToSelf,
+		/// The delegate already has `MaxDelegators` delegators
+		TooManyDelegators,
+		/// The account has not delegated its vote
+		NotDelegating,
+		/// The results root has already been attested
+		AlreadyAttested,
+		/// The biometric hash has an open dispute and cannot be registered until it settles
+		HashUnderDispute,
+		/// The identity has no pending recovery
+		RecoveryNotInitiated,
+		/// A recovery of the identity is already pending
+		RecoveryAlreadyInitiated,
+		/// The identity has expired and must be refreshed before it can be reactivated
+		IdentityExpired,
+		/// The identity was taken down and stays inactive until the takedown is lifted
+		IdentityTakenDown,
+		/// The identity is not taken down
+		NotTakenDown,
+		/// The identity was deactivated for being flagged and `FailedVerificationWindow` has
+		/// not passed since
+		IdentityDeactivatedByFlag,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Reset the registration counter, deactivate the identities whose expiry falls on this
+		/// block and settle the disputes whose resolution delay ends here
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			RegistrationsThisBlock::<T>::kill();
+
+			let expiring = IdentityExpiries::<T>::take(n);
+			let mut reads = 1u64;
+			let mut writes = 2u64;
+
+			for who in expiring.iter() {
+				reads += 1;
+				if let Some(mut proof) = IdentityProofs::<T>::get(who) {
+					// Entries left behind by a refresh no longer match the stored expiry
+					if proof.expires_at == Some(n) && proof.is_active {
+						proof.is_active = false;
+						IdentityProofs::<T>::insert(who, &proof);
+						writes += 1;
+						Self::deposit_event(Event::IdentityExpired(
+							Self::next_event_seq(),
+							who.clone(),
+						));
+					}
+				}
+			}
+
+			reads += 1;
+			writes += 1;
+			let mut settled = 0u32;
+			let mut deferred = Vec::new();
+			for dispute_id in ScheduledResolutions::<T>::take(n) {
+				if settled >= T::MaxResolutionsPerBlock::get() {
+					deferred.push(dispute_id);
+					continue;
+				}
+				reads += 1;
+				if let Some(mut dispute) = Disputes::<T>::get(dispute_id) {
+					if dispute.status == DisputeStatus::ReadyToResolve {
+						let voters = Self::settle_dispute(dispute_id, &mut dispute) as u64;
+						Disputes::<T>::insert(dispute_id, &dispute);
+						settled += 1;
+						reads += 3 + 3 * voters;
+						writes += 3 + voters;
+					}
+				}
+			}
+			let slots = Self::defer_resolutions(n, deferred);
+			reads += slots;
+			writes += slots;
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::ensure_unique_hashes()
+		}
+	}
+	
+	// Dispatchable functions allow users to interact with the pallet and invoke state changes.
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a new biometric proof (face identity)
+		///
+		/// This function allows users to register their face identity on-chain by providing
+		/// a biometric hash (SHA-256 of face embeddings) and an IPFS CID pointing to
+		/// the encrypted face data stored off-chain.
 		///
 		/// # Parameters
 		/// - `origin`: The account registering the identity (must be signed)
@@ -221,11 +1505,18 @@ pub mod pallet {
 		/// - `ipfs_cid`: IPFS Content Identifier for the stored face data
 		///
 		/// # Errors
+		/// - `NotAuthorized`: If `RegistrationGate` rejects the caller
 		/// - `IdentityAlreadyExists`: If the account already has a registered identity
 		/// - `InvalidBiometricHash`: If the biometric hash is already registered to another account
-		/// - `InvalidIpfsCid`: If the IPFS CID is empty or invalid format
+		///   or fails `HashValidator`
+		/// - `HashUnderDispute`: If the hash has an open dispute and `RejectDisputedHashes` is on
+		/// - `InvalidIpfsCid`: If the IPFS CID is shorter than `MinIpfsCidLength` or empty
+		/// - `ExpiryQueueFull`: If no more identities can expire in the same block
+		/// - `RegistrationRateLimited`: If `MaxRegistrationsPerBlock` identities were already
+		///   registered in this block
+		/// - Currency error: If `RegistrationDeposit` cannot be reserved from the caller
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::register_identity(ipfs_cid.len() as u32))]
 		pub fn register_identity(
 			origin: OriginFor<T>,
 			biometric_hash: T::Hash,
@@ -233,285 +1524,3000 @@ pub mod pallet {
 		) -> DispatchResult {
 			// Step 1: Ensure the origin is signed and get the AccountId
 			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+			ensure!(T::RegistrationGate::is_allowed(&who), Error::<T>::NotAuthorized);
+
+			// Steps 2-7: Validate the registration and store it
+			Self::do_register_identity(who, biometric_hash, ipfs_cid)
+		}
+
+		/// Perform verification against a registered biometric hash
+		///
+		/// This function checks if a given biometric hash exists in the system and emits
+		/// an audit event with the verification result. This is primarily used by the AI
+		/// service to verify face matches against registered identities.
+		///
+		/// # Audit Trail Concept
+		/// Even though this is essentially a read operation, we emit events to create
+		/// an immutable audit trail of all verification attempts. This serves multiple purposes:
+		/// - Compliance: Regulatory requirements for identity verification logging
+		/// - Security: Detection of potential brute force or enumeration attacks
+		/// - Analytics: Understanding verification patterns and system usage
+		/// - Transparency: Users can see when their identity was used for verification
+		///
+		/// # Parameters
+		/// - `origin`: The account performing the verification (must pass `VerifierOrigin`)
+		/// - `biometric_hash`: The biometric hash to verify against registered identities
+		///
+		/// # Returns
+		/// Returns `Ok` whether or not the hash matches - a miss is not an error, and is charged
+		/// the lower `verify_identity_miss` weight
+		///
+		/// # Errors
+		/// - `NoVerificationAllowance`: If the free tier is off and the caller has no prepaid
+		///   verifications left
+		///
+		/// # Events
+		/// - `VerificationPerformed(biometric_hash, true, nonce)`: If hash exists in system, with
+		///   the matched identity's next verification nonce
+		/// - `VerificationPerformed(biometric_hash, false, 0)`: If hash not found, or its owner
+		///   does not match under `VerificationPolicy`
+		///
+		/// With `SkipSelfVerificationEvents`, an owner verifying their own hash emits no event
+		/// and takes no nonce, so the nonces seen in events stay gapless. Owners never count
+		/// among the verifiers of their own identity.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::verify_identity())]
+		pub fn verify_identity(
+			origin: OriginFor<T>,
+			biometric_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			// Step 1: Ensure origin is an allowed verifier
+			// The origin must resolve to an account to create accountability for verification attempts
+			let who = T::VerifierOrigin::ensure_origin(origin)?;
+			Self::ensure_not_halted()?;
+
+			// Step 1b: Outside the free tier, each verification uses up one prepaid verification
+			if !T::FreeVerificationTier::get() {
+				VerificationAllowance::<T>::try_mutate(&who, |allowance| -> DispatchResult {
+					*allowance =
+						allowance.checked_sub(1).ok_or(Error::<T>::NoVerificationAllowance)?;
+					Ok(())
+				})?;
+			}
+
+			// Step 2: Check if biometric_hash exists in BiometricHashToOwner storage
+			// This is the core verification logic - does this biometric hash belong to any registered identity?
+			// Identities that `VerificationPolicy` excludes are treated as not found
+			let owner = Self::visible_owner(&biometric_hash);
+
+			// Remember the attempt so the caller can back a dispute with it
+			if T::RequireVerificationBeforeDispute::get() {
+				let now = <frame_system::Pallet<T>>::block_number();
+				RecentVerifications::<T>::insert(&who, &biometric_hash, now);
+			}
+
+			// Step 3 & 4: Emit audit event based on verification result
+			// This creates an immutable record of the verification attempt on the blockchain
+			// The event includes both the hash being verified and whether it was found
+			if let Some(owner) = owner {
+				// Count each verifier other than the owner once per identity
+				if owner != who && !IdentityVerifiers::<T>::contains_key(&owner, &who) {
+					IdentityVerifiers::<T>::insert(&owner, &who, ());
+					UniqueVerifierCount::<T>::mutate(&owner, |count| {
+						*count = count.saturating_add(1)
+					});
+				}
+				if owner != who || !T::SkipSelfVerificationEvents::get() {
+					// Hash found - successful verification, numbered per identity
+					let nonce = VerificationNonces::<T>::mutate(&owner, |nonce| {
+						*nonce = nonce.saturating_add(1);
+						*nonce
+					});
+					Self::deposit_event(Event::VerificationPerformed(
+						Self::next_event_seq(),
+						biometric_hash,
+						true,
+						nonce,
+					));
+				}
+			} else {
+				// Hash not found - no matching identity
+				Self::deposit_event(Event::VerificationPerformed(
+					Self::next_event_seq(),
+					biometric_hash,
+					false,
+					0,
+				));
+
+				// A miss does less work than a match, so refund the difference
+				return Ok(Some(T::WeightInfo::verify_identity_miss()).into())
+			}
+
+			// Always return Ok - verification failure is not an error condition
+			// The caller can determine success/failure from the emitted event
+			Ok(().into())
+		}
+
+		/// Create a dispute against a biometric proof
+		///
+		/// In jury mode this is charged for examining `MaxJuryCandidates` identities.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::raise_dispute(Self::jury_candidates_bound()))]
+		pub fn create_dispute(
+			origin: OriginFor<T>,
+			face_proof_id: T::Hash,
+			category: DisputeCategory,
+			evidence_url: EvidenceUrlOf<T>,
+		) -> DispatchResult {
+			// 1. Ensure origin is signed
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			// 2-8. Validate the dispute and store it
+			Self::do_create_dispute(who, face_proof_id, category, evidence_url)?;
+			Ok(())
+		}
+
+		/// Vote on an open dispute
+		///
+		/// Outside jury mode the vote also carries the weight of the voter's delegators who have
+		/// not voted on the dispute themselves. A delegator voting directly takes their weight
+		/// back from their delegate's vote.
+		///
+		/// Charged as if the vote resolves the dispute; votes that leave it pending are refunded
+		/// down to `vote_on_dispute_no_resolution`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::vote_on_dispute_resolving()
+			.saturating_add(T::WeightInfo::apply_delegated_votes(T::MaxDelegators::get()))
+			.saturating_add(T::WeightInfo::reward_voters(T::MaxVotersPerDispute::get())))]
+		pub fn vote_on_dispute(
+			origin: OriginFor<T>,
+			dispute_id: u64,
+			vote: bool, // true = agree it's unauthorized, false = disagree
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			// Get dispute info
+			let mut dispute = Disputes::<T>::get(dispute_id)
+				.ok_or(Error::<T>::DisputeNotFound)?;
+
+			// Ensure dispute is not settled yet (votes still count while it waits to resolve)
+			ensure!(dispute.status.is_open(), Error::<T>::DisputeAlreadyResolved);
+			ensure!(!dispute.frozen, Error::<T>::DisputeFrozen);
+
+			// Votes stop once the voting window closes; the expiry sweep settles the dispute
+			let window = T::VotingWindow::get();
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				window.is_zero() || now <= dispute.created_at.saturating_add(window),
+				Error::<T>::VotingClosed
+			);
+
+			// In jury mode only the drawn jurors may vote, and only for themselves
+			let jury = DisputeJury::<T>::get(dispute_id);
+			ensure!(
+				jury.as_ref().map_or(true, |jury| jury.contains(&who)),
+				Error::<T>::NotAuthorized
+			);
+			let (quorum, min_voters) = Self::quorum_of(dispute_id, &dispute);
+
+			// Ensure hasn't already voted
+			ensure!(
+				!DisputeVotes::<T>::contains_key(dispute_id, &who),
+				Error::<T>::AlreadyVoted
+			);
+
+			// Record the vote, keeping track of the order votes are cast in
+			let distinct_voters = DisputeVoterOrder::<T>::try_mutate(dispute_id, |voters| {
+				voters.try_push(who.clone()).map(|_| voters.len() as u32)
+			})
+			.map_err(|_| Error::<T>::TooManyVoters)?;
+			DisputeVotes::<T>::insert(dispute_id, &who, vote);
+
+			// A delegator voting directly takes back the weight their delegate cast for them
+			match DelegatedVotes::<T>::take(dispute_id, &who) {
+				Some(true) => dispute.votes_for = dispute.votes_for.saturating_sub(1),
+				Some(false) => dispute.votes_against = dispute.votes_against.saturating_sub(1),
+				None => {},
+			}
+
+			// Update vote counts, adding the weight of the voter's delegators
+			let (delegators, weight) = if jury.is_none() {
+				let delegators = Delegators::<T>::decode_len(&who).unwrap_or(0) as u32;
+				(delegators, Self::apply_delegated_votes(dispute_id, &who, vote).saturating_add(1))
+			} else {
+				(0, 1)
+			};
+			if vote {
+				dispute.votes_for = dispute.votes_for.saturating_add(weight);
+			} else {
+				dispute.votes_against = dispute.votes_against.saturating_add(weight);
+			}
+
+			// Check if the quorum has now voted, from enough distinct accounts; the dispute then
+			// settles by simple majority, right away or once `ResolutionDelay` has passed
+			let total_votes = dispute.votes_for + dispute.votes_against;
+			let reached_quorum = dispute.status == DisputeStatus::Pending &&
+				total_votes >= quorum &&
+				distinct_voters >= min_voters;
+			let rewarded = if reached_quorum {
+				Self::on_quorum_reached(dispute_id, &mut dispute)?
+			} else {
+				0
+			};
+
+			// Store updated dispute
+			Disputes::<T>::insert(dispute_id, &dispute);
+
+			// Emit vote event
+			Self::deposit_event(Event::DisputeVoted(Self::next_event_seq(), dispute_id, who, vote));
+
+			let actual_weight = if reached_quorum {
+				T::WeightInfo::vote_on_dispute_resolving()
+					.saturating_add(Self::reward_voters_weight(rewarded))
+			} else {
+				T::WeightInfo::vote_on_dispute_no_resolution()
+			};
+			Ok(Some(actual_weight.saturating_add(Self::delegated_votes_weight(delegators))).into())
+		}
+	
+		/// Deactivate a biometric proof (only by owner)
+		///
+		/// With `purge_hash` the identity is removed altogether instead: its proof, hash
+		/// mapping, expiry entry and handle are deleted, the hash becomes free to register
+		/// again and the registration deposit is returned. Purging is refused while disputes
+		/// against the identity are open.
+		#[pallet::call_index(4)]
+		#[pallet::weight(if *purge_hash {
+			T::WeightInfo::purge_identity()
+		} else {
+			T::WeightInfo::register_identity(0)
+		})]
+		pub fn deactivate_identity(
+			origin: OriginFor<T>,
+			purge_hash: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			// Get the identity proof
+			let mut proof = IdentityProofs::<T>::get(&who)
+				.ok_or(Error::<T>::IdentityNotFound)?;
+
+			if purge_hash {
+				ensure!(
+					OpenDisputesAgainst::<T>::get(&who) == 0,
+					Error::<T>::IdentityUnderDispute
+				);
+				Self::remove_identity(&who, &proof);
+				Self::deposit_event(Event::IdentityPurged(
+					Self::next_event_seq(),
+					who,
+					proof.biometric_hash,
+				));
+				return Ok(().into())
+			}
+
+			// Deactivate the proof
+			proof.is_active = false;
+			IdentityProofs::<T>::insert(&who, &proof);
+
+			Ok(().into())
+		}
+
+		/// Reactivate a biometric proof (only by owner)
+		///
+		/// An expired identity has to be refreshed with `refresh_identity` first, and one
+		/// taken down by `force_deactivate_hashes` stays inactive until `lift_takedown`. One
+		/// deactivated for being flagged stays inactive for a `FailedVerificationWindow`; the
+		/// hold is not indefinite because anyone passing `VerifierOrigin` can cause a flag.
+		///
+		/// # Errors
+		/// - `IdentityNotFound`: If the caller has no registered identity
+		/// - `IdentityExpired`: If the identity's expiry has passed
+		/// - `IdentityTakenDown`: If the identity was taken down
+		/// - `IdentityDeactivatedByFlag`: If the identity was deactivated for being flagged
+		///   less than `FailedVerificationWindow` ago
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::register_identity(0))]
+		pub fn reactivate_identity(
+			origin: OriginFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			// Get the identity proof
+			let mut proof = IdentityProofs::<T>::get(&who)
+				.ok_or(Error::<T>::IdentityNotFound)?;
+
+			// An expired identity stays inactive until it is refreshed
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(proof.expires_at.map_or(true, |at| now < at), Error::<T>::IdentityExpired);
+			ensure!(!TakenDown::<T>::contains_key(&who), Error::<T>::IdentityTakenDown);
+			if let Some(flagged_at) = DeactivatedByFlag::<T>::get(&who) {
+				ensure!(
+					now.saturating_sub(flagged_at) >= T::FailedVerificationWindow::get(),
+					Error::<T>::IdentityDeactivatedByFlag
+				);
+			}
+
+			// Reactivate the proof
+			proof.is_active = true;
+			IdentityProofs::<T>::insert(&who, &proof);
+			DeactivatedByDispute::<T>::remove(&who);
+			DeactivatedByFlag::<T>::remove(&who);
+
+			Ok(().into())
+		}
+
+		/// Extend the expiry of a biometric proof by the default TTL (only by owner)
+		///
+		/// The new expiry is counted from the current block, so refreshing early does not
+		/// accumulate extra time.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::refresh_identity())]
+		pub fn refresh_identity(
+			origin: OriginFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let mut proof = IdentityProofs::<T>::get(&who)
+				.ok_or(Error::<T>::IdentityNotFound)?;
+
+			// Move the account from its old expiry bucket to the new one
+			if let Some(old) = proof.expires_at {
+				Self::unschedule_expiry(&who, old);
+			}
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			proof.expires_at = Self::expiry_from(current_block);
+			if let Some(at) = proof.expires_at {
+				Self::schedule_expiry(&who, at)?;
+				Self::deposit_event(Event::IdentityRefreshed(
+					Self::next_event_seq(),
+					who.clone(),
+					at,
+				));
+			}
+
+			IdentityProofs::<T>::insert(&who, &proof);
+
+			Ok(().into())
+		}
+
+		/// Import a batch of identities on behalf of their owners
+		///
+		/// Used to migrate identities registered in the ink `IdentityRegistry` contract.
+		/// Entries whose account already has an identity, whose biometric hash is already
+		/// registered, fails `HashValidator` or is under dispute while `RejectDisputedHashes`
+		/// is on, or whose IPFS CID is empty are skipped rather than failing the batch.
+		///
+		/// # Parameters
+		/// - `origin`: Must satisfy `ForceOrigin`
+		/// - `items`: `(owner, biometric_hash, ipfs_cid)` tuples to register
+		///
+		/// # Events
+		/// - `IdentityRegistered` for each imported identity
+		/// - `BatchRegistered(inserted, skipped)` once the batch is processed
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::batch_register(items.len() as u32))]
+		pub fn batch_register(
+			origin: OriginFor<T>,
+			items: BoundedVec<(T::AccountId, T::Hash, BoundedVec<u8, ConstU32<100>>), T::MaxImportBatch>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let mut inserted = 0u32;
+			let mut skipped = 0u32;
+
+			for (owner, biometric_hash, ipfs_cid) in items.into_iter() {
+				if IdentityProofs::<T>::contains_key(&owner) ||
+					BiometricHashToOwner::<T>::contains_key(&biometric_hash) ||
+					!T::HashValidator::validate(&biometric_hash) ||
+					Self::ensure_hash_not_disputed(&biometric_hash).is_err() ||
+					!Self::ipfs_cid_is_valid(&ipfs_cid)
+				{
+					skipped += 1;
+					continue
+				}
+
+				Self::insert_identity(owner, biometric_hash, ipfs_cid)?;
+				inserted += 1;
+			}
+
+			Self::deposit_event(Event::BatchRegistered(Self::next_event_seq(), inserted, skipped));
+
+			Ok(().into())
+		}
+
+		/// Replace the evidence URL of a pending dispute (only by its creator)
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `NotAuthorized`: If the caller did not create the dispute
+		/// - `DisputeAlreadyResolved`: If the dispute is no longer pending
+		/// - `InvalidEvidenceUrl`: If the new URL is empty or contains whitespace/control bytes
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::update_evidence())]
+		pub fn update_evidence(
+			origin: OriginFor<T>,
+			dispute_id: u64,
+			new_evidence_url: EvidenceUrlOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let mut dispute = Disputes::<T>::get(dispute_id)
+				.ok_or(Error::<T>::DisputeNotFound)?;
+
+			ensure!(dispute.creator == who, Error::<T>::NotAuthorized);
+			ensure!(dispute.status.is_open(), Error::<T>::DisputeAlreadyResolved);
+			Self::ensure_valid_evidence_url(&new_evidence_url)?;
+
+			// The first evidence item is the one given at creation
+			match dispute.evidence.get_mut(0) {
+				Some(first) => *first = new_evidence_url,
+				None => dispute
+					.evidence
+					.try_push(new_evidence_url)
+					.map_err(|_| Error::<T>::TooManyEvidenceItems)?,
+			}
+			Disputes::<T>::insert(dispute_id, &dispute);
+
+			Self::deposit_event(Event::EvidenceUpdated(Self::next_event_seq(), dispute_id));
+
+			Ok(().into())
+		}
+
+		/// Prepay for `count` verifications at `PricePerVerification` each
+		///
+		/// The payment is withdrawn from the caller's free balance and burned.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::buy_verifications())]
+		pub fn buy_verifications(origin: OriginFor<T>, count: u32) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let cost = T::PricePerVerification::get().saturating_mul(count.into());
+			let _ = T::Currency::withdraw(
+				&who,
+				cost,
+				WithdrawReasons::FEE,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			VerificationAllowance::<T>::mutate(&who, |allowance| {
+				*allowance = allowance.saturating_add(count)
+			});
+
+			Self::deposit_event(Event::VerificationsPurchased(
+				Self::next_event_seq(),
+				who,
+				count,
+				cost,
+			));
+
+			Ok(().into())
+		}
+
+		/// Acknowledge a dispute as the owner of the disputed identity
+		///
+		/// This only records that the owner is aware of the dispute; it does not affect voting
+		/// or the outcome.
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `NotAuthorized`: If the caller does not own the disputed biometric hash
+		/// - `ProofMissing`: If the disputed hash points at an account without a proof
+		/// - `AlreadyAcknowledged`: If the owner has already acknowledged this dispute
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::acknowledge_dispute())]
+		pub fn acknowledge_dispute(origin: OriginFor<T>, dispute_id: u64) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let mut dispute = Disputes::<T>::get(dispute_id)
+				.ok_or(Error::<T>::DisputeNotFound)?;
+
+			let (owner, _) = Self::proof_by_hash(&dispute.face_proof_id)?;
+			ensure!(owner == who, Error::<T>::NotAuthorized);
+			ensure!(dispute.acknowledged_at.is_none(), Error::<T>::AlreadyAcknowledged);
+
+			dispute.acknowledged_at = Some(<frame_system::Pallet<T>>::block_number());
+			Disputes::<T>::insert(dispute_id, &dispute);
+
+			Self::deposit_event(Event::DisputeAcknowledged(
+				Self::next_event_seq(),
+				dispute_id,
+				who,
+			));
+
+			Ok(().into())
+		}
+
+		/// Attach another evidence URL to a pending dispute (only by its creator)
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `NotAuthorized`: If the caller did not create the dispute
+		/// - `DisputeAlreadyResolved`: If the dispute is no longer pending
+		/// - `InvalidEvidenceUrl`: If the URL is empty or contains whitespace/control bytes
+		/// - `TooManyEvidenceItems`: If the dispute already holds `MaxEvidenceItems` URLs
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::add_evidence())]
+		pub fn add_evidence(
+			origin: OriginFor<T>,
+			dispute_id: u64,
+			url: EvidenceUrlOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let mut dispute = Disputes::<T>::get(dispute_id)
+				.ok_or(Error::<T>::DisputeNotFound)?;
+
+			ensure!(dispute.creator == who, Error::<T>::NotAuthorized);
+			ensure!(dispute.status.is_open(), Error::<T>::DisputeAlreadyResolved);
+			Self::ensure_valid_evidence_url(&url)?;
+
+			dispute.evidence.try_push(url).map_err(|_| Error::<T>::TooManyEvidenceItems)?;
+			let evidence_count = dispute.evidence.len() as u32;
+			Disputes::<T>::insert(dispute_id, &dispute);
+
+			Self::deposit_event(Event::EvidenceAdded(
+				Self::next_event_seq(),
+				dispute_id,
+				evidence_count,
+			));
+
+			Ok(().into())
+		}
+
+		/// Register an identity previously held in the ink contract
+		///
+		/// `proof` is the migration authority's signature over the SCALE encoding of
+		/// `(MIGRATION_CONTEXT, who, biometric_hash, ipfs_cid)`, attesting that the caller
+		/// registered this identity in the contract. The identity is then registered as in
+		/// `register_identity`.
+		///
+		/// # Errors
+		/// - `InvalidMigrationProof`: If the signature does not match this caller and data
+		/// - any error of `register_identity`
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::claim_migrated_identity())]
+		pub fn claim_migrated_identity(
+			origin: OriginFor<T>,
+			biometric_hash: T::Hash,
+			ipfs_cid: BoundedVec<u8, ConstU32<100>>,
+			proof: T::MigrationSignature,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let message = (MIGRATION_CONTEXT, &who, &biometric_hash, &ipfs_cid).encode();
+			ensure!(
+				proof.verify(&message[..], &T::MigrationAuthority::get()),
+				Error::<T>::InvalidMigrationProof
+			);
+
+			Self::do_register_identity(who.clone(), biometric_hash, ipfs_cid)?;
+			Self::deposit_event(Event::IdentityMigrated(
+				Self::next_event_seq(),
+				who,
+				biometric_hash,
+			));
+
+			Ok(().into())
+		}
+
+		/// Set or clear the IPFS gateway relying parties should fetch the caller's face data from
+		///
+		/// `gateway` must be an `http://` or `https://` URL prefix, e.g. `https://ipfs.io/ipfs/`;
+		/// `None` clears a previously set gateway.
+		///
+		/// # Errors
+		/// - `IdentityNotFound`: If the caller has no registered identity
+		/// - `InvalidGateway`: If the gateway is not an http(s) URL prefix
+		/// - `GatewayNotAllowed`: If the allowlist is enforced and does not list the host
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_gateway())]
+		pub fn set_gateway(
+			origin: OriginFor<T>,
+			gateway: Option<GatewayOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			if let Some(url) = &gateway {
+				Self::ensure_valid_gateway(url)?;
+				Self::ensure_gateway_allowed(url)?;
+			}
+
+			IdentityProofs::<T>::try_mutate(&who, |maybe_proof| -> DispatchResult {
+				let proof = maybe_proof.as_mut().ok_or(Error::<T>::IdentityNotFound)?;
+				proof.gateway = gateway;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::GatewayUpdated(Self::next_event_seq(), who));
+
+			Ok(().into())
+		}
+
+		/// Link a secondary account to the caller's identity
+		///
+		/// `proof` is the secondary account's signature over the SCALE encoding of
+		/// `(LINK_CONTEXT, primary, secondary, nonce)`, where `nonce` is the pair's current
+		/// `LinkNonces` entry, so an account can only be linked with its consent and each
+		/// consent links it once. A linked account is then recognized as the primary's
+		/// identity, e.g. by `is_identity_active`.
+		///
+		/// # Errors
+		/// - `IdentityNotFound`: If the caller has no registered identity
+		/// - `CannotLinkSelf`: If `secondary` is the caller
+		/// - `IdentityAlreadyExists`: If `secondary` has an identity of its own
+		/// - `AccountAlreadyLinked`: If `secondary` is already linked to an identity
+		/// - `TooManyLinkedAccounts`: If the caller already has `MaxLinkedAccounts` linked accounts
+		/// - `InvalidLinkProof`: If `proof` is not the secondary's consent to this link
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::link_account())]
+		pub fn link_account(
+			origin: OriginFor<T>,
+			secondary: T::AccountId,
+			proof: T::LinkSignature,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			ensure!(IdentityProofs::<T>::contains_key(&who), Error::<T>::IdentityNotFound);
+			ensure!(secondary != who, Error::<T>::CannotLinkSelf);
+			ensure!(
+				!IdentityProofs::<T>::contains_key(&secondary),
+				Error::<T>::IdentityAlreadyExists
+			);
+			ensure!(
+				!LinkedToPrimary::<T>::contains_key(&secondary),
+				Error::<T>::AccountAlreadyLinked
+			);
+			// Counting stays cheap because the prefix never holds more than the limit
+			ensure!(
+				(LinkedAccounts::<T>::iter_key_prefix(&who).count() as u32) <
+					T::MaxLinkedAccounts::get(),
+				Error::<T>::TooManyLinkedAccounts
+			);
+
+			let nonce = LinkNonces::<T>::get(&who, &secondary);
+			let message = (LINK_CONTEXT, &who, &secondary, nonce).encode();
+			ensure!(proof.verify(&message[..], &secondary), Error::<T>::InvalidLinkProof);
+
+			LinkedAccounts::<T>::insert(&who, &secondary, ());
+			LinkedToPrimary::<T>::insert(&secondary, &who);
+			LinkNonces::<T>::insert(&who, &secondary, nonce.wrapping_add(1));
+
+			Self::deposit_event(Event::AccountLinked(Self::next_event_seq(), who, secondary));
+
+			Ok(().into())
+		}
+
+		/// Remove the link between the caller and `other`, from either side
+		///
+		/// The identity owner passes the secondary account to unlink; a secondary account
+		/// passes the primary it is linked to.
+		///
+		/// # Errors
+		/// - `NotAuthorized`: If the caller and `other` are not linked to each other
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::unlink_account())]
+		pub fn unlink_account(
+			origin: OriginFor<T>,
+			other: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let (who, secondary) = if LinkedAccounts::<T>::contains_key(&who, &other) {
+				(who, other)
+			} else if LinkedToPrimary::<T>::get(&who).as_ref() == Some(&other) {
+				(other, who)
+			} else {
+				return Err(Error::<T>::NotAuthorized.into())
+			};
+
+			LinkedAccounts::<T>::remove(&who, &secondary);
+			LinkedToPrimary::<T>::remove(&secondary);
+
+			Self::deposit_event(Event::AccountUnlinked(Self::next_event_seq(), who, secondary));
+
+			Ok(().into())
+		}
+
+		/// Set the caller's public handle, releasing their previous one
+		///
+		/// # Errors
+		/// - `IdentityNotFound`: If the caller has no registered identity
+		/// - `InvalidHandle`: If the handle is empty or contains whitespace or control bytes
+		/// - `HandleTaken`: If another account already holds the handle
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::set_handle())]
+		pub fn set_handle(origin: OriginFor<T>, handle: HandleOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			ensure!(IdentityProofs::<T>::contains_key(&who), Error::<T>::IdentityNotFound);
+			ensure!(
+				!handle.is_empty() && handle.iter().all(|byte| byte.is_ascii_graphic()),
+				Error::<T>::InvalidHandle
+			);
+			if let Some(holder) = HandleToAccount::<T>::get(&handle) {
+				ensure!(holder == who, Error::<T>::HandleTaken);
+			}
+
+			if let Some(previous) = Handles::<T>::get(&who) {
+				HandleToAccount::<T>::remove(&previous);
+			}
+			Handles::<T>::insert(&who, &handle);
+			HandleToAccount::<T>::insert(&handle, &who);
+
+			Self::deposit_event(Event::HandleSet(Self::next_event_seq(), who, handle));
+
+			Ok(().into())
+		}
+
+		/// Report a face match that looks spoofed and dispute the matched identity
+		///
+		/// Records a failed verification against the identity's owner, exactly like a failed
+		/// match would, and opens an `Impersonation` dispute in the same transaction. Every
+		/// check of `create_dispute` applies, and nothing is recorded if any of them fails.
+		///
+		/// # Errors
+		/// - Any error of `create_dispute`
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::report_spoof(Self::jury_candidates_bound()))]
+		pub fn report_spoof(
+			origin: OriginFor<T>,
+			biometric_hash: T::Hash,
+			evidence_url: EvidenceUrlOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = T::VerifierOrigin::ensure_origin(origin)?;
+			Self::ensure_not_halted()?;
+
+			let (owner, dispute_id) = Self::do_create_dispute(
+				who.clone(),
+				biometric_hash,
+				DisputeCategory::Impersonation,
+				evidence_url,
+			)?;
+			Self::note_failed_verification(&owner);
+
+			Self::deposit_event(Event::SpoofReported(
+				Self::next_event_seq(),
+				who,
+				biometric_hash,
+				dispute_id,
+			));
+
+			Ok(().into())
+		}
+
+		/// Leave a comment explaining one's position on an open dispute
+		///
+		/// Only the dispute's participants may comment: its creator, the owner of the disputed
+		/// identity, its jurors and anyone who already voted on it.
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `DisputeAlreadyResolved`: If the dispute is no longer open
+		/// - `NotAuthorized`: If the caller does not participate in the dispute
+		/// - `InvalidComment`: If the comment is empty
+		/// - `TooManyComments`: If the dispute already holds `MaxCommentsPerDispute` comments
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::add_comment())]
+		pub fn add_comment(
+			origin: OriginFor<T>,
+			dispute_id: u64,
+			text: CommentOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(dispute.status.is_open(), Error::<T>::DisputeAlreadyResolved);
+			ensure!(Self::is_dispute_participant(&dispute, &who), Error::<T>::NotAuthorized);
+			ensure!(!text.is_empty(), Error::<T>::InvalidComment);
+
+			let index = DisputeCommentCount::<T>::get(dispute_id);
+			ensure!(index < T::MaxCommentsPerDispute::get(), Error::<T>::TooManyComments);
+			DisputeComments::<T>::insert(dispute_id, index, (&who, text));
+			DisputeCommentCount::<T>::insert(dispute_id, index + 1);
+
+			Self::deposit_event(Event::CommentAdded(
+				Self::next_event_seq(),
+				dispute_id,
+				who,
+				index,
+			));
+
+			Ok(().into())
+		}
+
+		/// Fold `drop`'s identity into `keep`'s, for a person who registered twice
+		///
+		/// `drop`'s biometric hash, and any hashes merged into it before, keep resolving but
+		/// now to `keep`. Everything else of `drop`'s identity is removed as on a purge,
+		/// including returning its registration deposit.
+		///
+		/// # Errors
+		/// - `CannotMergeSelf`: If `keep` and `drop` are the same account
+		/// - `IdentityNotFound`: If either account has no registered identity
+		/// - `IdentityUnderDispute`: If disputes against `drop`'s identity are still open
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::merge_identities())]
+		pub fn merge_identities(
+			origin: OriginFor<T>,
+			keep: T::AccountId,
+			drop: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(keep != drop, Error::<T>::CannotMergeSelf);
+			ensure!(IdentityProofs::<T>::contains_key(&keep), Error::<T>::IdentityNotFound);
+			let drop_proof = IdentityProofs::<T>::get(&drop).ok_or(Error::<T>::IdentityNotFound)?;
+			ensure!(
+				OpenDisputesAgainst::<T>::get(&drop) == 0,
+				Error::<T>::IdentityUnderDispute
+			);
+
+			// Take the hashes along before `drop`'s identity and reverse entries are removed
+			let mut hashes: Vec<(T::Hash, T::AccountId)> =
+				MergedHashes::<T>::drain_prefix(&drop).collect();
+			hashes.push((drop_proof.biometric_hash, drop.clone()));
+			Self::remove_identity(&drop, &drop_proof);
+
+			for (biometric_hash, registrant) in hashes {
+				BiometricHashToOwner::<T>::insert(&biometric_hash, &keep);
+				MergedHashes::<T>::insert(&keep, &biometric_hash, registrant);
+			}
+
+			Self::deposit_event(Event::IdentitiesMerged(Self::next_event_seq(), keep, drop));
+
+			Ok(().into())
+		}
+
+		/// Reject up to `max` pending disputes that outlived both `DisputeVotingPeriod` and
+		/// `VotingWindow`
+		///
+		/// Anyone may call this. Disputes are inspected in ID order from where the previous
+		/// sweep stopped, at most `MaxSweepPerCall` per call, and the sweep stops at the first
+		/// pending dispute that has not expired yet. Frozen disputes are passed over. The caller
+		/// is paid `SweepReward` from the pallet account for each dispute closed, or whatever
+		/// the account holds above the existential deposit if that is less.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::sweep_expired_disputes(
+			(*max).min(T::MaxSweepPerCall::get())
+		))]
+		pub fn sweep_expired_disputes(
+			origin: OriginFor<T>,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let period = Self::dispute_lifetime();
+			if period.is_zero() {
+				return Ok(Some(T::WeightInfo::sweep_expired_disputes(0)).into())
+			}
+
+			let max = max.min(T::MaxSweepPerCall::get());
+			let now = <frame_system::Pallet<T>>::block_number();
+			let end = NextDisputeId::<T>::get();
+			let mut cursor = SweepCursor::<T>::get();
+			let mut inspected = 0u32;
+			let mut swept = 0u32;
+
+			while cursor < end && inspected < max {
+				inspected += 1;
+				if let Some(mut dispute) = Disputes::<T>::get(cursor) {
+					if dispute.status == DisputeStatus::Pending && !dispute.frozen {
+						// Later disputes were created no earlier, so none of them expired either
+						if now.saturating_sub(dispute.created_at) < period {
+							break
+						}
+						Self::expire_dispute(cursor, &mut dispute);
+						Disputes::<T>::insert(cursor, &dispute);
+						swept += 1;
+					}
+				}
+				cursor += 1;
+			}
+			SweepCursor::<T>::put(cursor);
+
+			let pot = Self::account_id();
+			let affordable =
+				T::Currency::free_balance(&pot).saturating_sub(T::Currency::minimum_balance());
+			let reward = T::SweepReward::get().saturating_mul(swept.into()).min(affordable);
+			if !reward.is_zero() {
+				let _ = T::Currency::transfer(
+					&pot,
+					&who,
+					reward,
+					ExistenceRequirement::KeepAlive,
+				);
+			}
+
+			Ok(Some(T::WeightInfo::sweep_expired_disputes(inspected)).into())
+		}
+
+		/// Allow identities to use gateways on `host`
+		///
+		/// `host` is the bare host name, without scheme, port or path.
+		///
+		/// # Errors
+		/// - `InvalidGateway`: If `host` is empty or contains a scheme, port or path
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::add_allowed_gateway())]
+		pub fn add_allowed_gateway(
+			origin: OriginFor<T>,
+			host: GatewayOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!host.is_empty() &&
+					host.iter().all(|byte| byte.is_ascii_graphic() && !b"/:".contains(byte)),
+				Error::<T>::InvalidGateway
+			);
+
+			AllowedGateways::<T>::insert(&host, ());
+
+			Self::deposit_event(Event::GatewayAllowed(Self::next_event_seq(), host));
+
+			Ok(().into())
+		}
+
+		/// Remove `host` from the gateway allowlist
+		///
+		/// Identities that already use a gateway on `host` keep it until they change it.
+		///
+		/// # Errors
+		/// - `GatewayNotAllowed`: If `host` is not in the allowlist
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::remove_allowed_gateway())]
+		pub fn remove_allowed_gateway(
+			origin: OriginFor<T>,
+			host: GatewayOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(AllowedGateways::<T>::contains_key(&host), Error::<T>::GatewayNotAllowed);
+			AllowedGateways::<T>::remove(&host);
+
+			Self::deposit_event(Event::GatewayDisallowed(Self::next_event_seq(), host));
+
+			Ok(().into())
+		}
+
+		/// Hand an open dispute over to `new_creator`
+		///
+		/// `proof` is `new_creator`'s signature over the SCALE encoding of
+		/// `(REASSIGN_CONTEXT, dispute_id, creator, new_creator)`, so nobody is made to defend a
+		/// dispute without their consent. The dispute moves between the two accounts'
+		/// `CreatedDisputes` lists.
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `NotAuthorized`: If the caller did not create the dispute, or `new_creator` is one
+		///   of its jurors
+		/// - `DisputeAlreadyResolved`: If the dispute is no longer open
+		/// - `CannotDisputeOwnIdentity`: If `new_creator` owns the disputed identity
+		/// - `TooManyDisputes`: If `new_creator` already has `MaxCreatedDisputes` disputes
+		/// - `InvalidReassignProof`: If `proof` is not `new_creator`'s consent
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::reassign_dispute())]
+		pub fn reassign_dispute(
+			origin: OriginFor<T>,
+			dispute_id: u64,
+			new_creator: T::AccountId,
+			proof: T::ReassignSignature,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let mut dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(dispute.creator == who, Error::<T>::NotAuthorized);
+			ensure!(dispute.status.is_open(), Error::<T>::DisputeAlreadyResolved);
+			let owner = BiometricHashToOwner::<T>::get(&dispute.face_proof_id);
+			ensure!(owner.as_ref() != Some(&new_creator), Error::<T>::CannotDisputeOwnIdentity);
+			let is_juror = DisputeJury::<T>::get(dispute_id)
+				.map_or(false, |jury| jury.contains(&new_creator));
+			ensure!(!is_juror, Error::<T>::NotAuthorized);
+
+			let message = (REASSIGN_CONTEXT, dispute_id, &who, &new_creator).encode();
+			ensure!(proof.verify(&message[..], &new_creator), Error::<T>::InvalidReassignProof);
+
+			CreatedDisputes::<T>::mutate(&who, |ids| ids.retain(|id| *id != dispute_id));
+			CreatedDisputes::<T>::try_mutate(&new_creator, |ids| ids.try_push(dispute_id))
+				.map_err(|_| Error::<T>::TooManyDisputes)?;
+
+			dispute.creator = new_creator.clone();
+			Disputes::<T>::insert(dispute_id, &dispute);
+
+			Self::deposit_event(Event::DisputeReassigned(
+				Self::next_event_seq(),
+				dispute_id,
+				who,
+				new_creator,
+			));
+
+			Ok(().into())
+		}
+
+		/// Commit the Merkle root of a batch of biometric hashes
+		///
+		/// The hashes themselves stay off-chain until their owners register them with
+		/// `register_from_commitment`. See the `merkle` module for the tree layout.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::commit_batch())]
+		pub fn commit_batch(origin: OriginFor<T>, root: T::Hash) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			BatchCommitments::<T>::insert(root, now);
+
+			Self::deposit_event(Event::BatchCommitted(Self::next_event_seq(), root));
+
+			Ok(().into())
+		}
+
+		/// Register a biometric hash from a committed batch
+		///
+		/// `proof` holds the siblings leading from the hash's leaf up to a root stored by
+		/// `commit_batch`. The registration is otherwise the same as `register_identity`.
+		///
+		/// # Errors
+		/// - `InvalidMerkleProof`: If `proof` does not lead to a committed root
+		/// - Any error of `register_identity`
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::register_from_commitment())]
+		pub fn register_from_commitment(
+			origin: OriginFor<T>,
+			biometric_hash: T::Hash,
+			ipfs_cid: BoundedVec<u8, ConstU32<100>>,
+			proof: BoundedVec<T::Hash, ConstU32<MAX_MERKLE_PROOF_DEPTH>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let leaf = merkle::leaf::<T::Hashing>(biometric_hash.as_ref());
+			let root = merkle::root_from_proof::<T::Hashing>(leaf, &proof);
+			ensure!(BatchCommitments::<T>::contains_key(root), Error::<T>::InvalidMerkleProof);
+
+			Self::do_register_identity(who, biometric_hash, ipfs_cid)?;
+
+			Ok(().into())
+		}
+
+		/// Replace the caller's biometric hash and IPFS CID together
+		///
+		/// Both are validated before anything is written, so the identity is never left with a
+		/// new hash and an old CID or the other way round. The old hash is released and can be
+		/// registered again.
+		///
+		/// # Errors
+		/// - `IdentityNotFound`: If the caller has no registered identity
+		/// - `InvalidBiometricHash`: If `new_hash` is already registered or fails
+		///   `HashValidator`
+		/// - `InvalidIpfsCid`: If `new_cid` is shorter than `MinIpfsCidLength` or empty
+		/// - `IdentityUnderDispute`: If a dispute against the identity is still open, since it
+		///   refers to the old hash
+		/// - `HashUnderDispute`: If `new_hash` has an open dispute and `RejectDisputedHashes`
+		///   is on
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::reenroll())]
+		pub fn reenroll(
+			origin: OriginFor<T>,
+			new_hash: T::Hash,
+			new_cid: BoundedVec<u8, ConstU32<100>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let mut proof = IdentityProofs::<T>::get(&who).ok_or(Error::<T>::IdentityNotFound)?;
+			ensure!(
+				!BiometricHashToOwner::<T>::contains_key(&new_hash) &&
+					T::HashValidator::validate(&new_hash),
+				Error::<T>::InvalidBiometricHash
+			);
+			ensure!(Self::ipfs_cid_is_valid(&new_cid), Error::<T>::InvalidIpfsCid);
+			ensure!(
+				OpenDisputesAgainst::<T>::get(&who).is_zero(),
+				Error::<T>::IdentityUnderDispute
+			);
+			Self::ensure_hash_not_disputed(&new_hash)?;
+
+			let old_hash = proof.biometric_hash;
+			BiometricHashToOwner::<T>::remove(&old_hash);
+			BiometricHashToOwner::<T>::insert(&new_hash, &who);
+			proof.biometric_hash = new_hash;
+			proof.ipfs_cid = new_cid;
+			IdentityProofs::<T>::insert(&who, &proof);
+
+			Self::deposit_event(Event::IdentityReenrolled(
+				Self::next_event_seq(),
+				who,
+				old_hash,
+				new_hash,
+			));
+
+			Ok(().into())
+		}
+
+		/// Halt or resume every extrinsic not gated by `ForceOrigin`
+		///
+		/// Queries, hooks and `ForceOrigin` calls keep working while halted.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_halted())]
+		pub fn set_halted(origin: OriginFor<T>, halted: bool) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Halted::<T>::put(halted);
+
+			Self::deposit_event(Event::HaltedSet(Self::next_event_seq(), halted));
+
+			Ok(().into())
+		}
+
+		/// Settle an open dispute with the given outcome, whatever its tally
+		///
+		/// A dispute settled in favor deactivates the disputed identity, as a vote would.
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `DisputeAlreadyResolved`: If the dispute is no longer open
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::force_resolve_dispute()
+			.saturating_add(T::WeightInfo::reward_voters(T::MaxVotersPerDispute::get())))]
+		pub fn force_resolve_dispute(
+			origin: OriginFor<T>,
+			dispute_id: u64,
+			in_favor: bool,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let mut dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(dispute.status.is_open(), Error::<T>::DisputeAlreadyResolved);
+
+			let rewarded =
+				Self::close_dispute(dispute_id, &mut dispute, in_favor, ResolutionReason::Force);
+			Disputes::<T>::insert(dispute_id, &dispute);
+
+			Self::deposit_event(Event::DisputeForceResolved(
+				Self::next_event_seq(),
+				dispute_id,
+				in_favor,
+			));
+
+			let actual_weight = T::WeightInfo::force_resolve_dispute()
+				.saturating_add(Self::reward_voters_weight(rewarded));
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Designate the account that may take over the caller's identity if it is lost, or
+		/// remove the designation with `None`
+		///
+		/// Either way a pending recovery by the previous recovery account is cancelled.
+		///
+		/// # Errors
+		/// - `IdentityNotFound`: If the caller has no registered identity
+		/// - `InvalidRecoveryAccount`: If `recovery` is the caller
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_recovery())]
+		pub fn set_recovery(
+			origin: OriginFor<T>,
+			recovery: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			ensure!(IdentityProofs::<T>::contains_key(&who), Error::<T>::IdentityNotFound);
+			ensure!(recovery.as_ref() != Some(&who), Error::<T>::InvalidRecoveryAccount);
+
+			RecoveryAccounts::<T>::set(&who, recovery.clone());
+			PendingRecoveries::<T>::remove(&who);
+
+			Self::deposit_event(Event::RecoverySet(Self::next_event_seq(), who, recovery));
+
+			Ok(().into())
+		}
+
+		/// Start taking over the identity of `lost_account` as its designated recovery account
+		///
+		/// The identity can be taken over with `recover_identity` once `RecoveryDelay` has
+		/// passed, unless its owner cancels the recovery first.
+		///
+		/// # Errors
+		/// - `NotAuthorized`: If the caller is not `lost_account`'s recovery account
+		/// - `IdentityNotFound`: If `lost_account` no longer has an identity
+		/// - `RecoveryAlreadyInitiated`: If a recovery of the identity is already pending
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::initiate_recovery())]
+		pub fn initiate_recovery(
+			origin: OriginFor<T>,
+			lost_account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			ensure!(
+				RecoveryAccounts::<T>::get(&lost_account).as_ref() == Some(&who),
+				Error::<T>::NotAuthorized
+			);
+			ensure!(
+				IdentityProofs::<T>::contains_key(&lost_account),
+				Error::<T>::IdentityNotFound
+			);
+			ensure!(
+				!PendingRecoveries::<T>::contains_key(&lost_account),
+				Error::<T>::RecoveryAlreadyInitiated
+			);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			PendingRecoveries::<T>::insert(&lost_account, now);
+
+			Self::deposit_event(Event::RecoveryInitiated(
+				Self::next_event_seq(),
+				lost_account,
+				who,
+			));
+
+			Ok(().into())
+		}
+
+		/// Take over the identity of `lost_account` as its designated recovery account
+		///
+		/// Everything tied to the identity moves to the caller: its proof and hashes, linked
+		/// accounts, handle, expiry, open dispute count, verification history and reserved
+		/// deposit.
+		///
+		/// # Errors
+		/// - `NotAuthorized`: If the caller is not `lost_account`'s recovery account
+		/// - `RecoveryNotInitiated`: If the caller has not initiated the recovery
+		/// - `RecoveryTooEarly`: If `RecoveryDelay` has not passed since it was initiated
+		/// - `IdentityNotFound`: If `lost_account` no longer has an identity
+		/// - `IdentityAlreadyExists`: If the caller has an identity of its own
+		/// - `AccountAlreadyLinked`: If the caller is linked to an identity
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::recover_identity())]
+		pub fn recover_identity(
+			origin: OriginFor<T>,
+			lost_account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			ensure!(
+				RecoveryAccounts::<T>::get(&lost_account).as_ref() == Some(&who),
+				Error::<T>::NotAuthorized
+			);
+			let initiated_at = PendingRecoveries::<T>::get(&lost_account)
+				.ok_or(Error::<T>::RecoveryNotInitiated)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				now >= initiated_at.saturating_add(T::RecoveryDelay::get()),
+				Error::<T>::RecoveryTooEarly
+			);
+			let proof =
+				IdentityProofs::<T>::get(&lost_account).ok_or(Error::<T>::IdentityNotFound)?;
+			ensure!(!IdentityProofs::<T>::contains_key(&who), Error::<T>::IdentityAlreadyExists);
+			ensure!(!LinkedToPrimary::<T>::contains_key(&who), Error::<T>::AccountAlreadyLinked);
+
+			Self::transfer_identity(&lost_account, &who, proof)?;
+
+			Self::deposit_event(Event::IdentityRecovered(
+				Self::next_event_seq(),
+				lost_account,
+				who,
+			));
+
+			Ok(().into())
+		}
+
+		/// Cancel a pending recovery of the caller's identity
+		///
+		/// The recovery account stays designated and can initiate a new recovery.
+		///
+		/// # Errors
+		/// - `RecoveryNotInitiated`: If no recovery of the caller's identity is pending
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::cancel_recovery())]
+		pub fn cancel_recovery(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			PendingRecoveries::<T>::take(&who).ok_or(Error::<T>::RecoveryNotInitiated)?;
+
+			Self::deposit_event(Event::RecoveryCancelled(Self::next_event_seq(), who));
+
+			Ok(().into())
+		}
+
+		/// Switch private mode on or off for the caller's identity
+		///
+		/// Under the `ActiveAndPublic` verification policy, `verify_identity` and the
+		/// verification queries treat a private identity's hashes as not found, though they
+		/// stay registered.
+		///
+		/// # Errors
+		/// - `IdentityNotFound`: If the caller has no identity
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_private())]
+		pub fn set_private(origin: OriginFor<T>, private: bool) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			IdentityProofs::<T>::try_mutate(&who, |maybe_proof| -> DispatchResult {
+				let proof = maybe_proof.as_mut().ok_or(Error::<T>::IdentityNotFound)?;
+				proof.private = private;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PrivacySet(Self::next_event_seq(), who, private));
+
+			Ok(().into())
+		}
+
+		/// Settle a pending dispute whose votes already meet the quorum
+		///
+		/// Disputes only settle as votes arrive, so one that meets the quorum without a
+		/// further vote (say after `MinDistinctVoters` was lowered) can be settled by anyone.
+		/// It settles as if the last vote had just been cast, so a `ResolutionDelay` still
+		/// applies.
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `DisputeAlreadyResolved`: If the dispute is settled or already waiting to settle
+		/// - `DisputeFrozen`: If the dispute is frozen
+		/// - `QuorumNotReached`: If the votes do not meet the quorum
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::finalize_dispute()
+			.saturating_add(T::WeightInfo::reward_voters(T::MaxVotersPerDispute::get())))]
+		pub fn finalize_dispute(
+			origin: OriginFor<T>,
+			dispute_id: u64,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let mut dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(dispute.status == DisputeStatus::Pending, Error::<T>::DisputeAlreadyResolved);
+			ensure!(!dispute.frozen, Error::<T>::DisputeFrozen);
+
+			let (quorum, min_voters) = Self::quorum_of(dispute_id, &dispute);
+			let distinct_voters = DisputeVoterOrder::<T>::decode_len(dispute_id).unwrap_or(0);
+			ensure!(
+				dispute.votes_for + dispute.votes_against >= quorum &&
+					distinct_voters as u32 >= min_voters,
+				Error::<T>::QuorumNotReached
+			);
+
+			let rewarded = Self::on_quorum_reached(dispute_id, &mut dispute)?;
+			Disputes::<T>::insert(dispute_id, &dispute);
+
+			let actual_weight = T::WeightInfo::finalize_dispute()
+				.saturating_add(Self::reward_voters_weight(rewarded));
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Freeze an open dispute under investigation, without resolving it
+		///
+		/// While frozen the dispute takes no votes and `sweep_expired_disputes` does not
+		/// expire it. A dispute that already reached quorum still settles on schedule.
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `DisputeAlreadyResolved`: If the dispute is no longer open
+		/// - `DisputeFrozen`: If the dispute is already frozen
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::freeze_dispute())]
+		pub fn freeze_dispute(origin: OriginFor<T>, dispute_id: u64) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Disputes::<T>::try_mutate(dispute_id, |maybe_dispute| -> DispatchResult {
+				let dispute = maybe_dispute.as_mut().ok_or(Error::<T>::DisputeNotFound)?;
+				ensure!(dispute.status.is_open(), Error::<T>::DisputeAlreadyResolved);
+				ensure!(!dispute.frozen, Error::<T>::DisputeFrozen);
+				dispute.frozen = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::DisputeFrozen(Self::next_event_seq(), dispute_id));
+
+			Ok(().into())
+		}
+
+		/// Release a frozen dispute, so it takes votes and can expire again
+		///
+		/// The sweep cursor is moved back to the dispute if it went past it while frozen.
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `DisputeNotFrozen`: If the dispute is not frozen
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::unfreeze_dispute())]
+		pub fn unfreeze_dispute(
+			origin: OriginFor<T>,
+			dispute_id: u64,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Disputes::<T>::try_mutate(dispute_id, |maybe_dispute| -> DispatchResult {
+				let dispute = maybe_dispute.as_mut().ok_or(Error::<T>::DisputeNotFound)?;
+				ensure!(dispute.frozen, Error::<T>::DisputeNotFrozen);
+				dispute.frozen = false;
+				Ok(())
+			})?;
+			SweepCursor::<T>::mutate(|cursor| *cursor = (*cursor).min(dispute_id));
+
+			Self::deposit_event(Event::DisputeUnfrozen(Self::next_event_seq(), dispute_id));
+
+			Ok(().into())
+		}
+
+		/// Delegate the caller's dispute vote to `delegate`
+		///
+		/// Each time `delegate` votes on a dispute outside jury mode, their vote also counts
+		/// once for the caller, unless the caller has voted on it already. Delegating again
+		/// replaces the previous delegate. Delegation is not transitive.
+		///
+		/// # Errors
+		/// - `CannotDelegateToSelf`: If `delegate` is the caller
+		/// - `TooManyDelegators`: If `delegate` already has `MaxDelegators` delegators
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::delegate_vote())]
+		pub fn delegate_vote(origin: OriginFor<T>, delegate: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+			ensure!(who != delegate, Error::<T>::CannotDelegateToSelf);
+
+			if let Some(previous) = VoteDelegates::<T>::get(&who) {
+				if previous == delegate {
+					return Ok(())
+				}
+				Self::remove_delegator(&previous, &who);
+			}
+			Delegators::<T>::try_mutate(&delegate, |delegators| delegators.try_push(who.clone()))
+				.map_err(|_| Error::<T>::TooManyDelegators)?;
+			VoteDelegates::<T>::insert(&who, &delegate);
+
+			Self::deposit_event(Event::VoteDelegated(Self::next_event_seq(), who, delegate));
+			Ok(())
+		}
+
+		/// Take back the caller's dispute vote from their delegate
+		///
+		/// Weight the delegate already cast for the caller stays counted unless the caller
+		/// votes on that dispute directly.
+		///
+		/// # Errors
+		/// - `NotDelegating`: If the caller has not delegated their vote
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+
+			let delegate = VoteDelegates::<T>::take(&who).ok_or(Error::<T>::NotDelegating)?;
+			Self::remove_delegator(&delegate, &who);
+
+			Self::deposit_event(Event::VoteUndelegated(Self::next_event_seq(), who, delegate));
+			Ok(())
+		}
+
+		/// Deactivate the identities registered under each of `hashes`, for takedown requests
+		///
+		/// Hashes that are not registered are skipped rather than failing the batch. Every
+		/// identity found is marked as taken down, including one that was already inactive,
+		/// so its owner cannot reactivate it until `lift_takedown`.
+		///
+		/// # Events
+		/// - `HashesForceDeactivated(deactivated)` once the list is processed
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::force_deactivate_hashes(hashes.len() as u32))]
+		pub fn force_deactivate_hashes(
+			origin: OriginFor<T>,
+			hashes: BoundedVec<T::Hash, T::MaxTakedownBatch>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let mut deactivated = 0u32;
+			for biometric_hash in hashes {
+				let owner = match BiometricHashToOwner::<T>::get(&biometric_hash) {
+					Some(owner) => owner,
+					None => continue,
+				};
+				TakenDown::<T>::insert(&owner, ());
+				IdentityProofs::<T>::mutate(&owner, |maybe_proof| {
+					if let Some(proof) = maybe_proof.as_mut().filter(|proof| proof.is_active) {
+						proof.is_active = false;
+						deactivated += 1;
+					}
+				});
+			}
+
+			Self::deposit_event(Event::HashesForceDeactivated(Self::next_event_seq(), deactivated));
+
+			Ok(().into())
+		}
+
+		/// Attach an opaque note to the caller's identity, replacing any previous one
+		///
+		/// The note is meant for small blobs such as an encrypted recovery hint; the pallet
+		/// makes no assumptions about its contents. An empty note clears it.
+		///
+		/// # Errors
+		/// - `IdentityNotFound`: If the caller has no registered identity
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_note())]
+		pub fn set_note(origin: OriginFor<T>, note: NoteOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_halted()?;
+			ensure!(IdentityProofs::<T>::contains_key(&who), Error::<T>::IdentityNotFound);
+
+			if note.is_empty() {
+				IdentityNotes::<T>::remove(&who);
+			} else {
+				IdentityNotes::<T>::insert(&who, note);
+			}
+
+			Self::deposit_event(Event::NoteSet(Self::next_event_seq(), who));
+
+			Ok(().into())
+		}
+
+		/// Completely remove `account`'s identity, for takedowns that deactivation does not
+		/// satisfy
+		///
+		/// Unlike `deactivate_identity`, this also clears the identity's per-account counters
+		/// (open disputes, failed matches, verification nonce), so the account can register
+		/// afresh. Disputes still open against the identity stay open but no longer count
+		/// against the account.
+		///
+		/// # Errors
+		/// - `IdentityNotFound`: If `account` has no registered identity
+		///
+		/// # Events
+		/// - `IdentityPurged(account, biometric_hash)` once the identity is removed
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::force_unregister())]
+		pub fn force_unregister(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let proof = IdentityProofs::<T>::get(&account).ok_or(Error::<T>::IdentityNotFound)?;
+			Self::remove_identity(&account, &proof);
+			OpenDisputesAgainst::<T>::remove(&account);
+			FailedVerifications::<T>::remove(&account);
+			VerificationNonces::<T>::remove(&account);
+
+			Self::deposit_event(Event::IdentityPurged(
+				Self::next_event_seq(),
+				account,
+				proof.biometric_hash,
+			));
+
+			Ok(().into())
+		}
+
+		/// Attest a batch of verification results processed off-chain by their Merkle root
+		///
+		/// Only the root is stored; each result is proven against it off-chain, using the
+		/// tree layout of the `merkle` module.
+		///
+		/// # Errors
+		/// - `AlreadyAttested`: If `results_root` was attested before
+		///
+		/// # Events
+		/// - `BatchAttested(results_root, block_number)` once the root is stored
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::attest_batch())]
+		pub fn attest_batch(
+			origin: OriginFor<T>,
+			results_root: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			T::VerifierOrigin::ensure_origin(origin)?;
+			Self::ensure_not_halted()?;
+			ensure!(
+				!AttestationRoots::<T>::contains_key(results_root),
+				Error::<T>::AlreadyAttested
+			);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			AttestationRoots::<T>::insert(results_root, now);
+
+			Self::deposit_event(Event::BatchAttested(Self::next_event_seq(), results_root, now));
+
+			Ok(().into())
+		}
+
+		/// Uphold an appeal against a resolved dispute, overturning it to `Rejected`
+		///
+		/// The disputed identity is reactivated if this dispute is the one that deactivated it.
+		///
+		/// # Errors
+		/// - `DisputeNotFound`: If the dispute does not exist
+		/// - `NotAuthorized`: If the dispute was not resolved in favor
+		///
+		/// # Events
+		/// - `IdentityReactivatedByAppeal(account, dispute_id)` if the identity is reactivated
+		/// - `DisputeOverturned(dispute_id)` once the dispute is overturned
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::resolve_appeal())]
+		pub fn resolve_appeal(origin: OriginFor<T>, dispute_id: u64) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Self::overturn_dispute(dispute_id)?;
+
+			Self::deposit_event(Event::DisputeOverturned(Self::next_event_seq(), dispute_id));
+
+			Ok(().into())
+		}
+
+		/// Lift the takedown of `account`'s identity, so its owner can reactivate it
+		///
+		/// The identity itself stays inactive until the owner calls `reactivate_identity`.
+		///
+		/// # Errors
+		/// - `NotTakenDown`: If `account`'s identity is not taken down
+		///
+		/// # Events
+		/// - `TakedownLifted(account)` once the takedown is lifted
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::lift_takedown())]
+		pub fn lift_takedown(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			TakenDown::<T>::take(&account).ok_or(Error::<T>::NotTakenDown)?;
+
+			Self::deposit_event(Event::TakedownLifted(Self::next_event_seq(), account));
+
+			Ok(().into())
+		}
+	}
+
+	// Helper functions for querying
+	impl<T: Config> Pallet<T> {
+		/// Get biometric proof by account ID
+		pub fn get_identity_proof(account: &T::AccountId) -> Option<BiometricProofOf<T>> {
+			IdentityProofs::<T>::get(account)
+		}
+
+		/// Get owner by biometric hash
+		pub fn get_owner_by_hash(hash: &T::Hash) -> Option<T::AccountId> {
+			BiometricHashToOwner::<T>::get(hash)
+		}
+
+		/// Check if identity is active
+		///
+		/// A linked secondary account reports its primary's status. Links never chain: a primary
+		/// always owns an identity and a secondary never does, so one `LinkedToPrimary` read
+		/// resolves any account and no cycle can form.
+		pub fn is_identity_active(account: &T::AccountId) -> bool {
+			let owner = LinkedToPrimary::<T>::get(account).unwrap_or_else(|| account.clone());
+			if let Some(proof) = IdentityProofs::<T>::get(&owner) {
+				proof.is_active
+			} else {
+				false
+			}
+		}
+
+		/// Get dispute by ID
+		pub fn get_dispute(dispute_id: u64) -> Option<Dispute<T>> {
+			Disputes::<T>::get(dispute_id)
+		}
+
+		/// Check if account has voted on dispute
+		pub fn has_voted(dispute_id: u64, account: &T::AccountId) -> bool {
+			DisputeVotes::<T>::contains_key(dispute_id, account)
+		}
+
+		/// Whether `biometric_hash` belongs to a registered identity that matches under
+		/// `VerificationPolicy`
+		///
+		/// Unlike `verify_identity`, this performs no writes and emits no audit event. It backs
+		/// the `dry_run_verify` runtime API for clients that want a preview.
+		pub fn dry_run_verify(biometric_hash: &T::Hash) -> bool {
+			Self::visible_owner(biometric_hash).is_some()
+		}
+
+		/// Whether `ipfs_cid` is non-empty and at least `MinIpfsCidLength` bytes long
+		fn ipfs_cid_is_valid(ipfs_cid: &[u8]) -> bool {
+			ipfs_cid.len() as u32 >= T::MinIpfsCidLength::get().max(1)
+		}
+
+		/// Owner of `biometric_hash`, if their identity matches under `VerificationPolicy`
+		///
+		/// A hash whose owner has no stored proof only matches under `MatchAll`.
+		fn visible_owner(biometric_hash: &T::Hash) -> Option<T::AccountId> {
+			let policy = T::VerificationPolicy::get();
+			let owner = BiometricHashToOwner::<T>::get(biometric_hash)?;
+			let matches = match IdentityProofs::<T>::get(&owner) {
+				Some(proof) => policy.matches(proof.is_active, proof.private),
+				None => policy == VerificationPolicy::MatchAll,
+			};
+			matches.then_some(owner)
+		}
+
+		/// Whether `biometric_hash` belongs to an identity that is currently active
+		///
+		/// Chains the reverse index and the owner's proof in one read. A hash whose owner has
+		/// no stored proof is treated as inactive.
+		pub fn hash_is_active(biometric_hash: &T::Hash) -> bool {
+			BiometricHashToOwner::<T>::get(biometric_hash)
+				.and_then(|owner| IdentityProofs::<T>::get(owner))
+				.map_or(false, |proof| proof.is_active)
+		}
+
+		/// Whether each of `hashes` belongs to an active identity, in the order given
+		///
+		/// Unknown hashes are reported inactive. Only the first `MaxQueryBatch` hashes are
+		/// looked up.
+		pub fn active_status_of(hashes: Vec<T::Hash>) -> Vec<(T::Hash, bool)> {
+			hashes
+				.into_iter()
+				.take(T::MaxQueryBatch::get() as usize)
+				.map(|biometric_hash| {
+					let active = Self::hash_is_active(&biometric_hash);
+					(biometric_hash, active)
+				})
+				.collect()
+		}
+
+		/// Owner of `biometric_hash` and whether their identity is active, read together so
+		/// relying parties need a single call
+		///
+		/// Unknown hashes give `(None, false)`. A hash whose owner has no stored identity is
+		/// reported inactive. Under `VerificationPolicy::ActiveAndPublic` an identity in private
+		/// mode is reported like an unknown hash, so its owner cannot be looked up by hash.
+		pub fn hash_owner_and_status(biometric_hash: &T::Hash) -> (Option<T::AccountId>, bool) {
+			let owner = match BiometricHashToOwner::<T>::get(biometric_hash) {
+				Some(owner) => owner,
+				None => return (None, false),
+			};
+			match IdentityProofs::<T>::get(&owner) {
+				Some(proof) if proof.private && !T::VerificationPolicy::get().matches(true, true) =>
+					(None, false),
+				Some(proof) => (Some(owner), proof.is_active),
+				None => (Some(owner), false),
+			}
+		}
+
+		/// Identities of `accounts`, in the order given, with `None` for accounts without one
+		///
+		/// Only the first `MaxQueryBatch` accounts are looked up.
+		pub fn identities_of(
+			accounts: Vec<T::AccountId>,
+		) -> Vec<(T::AccountId, Option<BiometricProofOf<T>>)> {
+			accounts
+				.into_iter()
+				.take(T::MaxQueryBatch::get() as usize)
+				.map(|account| {
+					let proof = IdentityProofs::<T>::get(&account);
+					(account, proof)
+				})
+				.collect()
+		}
+
+		/// Block at which `account` registered its identity, if it has one
+		pub fn registered_at(account: &T::AccountId) -> Option<BlockNumberFor<T>> {
+			IdentityProofs::<T>::get(account).map(|proof| proof.timestamp)
+		}
+
+		/// IPFS CID of the identity registered by `account`, if it has one
+		pub fn ipfs_cid_of(account: &T::AccountId) -> Option<Vec<u8>> {
+			IdentityProofs::<T>::get(account).map(|proof| proof.ipfs_cid.into_inner())
+		}
+
+		/// Owner of `biometric_hash` and their public handle, if the hash is registered and its
+		/// owner matches under `VerificationPolicy`
+		///
+		/// Like `dry_run_verify` this records nothing; relying parties use it to show a
+		/// human-readable name alongside a match.
+		pub fn verify_with_handle(
+			biometric_hash: &T::Hash,
+		) -> Option<(T::AccountId, Option<HandleOf<T>>)> {
+			let owner = Self::visible_owner(biometric_hash)?;
+			let handle = Handles::<T>::get(&owner);
+			Some((owner, handle))
+		}
+
+		/// IDs of the disputes opened by `account`, oldest first
+		pub fn disputes_created_by(account: &T::AccountId) -> Vec<u64> {
+			CreatedDisputes::<T>::get(account).into_inner()
+		}
+
+		/// Number of disputes each of `accounts` has opened, in the order given
+		///
+		/// Only the first `MAX_CREATOR_COUNT_ACCOUNTS` accounts are counted.
+		pub fn creator_dispute_counts(accounts: Vec<T::AccountId>) -> Vec<(T::AccountId, u32)> {
+			accounts
+				.into_iter()
+				.take(MAX_CREATOR_COUNT_ACCOUNTS as usize)
+				.map(|account| {
+					let count = CreatedDisputes::<T>::decode_len(&account).unwrap_or_default();
+					(account, count as u32)
+				})
+				.collect()
+		}
+
+		/// Numbers of open, resolved and rejected disputes, without iterating `Disputes`
+		///
+		/// Disputes waiting out `ResolutionDelay` count as open.
+		pub fn dispute_status_counts() -> (u32, u32, u32) {
+			(
+				PendingDisputeCount::<T>::get(),
+				ResolvedDisputeCount::<T>::get(),
+				RejectedDisputeCount::<T>::get(),
+			)
+		}
+
+		/// Disputes with the given status, in ID order, starting from `start`
+		///
+		/// At most `limit` entries are returned, capped at `MAX_DISPUTES_PER_PAGE`. Pass the last
+		/// returned ID plus one as the next `start`. Dispute IDs are walked one by one up to
+		/// `NextDisputeId`, so a page costs one read per ID scanned; only call this off-chain.
+		pub fn disputes_by_status(
+			status: DisputeStatus,
+			start: u64,
+			limit: u32,
+		) -> Vec<(u64, Dispute<T>)> {
+			let limit = limit.min(MAX_DISPUTES_PER_PAGE) as usize;
+			(start..NextDisputeId::<T>::get())
+				.filter_map(|dispute_id| Disputes::<T>::get(dispute_id).map(|dispute| (dispute_id, dispute)))
+				.filter(|(_, dispute)| dispute.status == status)
+				.take(limit)
+				.collect()
+		}
+
+		/// Voters of a dispute and how they voted, in the order the votes were cast
+		///
+		/// At most `limit` entries are returned, capped at `MAX_VOTERS_PER_PAGE`. Pass the last
+		/// returned account as the next `start` to continue after it.
+		pub fn dispute_voters(
+			dispute_id: u64,
+			start: Option<T::AccountId>,
+			limit: u32,
+		) -> Vec<(T::AccountId, bool)> {
+			let limit = limit.min(MAX_VOTERS_PER_PAGE) as usize;
+			let voters = DisputeVoterOrder::<T>::get(dispute_id);
+			let skip = match start {
+				Some(after) => match voters.iter().position(|voter| *voter == after) {
+					Some(index) => index + 1,
+					None => return Vec::new(),
+				},
+				None => 0,
+			};
+			voters
+				.into_iter()
+				.skip(skip)
+				.filter_map(|voter| {
+					DisputeVotes::<T>::get(dispute_id, &voter).map(|vote| (voter, vote))
+				})
+				.take(limit)
+				.collect()
+		}
+
+		/// Comments on a dispute with their index and author, oldest first
+		///
+		/// At most `limit` entries are returned, capped at `MAX_COMMENTS_PER_PAGE`, starting
+		/// at comment index `start`.
+		pub fn dispute_comments(
+			dispute_id: u64,
+			start: u32,
+			limit: u32,
+		) -> Vec<(u32, T::AccountId, CommentOf<T>)> {
+			let limit = limit.min(MAX_COMMENTS_PER_PAGE) as usize;
+			(start..DisputeCommentCount::<T>::get(dispute_id))
+				.filter_map(|index| {
+					DisputeComments::<T>::get(dispute_id, index)
+						.map(|(author, text)| (index, author, text))
+				})
+				.take(limit)
+				.collect()
+		}
+
+		/// Net votes (`votes_for - votes_against`) of an open dispute, and whether the current
+		/// tally would resolve it in favor if it were settled now
+		///
+		/// Returns `None` if the dispute does not exist or is already settled.
+		pub fn dispute_lean(dispute_id: u64) -> Option<(i64, bool)> {
+			let dispute = Disputes::<T>::get(dispute_id)?;
+			if !dispute.status.is_open() {
+				return None
+			}
+
+			let net = i64::from(dispute.votes_for) - i64::from(dispute.votes_against);
+			Some((net, Self::resolves_in_favor(dispute.votes_for, dispute.votes_against)))
+		}
+
+		/// Public handle of `account`, if it set one
+		pub fn handle_of(account: &T::AccountId) -> Option<HandleOf<T>> {
+			Handles::<T>::get(account)
+		}
+
+		/// Account holding `handle`, if any
+		pub fn account_of_handle(handle: &HandleOf<T>) -> Option<T::AccountId> {
+			HandleToAccount::<T>::get(handle)
+		}
+
+		/// Secondary accounts linked to `primary`'s identity (at most `MaxLinkedAccounts`)
+		pub fn linked_accounts(primary: &T::AccountId) -> Vec<T::AccountId> {
+			LinkedAccounts::<T>::iter_key_prefix(primary).collect()
+		}
+
+		/// Number of pending disputes against `account`'s identity
+		pub fn open_disputes_against(account: &T::AccountId) -> u32 {
+			OpenDisputesAgainst::<T>::get(account)
+		}
+
+		/// Every configured pallet constant, plus the dispute quorum
+		pub fn config_constants() -> ConstantsOf<T> {
+			ProofOfFaceConstants {
+				max_ipfs_cid_length: T::MaxIpfsCidLength::get(),
+				min_ipfs_cid_length: T::MinIpfsCidLength::get(),
+				max_evidence_url_length: T::MaxEvidenceUrlLength::get(),
+				max_gateway_len: T::MaxGatewayLen::get(),
+				max_handle_len: T::MaxHandleLen::get(),
+				max_note_len: T::MaxNoteLen::get(),
+				max_evidence_items: T::MaxEvidenceItems::get(),
+				prune_evidence_on_resolve: T::PruneEvidenceOnResolve::get(),
+				default_identity_ttl: T::DefaultIdentityTtl::get(),
+				max_expiries_per_block: T::MaxExpiriesPerBlock::get(),
+				max_import_batch: T::MaxImportBatch::get(),
+				max_takedown_batch: T::MaxTakedownBatch::get(),
+				max_query_batch: T::MaxQueryBatch::get(),
+				max_reputation: T::MaxReputation::get(),
+				reputation_threshold: T::ReputationThreshold::get(),
+				honest_voter_reward: T::HonestVoterReward::get(),
+				dishonest_voter_penalty: T::DishonestVoterPenalty::get(),
+				dispute_quorum: DISPUTE_QUORUM,
+				quorum_share: T::QuorumShare::get(),
+				reject_disputed_hashes: T::RejectDisputedHashes::get(),
+				tie_resolves_in_favor: T::TieResolvesInFavor::get(),
+				max_created_disputes: T::MaxCreatedDisputes::get(),
+				min_dispute_age: T::MinDisputeAge::get(),
+				jury_size: T::JurySize::get(),
+				max_jury_candidates: T::MaxJuryCandidates::get(),
+				registration_deposit: T::RegistrationDeposit::get(),
+				price_per_verification: T::PricePerVerification::get(),
+				free_verification_tier: T::FreeVerificationTier::get(),
+				summarize_batch_verifications: T::SummarizeBatchVerifications::get(),
+				skip_self_verification_events: T::SkipSelfVerificationEvents::get(),
+				verification_policy: T::VerificationPolicy::get(),
+				require_verification_before_dispute: T::RequireVerificationBeforeDispute::get(),
+				verification_validity: T::VerificationValidity::get(),
+				max_linked_accounts: T::MaxLinkedAccounts::get(),
+				max_registrations_per_block: T::MaxRegistrationsPerBlock::get(),
+				max_failed_verifications: T::MaxFailedVerifications::get(),
+				failed_verification_window: T::FailedVerificationWindow::get(),
+				deactivate_flagged_identities: T::DeactivateFlaggedIdentities::get(),
+				resolution_delay: T::ResolutionDelay::get(),
+				max_scheduled_resolutions: T::MaxScheduledResolutions::get(),
+				max_resolutions_per_block: T::MaxResolutionsPerBlock::get(),
+				milestone_interval: T::MilestoneInterval::get(),
+				enforce_gateway_allowlist: T::EnforceGatewayAllowlist::get(),
+				max_comment_len: T::MaxCommentLen::get(),
+				max_comments_per_dispute: T::MaxCommentsPerDispute::get(),
+				max_voters_per_dispute: T::MaxVotersPerDispute::get(),
+				max_delegators: T::MaxDelegators::get(),
+				min_distinct_voters: T::MinDistinctVoters::get(),
+				dispute_voting_period: T::DisputeVotingPeriod::get(),
+				voting_window: T::VotingWindow::get(),
+				recovery_delay: T::RecoveryDelay::get(),
+				max_sweep_per_call: T::MaxSweepPerCall::get(),
+				sweep_reward: T::SweepReward::get(),
+			}
+		}
+
+		/// Owner and proof behind a biometric hash
+		///
+		/// Fails with `IdentityNotFound` if the hash is unknown, or `ProofMissing` if the reverse
+		/// index points at an account whose proof is gone.
+		fn proof_by_hash(
+			biometric_hash: &T::Hash,
+		) -> Result<(T::AccountId, BiometricProofOf<T>), DispatchError> {
+			let owner = BiometricHashToOwner::<T>::get(biometric_hash)
+				.ok_or(Error::<T>::IdentityNotFound)?;
+			let proof = IdentityProofs::<T>::get(&owner).ok_or(Error::<T>::ProofMissing)?;
+			Ok((owner, proof))
+		}
+
+		/// Settle a dispute on its current tally, emitting `DisputeTied` first on a tie
+		///
+		/// See `close_dispute` for the effects and return value. The caller stores the dispute.
+		fn settle_dispute(dispute_id: u64, dispute: &mut Dispute<T>) -> u32 {
+			if dispute.votes_for == dispute.votes_against {
+				Self::deposit_event(Event::DisputeTied(Self::next_event_seq(), dispute_id));
+			}
+
+			let in_favor = Self::resolves_in_favor(dispute.votes_for, dispute.votes_against);
+			Self::close_dispute(dispute_id, dispute, in_favor, ResolutionReason::Vote)
+		}
+
+		/// Close a dispute as `Resolved` if `in_favor` and `Rejected` otherwise
+		///
+		/// Resolving deactivates the disputed identity. The dispute stops counting as open
+		/// against its owner, its voters' reputations are adjusted, and `DisputeResolved` is
+		/// emitted. The caller stores the dispute. Returns the number of voters looked at.
+		fn close_dispute(
+			dispute_id: u64,
+			dispute: &mut Dispute<T>,
+			in_favor: bool,
+			reason: ResolutionReason,
+		) -> u32 {
+			PendingDisputeCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			if in_favor {
+				dispute.status = DisputeStatus::Resolved;
+				ResolvedDisputeCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+				// Deactivate the disputed biometric proof, remembering which dispute did it
+				if let Some(owner) = BiometricHashToOwner::<T>::get(&dispute.face_proof_id) {
+					if let Some(mut proof) = IdentityProofs::<T>::get(&owner) {
+						if proof.is_active {
+							DeactivatedByDispute::<T>::insert(&owner, dispute_id);
+						}
+						proof.is_active = false;
+						IdentityProofs::<T>::insert(&owner, &proof);
+					}
+				}
+			} else {
+				dispute.status = DisputeStatus::Rejected;
+				RejectedDisputeCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			}
+			dispute.resolution_reason = Some(reason);
+			Self::note_dispute_closed(&dispute.face_proof_id);
+
+			Self::prune_evidence(dispute);
+
+			let voters = Self::reward_voters(dispute_id, in_favor);
+
+			Self::deposit_event(Event::DisputeResolved(
+				Self::next_event_seq(),
+				dispute_id,
+				dispute.status.clone(),
+				dispute.votes_for,
+				dispute.votes_against,
+				reason,
+			));
+
+			voters
+		}
+
+		/// Record `vote` on behalf of `delegate`'s delegators who have no vote on the dispute
+		/// yet, directly or through an earlier delegate
+		///
+		/// Returns the number of delegators whose weight was added. Benchmarked on its own as
+		/// `apply_delegated_votes`.
+		pub(crate) fn apply_delegated_votes(
+			dispute_id: u64,
+			delegate: &T::AccountId,
+			vote: bool,
+		) -> u32 {
+			let mut applied = 0;
+			for delegator in Delegators::<T>::get(delegate) {
+				if DisputeVotes::<T>::contains_key(dispute_id, &delegator) ||
+					DelegatedVotes::<T>::contains_key(dispute_id, &delegator)
+				{
+					continue
+				}
+				DelegatedVotes::<T>::insert(dispute_id, &delegator, vote);
+				applied += 1;
+			}
+			applied
+		}
+
+		/// Weight of `apply_delegated_votes` over `delegators` delegators, nothing when none
+		fn delegated_votes_weight(delegators: u32) -> Weight {
+			if delegators.is_zero() {
+				Weight::zero()
+			} else {
+				T::WeightInfo::apply_delegated_votes(delegators)
+			}
+		}
+
+		/// Drop `delegator` from the delegators of `delegate`
+		fn remove_delegator(delegate: &T::AccountId, delegator: &T::AccountId) {
+			Delegators::<T>::mutate_exists(delegate, |delegators| {
+				if let Some(list) = delegators {
+					list.retain(|account| account != delegator);
+					if list.is_empty() {
+						*delegators = None;
+					}
+				}
+			});
+		}
+
+		/// Votes an open-voting dispute created now needs to reach quorum
+		fn current_quorum() -> u32 {
+			T::QuorumShare::get().mul_floor(TotalIdentities::<T>::get()).max(DISPUTE_QUORUM)
+		}
+
+		/// Votes and distinct voters a dispute needs to reach quorum
+		///
+		/// The vote count is the dispute's snapshot; in jury mode that is all of the drawn
+		/// jurors.
+		fn quorum_of(dispute_id: u64, dispute: &Dispute<T>) -> (u32, u32) {
+			let quorum = dispute.quorum_snapshot;
+			if DisputeJury::<T>::contains_key(dispute_id) {
+				(quorum, T::MinDistinctVoters::get().min(quorum))
+			} else {
+				(quorum, T::MinDistinctVoters::get())
+			}
+		}
+
+		/// Settle a dispute that reached quorum, or schedule it to settle once
+		/// `ResolutionDelay` has passed. The caller stores the dispute.
+		///
+		/// Returns the number of voters looked at by the settlement, zero if it was scheduled.
+		fn on_quorum_reached(
+			dispute_id: u64,
+			dispute: &mut Dispute<T>,
+		) -> Result<u32, DispatchError> {
+			let delay = T::ResolutionDelay::get();
+			if delay.is_zero() {
+				return Ok(Self::settle_dispute(dispute_id, dispute))
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let resolves_at = now.saturating_add(delay);
+			ScheduledResolutions::<T>::try_mutate(resolves_at, |ids| ids.try_push(dispute_id))
+				.map_err(|_| Error::<T>::ResolutionQueueFull)?;
+
+			dispute.status = DisputeStatus::ReadyToResolve;
+			dispute.ready_at = Some(now);
+			Self::deposit_event(Event::DisputeReadyToResolve(
+				Self::next_event_seq(),
+				dispute_id,
+				resolves_at,
+			));
+			Ok(0)
+		}
+
+		/// Reward the voters of a closing dispute who have an identity
+		///
+		/// Those who voted with the outcome gain `HonestVoterReward` and the others lose
+		/// `DishonestVoterPenalty`. The work is bounded by `MaxVotersPerDispute`. Returns the
+		/// number of voters looked at. Benchmarked on its own as `reward_voters`.
+		pub(crate) fn reward_voters(dispute_id: u64, in_favor: bool) -> u32 {
+			let reward = T::HonestVoterReward::get();
+			let penalty = T::DishonestVoterPenalty::get();
+			if reward.is_zero() && penalty.is_zero() {
+				return 0
+			}
+
+			let voters = DisputeVoterOrder::<T>::get(dispute_id);
+			for voter in voters.iter() {
+				if !IdentityProofs::<T>::contains_key(voter) {
+					continue
+				}
+				match DisputeVotes::<T>::get(dispute_id, voter) {
+					Some(vote) if vote == in_favor => {
+						Self::increase_reputation(voter, reward);
+					},
+					Some(_) => {
+						Self::decrease_reputation(voter, penalty);
+					},
+					None => {},
+				}
+			}
+			voters.len() as u32
+		}
+
+		/// Weight of `reward_voters` having looked at `voters` voters, nothing when none were
+		fn reward_voters_weight(voters: u32) -> Weight {
+			if voters.is_zero() {
+				Weight::zero()
+			} else {
+				T::WeightInfo::reward_voters(voters)
+			}
+		}
+
+		/// Overturn a resolved dispute to `Rejected`, as the outcome of a successful appeal
+		///
+		/// The disputed identity is reactivated only if this dispute is the one that
+		/// deactivated it; an identity deactivated by its owner or by another dispute stays
+		/// inactive. Returns whether the identity was reactivated. Reached through
+		/// `resolve_appeal`.
+		pub(crate) fn overturn_dispute(dispute_id: u64) -> Result<bool, DispatchError> {
+			let mut dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(dispute.status == DisputeStatus::Resolved, Error::<T>::NotAuthorized);
+
+			dispute.status = DisputeStatus::Rejected;
+			dispute.resolution_reason = Some(ResolutionReason::Appeal);
+			Disputes::<T>::insert(dispute_id, &dispute);
+			ResolvedDisputeCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			RejectedDisputeCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+			let owner = match BiometricHashToOwner::<T>::get(&dispute.face_proof_id) {
+				Some(owner) if DeactivatedByDispute::<T>::get(&owner) == Some(dispute_id) => owner,
+				_ => return Ok(false),
+			};
+			DeactivatedByDispute::<T>::remove(&owner);
+			IdentityProofs::<T>::mutate(&owner, |maybe_proof| {
+				if let Some(proof) = maybe_proof {
+					proof.is_active = true;
+				}
+			});
+			Self::deposit_event(Event::IdentityReactivatedByAppeal(
+				Self::next_event_seq(),
+				owner,
+				dispute_id,
+			));
+
+			Ok(true)
+		}
+
+		/// Blocks after its creation from which a pending dispute can be swept as expired, zero
+		/// if disputes never expire
+		///
+		/// Voting closes with the `VotingWindow`, so a dispute whose window closed must expire
+		/// even if `DisputeVotingPeriod` is shorter or zero; it would stay pending otherwise.
+		fn dispute_lifetime() -> BlockNumberFor<T> {
+			T::DisputeVotingPeriod::get().max(T::VotingWindow::get())
+		}
+
+		/// Reject a pending dispute that outlived its `dispute_lifetime`
+		fn expire_dispute(dispute_id: u64, dispute: &mut Dispute<T>) {
+			dispute.status = DisputeStatus::Rejected;
+			dispute.resolution_reason = Some(ResolutionReason::Expiry);
+			PendingDisputeCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			RejectedDisputeCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			Self::note_dispute_closed(&dispute.face_proof_id);
+			Self::prune_evidence(dispute);
+			Self::deposit_event(Event::DisputeExpired(Self::next_event_seq(), dispute_id));
+		}
+
+		/// Drop the supplementary evidence of a closed dispute if `PruneEvidenceOnResolve` is
+		/// set, as it can no longer influence the outcome
+		fn prune_evidence(dispute: &mut Dispute<T>) {
+			if T::PruneEvidenceOnResolve::get() {
+				let evidence = sp_std::mem::take(&mut dispute.evidence);
+				dispute.evidence =
+					BoundedVec::truncate_from(evidence.into_iter().take(1).collect());
+			}
+		}
+
+		/// Account holding the funds that pay sweep rewards
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Ordered trie root of a batch of `(biometric_hash, matched, nonce)` verification
+		/// results
+		///
+		/// Each leaf is the SCALE encoding of one result, keyed by its position in the batch, so
+		/// a client holding a single result can check it against the root with a trie proof.
+		pub fn verification_root(results: &[(T::Hash, bool, u64)]) -> T::Hash {
+			let leaves = results.iter().map(|result| result.encode()).collect();
+			T::Hashing::ordered_trie_root(leaves, sp_runtime::StateVersion::V1)
+		}
+
+		/// Report a batch of verification results
+		///
+		/// Emits a single `BatchVerificationSummary` when `SummarizeBatchVerifications` is set,
+		/// and one `VerificationPerformed` per result otherwise. Not called by any extrinsic yet;
+		/// it is the reporting path for a future batch verification call.
+		pub fn deposit_verification_results(results: &[(T::Hash, bool, u64)]) {
+			if T::SummarizeBatchVerifications::get() {
+				let matched = results.iter().filter(|(_, matched, _)| *matched).count() as u32;
+				let root = Self::verification_root(results);
+				Self::deposit_event(Event::BatchVerificationSummary(
+					Self::next_event_seq(),
+					root,
+					matched,
+					results.len() as u32,
+				));
+			} else {
+				for (biometric_hash, matched, nonce) in results {
+					Self::deposit_event(Event::VerificationPerformed(
+						Self::next_event_seq(),
+						*biometric_hash,
+						*matched,
+						*nonce,
+					));
+				}
+			}
+		}
+
+		/// Stop counting a dispute against `face_proof_id` and its owner once it is settled
+		fn note_dispute_closed(face_proof_id: &T::Hash) {
+			OpenDisputesByHash::<T>::mutate_exists(face_proof_id, |open| {
+				*open = open.and_then(|open| open.checked_sub(1)).filter(|open| !open.is_zero());
+			});
+			if let Some(owner) = BiometricHashToOwner::<T>::get(face_proof_id) {
+				OpenDisputesAgainst::<T>::mutate(&owner, |open| *open = open.saturating_sub(1));
+			}
+		}
+
+		/// Fail with `HashUnderDispute` if `RejectDisputedHashes` is on and `biometric_hash`
+		/// has an open dispute
+		fn ensure_hash_not_disputed(biometric_hash: &T::Hash) -> DispatchResult {
+			ensure!(
+				!T::RejectDisputedHashes::get() ||
+					OpenDisputesByHash::<T>::get(biometric_hash).is_zero(),
+				Error::<T>::HashUnderDispute
+			);
+			Ok(())
+		}
+
+		/// Put disputes that did not fit under `MaxResolutionsPerBlock` at block `n` in front of
+		/// the disputes scheduled for the following blocks, spilling into later blocks when a
+		/// schedule is full
+		///
+		/// Returns the number of schedules rewritten.
+		fn defer_resolutions(n: BlockNumberFor<T>, mut ids: Vec<u64>) -> u64 {
+			let capacity = T::MaxScheduledResolutions::get().max(1) as usize;
+			let mut at = n;
+			let mut slots = 0u64;
+			while !ids.is_empty() {
+				at = at.saturating_add(1u32.into());
+				slots += 1;
+				ScheduledResolutions::<T>::mutate(at, |scheduled| {
+					ids.extend(scheduled.iter().copied());
+					let overflow = ids.split_off(ids.len().min(capacity));
+					let kept = sp_std::mem::replace(&mut ids, overflow);
+					*scheduled = BoundedVec::truncate_from(kept);
+				});
+			}
+			slots
+		}
+
+		/// Whether a settled tally resolves the dispute in favor (simple majority, with ties
+		/// decided by `TieResolvesInFavor` instead of defaulting to rejection)
+		fn resolves_in_favor(votes_for: u32, votes_against: u32) -> bool {
+			votes_for > votes_against || (votes_for == votes_against && T::TieResolvesInFavor::get())
+		}
+
+		/// Check whether `account` could register `biometric_hash` in the current block
+		///
+		/// Mirrors the checks performed by `register_identity` without mutating state, so
+		/// frontends can pre-validate before prompting for a camera scan.
+		pub fn can_register(account: &T::AccountId, biometric_hash: &T::Hash) -> RegisterEligibility {
+			if IdentityProofs::<T>::contains_key(account) {
+				return RegisterEligibility::AccountHasIdentity
+			}
+
+			if BiometricHashToOwner::<T>::contains_key(biometric_hash) {
+				return RegisterEligibility::HashTaken
+			}
+
+			// Registration fails while the expiry bucket it would land in is full
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			if let Some(at) = Self::expiry_from(current_block) {
+				if IdentityExpiries::<T>::decode_len(at).unwrap_or(0) >=
+					T::MaxExpiriesPerBlock::get() as usize
+				{
+					return RegisterEligibility::CooldownActive
+				}
+			}
+
+			if Self::registration_limit_reached() {
+				return RegisterEligibility::CooldownActive
+			}
+
+			RegisterEligibility::Eligible
+		}
+
+		/// Record a failed face match against `owner`'s identity
+		///
+		/// Failures are counted per `FailedVerificationWindow`; the failure that takes the count
+		/// past `MaxFailedVerifications` emits `IdentityFlagged`, once per window. Flagging is
+		/// advisory unless `DeactivateFlaggedIdentities` is set, in which case the identity is
+		/// also deactivated and recorded in `DeactivatedByFlag`. Failures are counted even
+		/// when `MaxFailedVerifications` is zero and flagging is off. Returns the failure count
+		/// in the current window.
+		pub fn note_failed_verification(owner: &T::AccountId) -> u32 {
+			let limit = T::MaxFailedVerifications::get();
+			let now = <frame_system::Pallet<T>>::block_number();
+			let failures = FailedVerifications::<T>::mutate(owner, |(count, window_start)| {
+				if now.saturating_sub(*window_start) >= T::FailedVerificationWindow::get() {
+					*count = 0;
+					*window_start = now;
+				}
+				*count = count.saturating_add(1);
+				*count
+			});
+
+			if !limit.is_zero() && failures == limit.saturating_add(1) {
+				if T::DeactivateFlaggedIdentities::get() {
+					IdentityProofs::<T>::mutate(owner, |maybe_proof| {
+						if let Some(proof) = maybe_proof {
+							proof.is_active = false;
+							DeactivatedByFlag::<T>::insert(owner, now);
+						}
+					});
+				}
+				Self::deposit_event(Event::IdentityFlagged(Self::next_event_seq(), owner.clone()));
+			}
+
+			failures
+		}
 
-			// Step 2: Check if user already has a registered identity
-			// This prevents users from registering multiple identities with the same account
-			ensure!(
-				!IdentityProofs::<T>::contains_key(&who),
-				Error::<T>::IdentityAlreadyExists
-			);
+		/// Raise an account's reputation, clamped to `MaxReputation`. Returns the new score.
+		pub fn increase_reputation(account: &T::AccountId, amount: u32) -> u32 {
+			Self::update_reputation(account, |score| score.saturating_add(amount))
+		}
 
-			// Step 3: Check if biometric_hash already exists in the system
-			// This prevents duplicate registrations of the same biometric data
-			ensure!(
-				!BiometricHashToOwner::<T>::contains_key(&biometric_hash),
-				Error::<T>::InvalidBiometricHash
-			);
+		/// Lower an account's reputation, flooring at zero. Returns the new score.
+		pub fn decrease_reputation(account: &T::AccountId, amount: u32) -> u32 {
+			Self::update_reputation(account, |score| score.saturating_sub(amount))
+		}
 
-			// Step 4: Validate that ipfs_cid is not empty
-			// An empty IPFS CID would indicate no actual face data is stored
-			ensure!(!ipfs_cid.is_empty(), Error::<T>::InvalidIpfsCid);
+		/// Apply `change` to an account's reputation, clamped to `MaxReputation`, and notify
+		/// `OnReputationThreshold` if the score moved across `ReputationThreshold`
+		fn update_reputation(account: &T::AccountId, change: impl FnOnce(u32) -> u32) -> u32 {
+			let (old, new) = Reputation::<T>::mutate(account, |score| {
+				let old = *score;
+				*score = change(old).min(T::MaxReputation::get());
+				(old, *score)
+			});
 
-			// Step 5: Get current block number for timestamp
-			// This provides an immutable record of when the identity was registered
-			let current_block = <frame_system::Pallet<T>>::block_number();
+			let threshold = T::ReputationThreshold::get();
+			if (old >= threshold) != (new >= threshold) {
+				T::OnReputationThreshold::on_reputation_threshold(account, new, new >= threshold);
+			}
 
-			// Step 6: Create BiometricProof struct with all required data
-			let biometric_proof = BiometricProof {
-				owner: who.clone(),
-				biometric_hash,
-				ipfs_cid,
-				timestamp: current_block,
-				is_active: true, // New identities are active by default
-			};
+			new
+		}
 
-			// Step 7: Store the proof in IdentityProofs storage
-			// This creates the primary mapping from AccountId to BiometricProof
-			IdentityProofs::<T>::insert(&who, &biometric_proof);
+		/// Reject evidence URLs that are empty or contain whitespace or control bytes
+		fn ensure_valid_evidence_url(url: &[u8]) -> DispatchResult {
+			ensure!(
+				!url.is_empty() && url.iter().all(|byte| byte.is_ascii_graphic()),
+				Error::<T>::InvalidEvidenceUrl
+			);
+			Ok(())
+		}
 
-			// Step 8: Store reverse mapping in BiometricHashToOwner
-			// This enables efficient lookup of identity owner by biometric hash
-			BiometricHashToOwner::<T>::insert(&biometric_hash, &who);
+		/// Reject gateways that are not `http(s)://` URL prefixes with a host, or that contain
+		/// whitespace or control bytes
+		fn ensure_valid_gateway(gateway: &[u8]) -> DispatchResult {
+			let host_and_path =
+				gateway.strip_prefix(b"https://").or_else(|| gateway.strip_prefix(b"http://"));
+			ensure!(
+				host_and_path.map_or(false, |rest| !rest.is_empty() && !rest.starts_with(b"/")) &&
+					gateway.iter().all(|byte| byte.is_ascii_graphic()),
+				Error::<T>::InvalidGateway
+			);
+			Ok(())
+		}
 
-			// Step 9: Emit IdentityRegistered event
-			// This notifies external systems (frontend, indexers) of the registration
-			Self::deposit_event(Event::IdentityRegistered(who, biometric_hash));
+		/// Take the sequence number for the next pallet event
+		fn next_event_seq() -> u64 {
+			EventSeq::<T>::mutate(|seq| {
+				let current = *seq;
+				*seq = seq.wrapping_add(1);
+				current
+			})
+		}
 
-			// Step 10: Return success
+		/// Fail with `PalletHalted` while the pallet is halted
+		fn ensure_not_halted() -> DispatchResult {
+			ensure!(!Halted::<T>::get(), Error::<T>::PalletHalted);
 			Ok(())
 		}
 
-		/// Perform verification against a registered biometric hash
-		///
-		/// This function checks if a given biometric hash exists in the system and emits
-		/// an audit event with the verification result. This is primarily used by the AI
-		/// service to verify face matches against registered identities.
-		///
-		/// # Audit Trail Concept
-		/// Even though this is essentially a read operation, we emit events to create
-		/// an immutable audit trail of all verification attempts. This serves multiple purposes:
-		/// - Compliance: Regulatory requirements for identity verification logging
-		/// - Security: Detection of potential brute force or enumeration attacks
-		/// - Analytics: Understanding verification patterns and system usage
-		/// - Transparency: Users can see when their identity was used for verification
-		///
-		/// # Parameters
-		/// - `origin`: The account performing the verification (must be signed)
-		/// - `biometric_hash`: The biometric hash to verify against registered identities
-		///
-		/// # Returns
-		/// Always returns `Ok(())` - verification failure is not an error, just no match
+		/// Ensure the host of an already validated gateway is allowlisted, if enforcement is on
+		fn ensure_gateway_allowed(gateway: &[u8]) -> DispatchResult {
+			if !T::EnforceGatewayAllowlist::get() {
+				return Ok(())
+			}
+			let host_and_path = gateway
+				.strip_prefix(b"https://")
+				.or_else(|| gateway.strip_prefix(b"http://"))
+				.unwrap_or(gateway);
+			let host = host_and_path
+				.split(|byte| *byte == b'/' || *byte == b':')
+				.next()
+				.unwrap_or_default();
+			let allowed = GatewayOf::<T>::try_from(host.to_vec())
+				.map_or(false, |host| AllowedGateways::<T>::contains_key(&host));
+			ensure!(allowed, Error::<T>::GatewayNotAllowed);
+			Ok(())
+		}
+
+		/// Identities examined when drawing a jury, for weighing dispute creation
+		fn jury_candidates_bound() -> u32 {
+			if T::JurySize::get().is_zero() {
+				0
+			} else {
+				T::MaxJuryCandidates::get()
+			}
+		}
+
+		/// Draw `JurySize` distinct jurors for a dispute from the active identities
 		///
-		/// # Events
-		/// - `VerificationPerformed(biometric_hash, true)`: If hash exists in system
-		/// - `VerificationPerformed(biometric_hash, false)`: If hash not found
-		#[pallet::call_index(1)]
-		#[pallet::weight(10_000)]
-		pub fn verify_identity(
-			origin: OriginFor<T>,
-			biometric_hash: T::Hash,
-		) -> DispatchResult {
-			// Step 1: Ensure origin is signed
-			// We require a signed transaction to create accountability for verification attempts
-			let _who = ensure_signed(origin)?;
+		/// At most `MaxJuryCandidates` identities are examined, active or not. The scan starts
+		/// at a random point of `IdentityProofs` and wraps around, so every identity can be
+		/// drawn however large the registry is. The accounts in `exclude` (the dispute's
+		/// creator and the disputed owner) are skipped.
+		fn select_jury(
+			dispute_id: u64,
+			exclude: &[T::AccountId],
+		) -> Result<BoundedVec<T::AccountId, T::JurySize>, DispatchError> {
+			let jury_size = T::JurySize::get() as usize;
+			let (seed, _) = T::Randomness::random(&(b"proofofface/jury", dispute_id).encode());
 
-			// Step 2: Check if biometric_hash exists in BiometricHashToOwner storage
-			// This is the core verification logic - does this biometric hash belong to any registered identity?
-			let verification_result = BiometricHashToOwner::<T>::contains_key(&biometric_hash);
+			let mut start = IdentityProofs::<T>::final_prefix().to_vec();
+			start.extend_from_slice(seed.as_ref());
+			let wrapped = IdentityProofs::<T>::iter()
+				.take_while(|(who, _)| IdentityProofs::<T>::hashed_key_for(who) <= start);
+			let mut candidates: Vec<T::AccountId> = IdentityProofs::<T>::iter_from(start.clone())
+				.chain(wrapped)
+				.take(T::MaxJuryCandidates::get() as usize)
+				.filter(|(who, proof)| proof.is_active && !exclude.contains(who))
+				.map(|(who, _)| who)
+				.collect();
+			ensure!(candidates.len() >= jury_size, Error::<T>::NotEnoughJurors);
 
-			// Step 3 & 4: Emit audit event based on verification result
-			// This creates an immutable record of the verification attempt on the blockchain
-			// The event includes both the hash being verified and whether it was found
-			if verification_result {
-				// Hash found - successful verification
-				Self::deposit_event(Event::VerificationPerformed(biometric_hash, true));
-			} else {
-				// Hash not found - no matching identity
-				Self::deposit_event(Event::VerificationPerformed(biometric_hash, false));
+			let mut jury = Vec::with_capacity(jury_size);
+			for round in 0..jury_size as u32 {
+				let entropy = T::Hashing::hash_of(&(seed, round));
+				let pick = u32::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+					.unwrap_or_default() as usize % candidates.len();
+				jury.push(candidates.swap_remove(pick));
 			}
 
-			// Always return Ok(()) - verification failure is not an error condition
-			// The caller can determine success/failure from the emitted event
-			Ok(())
+			jury.try_into().map_err(|_| Error::<T>::NotEnoughJurors.into())
 		}
 
-		/// Create a dispute against a biometric proof
-		#[pallet::call_index(2)]
-		#[pallet::weight(10_000)]
-		pub fn create_dispute(
-			origin: OriginFor<T>,
+		/// Whether `who` created, is disputed by, sits on the jury of or voted on `dispute`
+		fn is_dispute_participant(dispute: &Dispute<T>, who: &T::AccountId) -> bool {
+			dispute.creator == *who ||
+				BiometricHashToOwner::<T>::get(dispute.face_proof_id).as_ref() == Some(who) ||
+				DisputeJury::<T>::get(dispute.dispute_id)
+					.map_or(false, |jury| jury.contains(who)) ||
+				DisputeVotes::<T>::contains_key(dispute.dispute_id, who)
+		}
+
+		/// Validate and store a new dispute opened by `who`
+		///
+		/// Returns the owner of the disputed identity and the new dispute's ID.
+		fn do_create_dispute(
+			who: T::AccountId,
 			face_proof_id: T::Hash,
-			evidence_url: BoundedVec<u8, ConstU32<256>>,
-		) -> DispatchResult {
-			// 1. Ensure origin is signed
-			let who = ensure_signed(origin)?;
+			category: DisputeCategory,
+			evidence_url: EvidenceUrlOf<T>,
+		) -> Result<(T::AccountId, u64), DispatchError> {
+			// 1. Ensure the evidence URL is well-formed
+			Self::ensure_valid_evidence_url(&evidence_url)?;
 
-			// 2. Verify the face_proof_id exists (caller must own it)
-			let _owner = BiometricHashToOwner::<T>::get(&face_proof_id)
-				.ok_or(Error::<T>::IdentityNotFound)?;
+			// 2. Verify the face_proof_id belongs to a stored proof
+			let (owner, proof) = Self::proof_by_hash(&face_proof_id)?;
+
+			// 2b. Give freshly registered identities a grace period before they can be disputed
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				current_block.saturating_sub(proof.timestamp) >= T::MinDisputeAge::get(),
+				Error::<T>::IdentityTooNew
+			);
+
+			// 2c. When required, the creator must have verified this hash recently
+			if T::RequireVerificationBeforeDispute::get() {
+				let verified_at = RecentVerifications::<T>::take(&who, &face_proof_id)
+					.ok_or(Error::<T>::VerificationRequired)?;
+				ensure!(
+					current_block.saturating_sub(verified_at) <= T::VerificationValidity::get(),
+					Error::<T>::VerificationRequired
+				);
+			}
 
 			// 3. Get next dispute ID from NextDisputeId storage
 			let dispute_id = NextDisputeId::<T>::get();
 
 			// 4. Create Dispute struct with status: Pending
-			let dispute = Dispute {
+			let mut dispute = Dispute {
 				dispute_id,
 				face_proof_id,
 				creator: who.clone(),
-				evidence_url,
+				category,
+				evidence: BoundedVec::truncate_from(sp_std::vec![evidence_url]),
 				votes_for: 0,
 				votes_against: 0,
 				status: DisputeStatus::Pending,
-				created_at: <frame_system::Pallet<T>>::block_number(),
+				created_at: current_block,
+				acknowledged_at: None,
+				ready_at: None,
+				frozen: false,
+				resolution_reason: None,
+				quorum_snapshot: Self::current_quorum(),
 			};
 
-			// 5. Store in Disputes storage
+			// 5. Index the dispute under its creator, enforcing the per-account cap
+			CreatedDisputes::<T>::try_mutate(&who, |ids| ids.try_push(dispute_id))
+				.map_err(|_| Error::<T>::TooManyDisputes)?;
+
+			// 5b. In jury mode, draw the jurors who alone may vote on this dispute
+			if !T::JurySize::get().is_zero() {
+				let jury = Self::select_jury(dispute_id, &[who.clone(), owner.clone()])?;
+				dispute.quorum_snapshot = jury.len() as u32;
+				DisputeJury::<T>::insert(dispute_id, jury);
+			}
+
+			// 5c. Count the dispute as open against the disputed identity's owner and hash
+			OpenDisputesAgainst::<T>::mutate(&owner, |open| *open = open.saturating_add(1));
+			OpenDisputesByHash::<T>::mutate(&face_proof_id, |open| *open = open.saturating_add(1));
+			PendingDisputeCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+			// 6. Store in Disputes storage
 			Disputes::<T>::insert(dispute_id, &dispute);
 
-			// 6. Increment NextDisputeId
+			// 7. Increment NextDisputeId
 			NextDisputeId::<T>::put(dispute_id + 1);
 
-			// 7. Emit DisputeCreated event
-			Self::deposit_event(Event::DisputeCreated(dispute_id, who));
+			// 8. Emit DisputeCreated event
+			Self::deposit_event(Event::DisputeCreated(
+				Self::next_event_seq(),
+				dispute_id,
+				who,
+				category,
+			));
 
-			Ok(())
+			Ok((owner, dispute_id))
 		}
 
-		/// Vote on an open dispute
-		#[pallet::call_index(3)]
-		#[pallet::weight(10_000)]
-		pub fn vote_on_dispute(
-			origin: OriginFor<T>,
-			dispute_id: u64,
-			vote: bool, // true = agree it's unauthorized, false = disagree
-		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
-
-			// Get dispute info
-			let mut dispute = Disputes::<T>::get(dispute_id)
-				.ok_or(Error::<T>::DisputeNotFound)?;
-
-			// Ensure dispute is still pending
+		/// Validate a registration for `who` and store it
+		fn do_register_identity(
+			who: T::AccountId,
+			biometric_hash: T::Hash,
+			ipfs_cid: BoundedVec<u8, ConstU32<100>>,
+		) -> DispatchResult {
+			// Step 2: Check if user already has a registered identity
+			// This prevents users from registering multiple identities with the same account
 			ensure!(
-				matches!(dispute.status, DisputeStatus::Pending),
-				Error::<T>::DisputeAlreadyResolved
+				!IdentityProofs::<T>::contains_key(&who),
+				Error::<T>::IdentityAlreadyExists
 			);
 
-			// Ensure hasn't already voted
+			// Step 2b: A linked account already resolves to another person's identity
+			ensure!(!LinkedToPrimary::<T>::contains_key(&who), Error::<T>::AccountAlreadyLinked);
+
+			// Step 3: Check if biometric_hash already exists in the system
+			// This prevents duplicate registrations of the same biometric data
 			ensure!(
-				!DisputeVotes::<T>::contains_key(dispute_id, &who),
-				Error::<T>::AlreadyVoted
+				!BiometricHashToOwner::<T>::contains_key(&biometric_hash),
+				Error::<T>::InvalidBiometricHash
 			);
 
-			// Record the vote
-			DisputeVotes::<T>::insert(dispute_id, &who, vote);
+			// Step 3a: A hash released while disputed stays locked until its disputes settle
+			Self::ensure_hash_not_disputed(&biometric_hash)?;
 
-			// Update vote counts
-			if vote {
-				dispute.votes_for = dispute.votes_for.saturating_add(1);
-			} else {
-				dispute.votes_against = dispute.votes_against.saturating_add(1);
-			}
+			// Step 3b: Apply the deployment's own validation of the hash format
+			ensure!(T::HashValidator::validate(&biometric_hash), Error::<T>::InvalidBiometricHash);
 
-			// Check if dispute should be resolved (simple majority with minimum 10 votes)
-			let total_votes = dispute.votes_for + dispute.votes_against;
-			if total_votes >= 10u32 {
-				if dispute.votes_for > dispute.votes_against {
-					dispute.status = DisputeStatus::Resolved;
-					
-					// Deactivate the disputed biometric proof
-					if let Some(owner) = BiometricHashToOwner::<T>::get(&dispute.face_proof_id) {
-						if let Some(mut proof) = IdentityProofs::<T>::get(&owner) {
-							proof.is_active = false;
-							IdentityProofs::<T>::insert(&owner, &proof);
-						}
-					}
-				} else {
-					dispute.status = DisputeStatus::Rejected;
-				}
-				
-				Self::deposit_event(Event::DisputeResolved(dispute_id, dispute.status.clone()));
+			// Step 4: Validate that ipfs_cid is long enough to be a real CID
+			// An empty IPFS CID would indicate no actual face data is stored
+			ensure!(Self::ipfs_cid_is_valid(&ipfs_cid), Error::<T>::InvalidIpfsCid);
+
+			// Step 5: Enforce the network-wide per-block registration limit
+			ensure!(!Self::registration_limit_reached(), Error::<T>::RegistrationRateLimited);
+			RegistrationsThisBlock::<T>::mutate(|count| *count = count.saturating_add(1));
+
+			// Step 6: Reserve the registration deposit, held until the identity is purged
+			let deposit = T::RegistrationDeposit::get();
+			if !deposit.is_zero() {
+				T::Currency::reserve(&who, deposit)?;
+				RegistrationDeposits::<T>::insert(&who, deposit);
 			}
 
-			// Store updated dispute
-			Disputes::<T>::insert(dispute_id, &dispute);
+			// Step 7: Store the proof, its reverse mapping and expiry, and emit the event
+			LastRegistered::<T>::put((who.clone(), <frame_system::Pallet<T>>::block_number()));
+			Self::insert_identity(who, biometric_hash, ipfs_cid)
+		}
 
-			// Emit vote event
-			Self::deposit_event(Event::DisputeVoted(dispute_id, who, vote));
+		/// Remove every trace of `who`'s identity and return its registration deposit
+		///
+		/// Hashes merged into the identity and accounts linked to it are released as well, with
+		/// a `MergedHashReleased` event per hash.
+		fn remove_identity(who: &T::AccountId, proof: &BiometricProofOf<T>) {
+			IdentityProofs::<T>::remove(who);
+			BiometricHashToOwner::<T>::remove(&proof.biometric_hash);
+			for (merged_hash, _) in MergedHashes::<T>::drain_prefix(who) {
+				BiometricHashToOwner::<T>::remove(&merged_hash);
+				Self::deposit_event(Event::MergedHashReleased(
+					Self::next_event_seq(),
+					who.clone(),
+					merged_hash,
+				));
+			}
+			for (secondary, ()) in LinkedAccounts::<T>::drain_prefix(who) {
+				LinkedToPrimary::<T>::remove(&secondary);
+			}
+			if let Some(at) = proof.expires_at {
+				Self::unschedule_expiry(who, at);
+			}
+			if let Some(handle) = Handles::<T>::take(who) {
+				HandleToAccount::<T>::remove(&handle);
+			}
+			IdentityNotes::<T>::remove(who);
+			DeactivatedByDispute::<T>::remove(who);
+			TakenDown::<T>::remove(who);
+			DeactivatedByFlag::<T>::remove(who);
+			let _ = IdentityVerifiers::<T>::clear_prefix(who, u32::MAX, None);
+			UniqueVerifierCount::<T>::remove(who);
+			RecoveryAccounts::<T>::remove(who);
+			PendingRecoveries::<T>::remove(who);
+			if let Some(deposit) = RegistrationDeposits::<T>::take(who) {
+				let _ = T::Currency::unreserve(who, deposit);
+			}
 
-			Ok(().into())
+			TotalIdentities::<T>::mutate(|total| *total = total.saturating_sub(1));
 		}
-	
-		/// Deactivate a biometric proof (only by owner)
-		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::register_identity())]
-		pub fn deactivate_identity(
-			origin: OriginFor<T>,
-		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
 
-			// Get the identity proof
-			let mut proof = IdentityProofs::<T>::get(&who)
-				.ok_or(Error::<T>::IdentityNotFound)?;
+		/// Move the identity of `from`, whose proof is `proof`, to `to`, which has none
+		fn transfer_identity(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			mut proof: BiometricProofOf<T>,
+		) -> DispatchResult {
+			if let Some(at) = proof.expires_at {
+				Self::unschedule_expiry(from, at);
+				Self::schedule_expiry(to, at)?;
+			}
 
-			// Deactivate the proof
-			proof.is_active = false;
-			IdentityProofs::<T>::insert(&who, &proof);
+			IdentityProofs::<T>::remove(from);
+			proof.owner = to.clone();
+			BiometricHashToOwner::<T>::insert(&proof.biometric_hash, to);
+			IdentityProofs::<T>::insert(to, &proof);
 
-			Ok(().into())
+			for (merged_hash, original) in MergedHashes::<T>::drain_prefix(from) {
+				BiometricHashToOwner::<T>::insert(&merged_hash, to);
+				MergedHashes::<T>::insert(to, &merged_hash, original);
+			}
+			for (secondary, ()) in LinkedAccounts::<T>::drain_prefix(from) {
+				LinkedToPrimary::<T>::insert(&secondary, to);
+				LinkedAccounts::<T>::insert(to, &secondary, ());
+			}
+			if let Some(handle) = Handles::<T>::get(from) {
+				HandleToAccount::<T>::insert(&handle, to);
+			}
+			for (verifier, ()) in IdentityVerifiers::<T>::drain_prefix(from) {
+				IdentityVerifiers::<T>::insert(to, &verifier, ());
+			}
+			// `to` has no identity, so swapping leaves `from` without these entries
+			Handles::<T>::swap(from, to);
+			IdentityNotes::<T>::swap(from, to);
+			DeactivatedByDispute::<T>::swap(from, to);
+			TakenDown::<T>::swap(from, to);
+			DeactivatedByFlag::<T>::swap(from, to);
+			OpenDisputesAgainst::<T>::swap(from, to);
+			FailedVerifications::<T>::swap(from, to);
+			VerificationNonces::<T>::swap(from, to);
+			UniqueVerifierCount::<T>::swap(from, to);
+			RecoveryAccounts::<T>::remove(from);
+			PendingRecoveries::<T>::remove(from);
+
+			// The deposit stays reserved, now from the new owner
+			if let Some(deposit) = RegistrationDeposits::<T>::take(from) {
+				let moved = deposit.saturating_sub(T::Currency::repatriate_reserved(
+					from,
+					to,
+					deposit,
+					BalanceStatus::Reserved,
+				)?);
+				RegistrationDeposits::<T>::insert(to, moved);
+			}
+
+			Ok(())
 		}
 
-		/// Reactivate a biometric proof (only by owner)
-		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::register_identity())]
-		pub fn reactivate_identity(
-			origin: OriginFor<T>,
-		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+		/// Whether this block's registrations already hit `MaxRegistrationsPerBlock`
+		fn registration_limit_reached() -> bool {
+			let limit = T::MaxRegistrationsPerBlock::get();
+			limit != 0 && limit != u32::MAX && RegistrationsThisBlock::<T>::get() >= limit
+		}
 
-			// Get the identity proof
-			let mut proof = IdentityProofs::<T>::get(&who)
-				.ok_or(Error::<T>::IdentityNotFound)?;
+		/// Store a new, already validated identity and emit `IdentityRegistered`
+		fn insert_identity(
+			who: T::AccountId,
+			biometric_hash: T::Hash,
+			ipfs_cid: BoundedVec<u8, ConstU32<100>>,
+		) -> DispatchResult {
+			// Use the current block number as the registration timestamp
+			let current_block = <frame_system::Pallet<T>>::block_number();
 
-			// Reactivate the proof
-			proof.is_active = true;
-			IdentityProofs::<T>::insert(&who, &proof);
+			// Schedule the identity for expiry if a TTL is configured
+			let expires_at = Self::expiry_from(current_block);
+			if let Some(at) = expires_at {
+				Self::schedule_expiry(&who, at)?;
+			}
 
+			let biometric_proof = BiometricProof {
+				owner: who.clone(),
+				biometric_hash,
+				ipfs_cid,
+				timestamp: current_block,
+				is_active: true, // New identities are active by default
+				expires_at,
+				gateway: None,
+				private: false,
+			};
 
+			// Primary mapping from AccountId to BiometricProof
+			IdentityProofs::<T>::insert(&who, &biometric_proof);
 
-			Ok(().into())
-		}
-	}
+			// Reverse mapping for lookup of the owner by biometric hash
+			BiometricHashToOwner::<T>::insert(&biometric_hash, &who);
 
-	// Helper functions for querying
-	impl<T: Config> Pallet<T> {
-		/// Get biometric proof by account ID
-		pub fn get_identity_proof(account: &T::AccountId) -> Option<BiometricProof<T::AccountId, T::Hash, BlockNumberFor<T>>> {
-			IdentityProofs::<T>::get(account)
+			// Notify external systems (frontend, indexers) of the registration
+			Self::deposit_event(Event::IdentityRegistered(
+				Self::next_event_seq(),
+				who,
+				biometric_hash,
+			));
+
+			let total = TotalIdentities::<T>::mutate(|total| {
+				*total = total.saturating_add(1);
+				*total
+			});
+			Self::note_milestone(total);
+
+			Ok(())
 		}
 
-		/// Get owner by biometric hash
-		pub fn get_owner_by_hash(hash: &T::Hash) -> Option<T::AccountId> {
-			BiometricHashToOwner::<T>::get(hash)
+		/// Emit `IdentityMilestone` when `total` is a new multiple of `MilestoneInterval`
+		///
+		/// Milestones only ever move up, so dropping below one through purges and climbing
+		/// back does not announce it again.
+		fn note_milestone(total: u32) {
+			let interval = T::MilestoneInterval::get();
+			if interval == 0 || total % interval != 0 || total <= LastMilestone::<T>::get() {
+				return
+			}
+			LastMilestone::<T>::put(total);
+			Self::deposit_event(Event::IdentityMilestone(Self::next_event_seq(), total));
 		}
 
-		/// Check if identity is active
-		pub fn is_identity_active(account: &T::AccountId) -> bool {
-			if let Some(proof) = IdentityProofs::<T>::get(account) {
-				proof.is_active
+		/// Expiry block for an identity (re)validated at `now`, if expiry is enabled
+		fn expiry_from(now: BlockNumberFor<T>) -> Option<BlockNumberFor<T>> {
+			let ttl = T::DefaultIdentityTtl::get();
+			if ttl.is_zero() {
+				None
 			} else {
-				false
+				Some(now.saturating_add(ttl))
 			}
 		}
 
-		/// Get dispute by ID
-		pub fn get_dispute(dispute_id: u64) -> Option<Dispute<T::AccountId, T::Hash, BlockNumberFor<T>>> {
-			Disputes::<T>::get(dispute_id)
+		/// Add an account to the expiry index for the given block
+		fn schedule_expiry(who: &T::AccountId, at: BlockNumberFor<T>) -> DispatchResult {
+			IdentityExpiries::<T>::try_mutate(at, |queue| {
+				queue.try_push(who.clone()).map_err(|_| Error::<T>::ExpiryQueueFull.into())
+			})
 		}
 
-		/// Check if account has voted on dispute
-		pub fn has_voted(dispute_id: u64, account: &T::AccountId) -> bool {
-			DisputeVotes::<T>::contains_key(dispute_id, account)
+		/// Remove an account from the expiry index for the given block
+		fn unschedule_expiry(who: &T::AccountId, at: BlockNumberFor<T>) {
+			IdentityExpiries::<T>::mutate(at, |queue| queue.retain(|account| account != who));
+		}
+	}
+
+	#[cfg(any(feature = "try-runtime", test))]
+	impl<T: Config> Pallet<T> {
+		/// Check that `BiometricHashToOwner` and `IdentityProofs` describe the same identities
+		///
+		/// Every hash must point at an account whose stored proof carries that same hash, which
+		/// also rules out two hashes sharing one account. Every proof must in turn be reachable
+		/// through the reverse index. Anomalies are logged before the check fails.
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			for (biometric_hash, owner) in BiometricHashToOwner::<T>::iter() {
+				match IdentityProofs::<T>::get(&owner) {
+					Some(proof) if proof.biometric_hash == biometric_hash => {},
+					Some(_) if MergedHashes::<T>::contains_key(&owner, &biometric_hash) => {},
+					Some(proof) => {
+						log::error!(
+							target: LOG_TARGET,
+							"hash {:?} points at {:?}, whose proof stores hash {:?}",
+							biometric_hash,
+							owner,
+							proof.biometric_hash,
+						);
+						return Err("biometric hash does not match the owner's proof".into())
+					},
+					None => {
+						log::error!(
+							target: LOG_TARGET,
+							"hash {:?} points at {:?}, who has no proof",
+							biometric_hash,
+							owner,
+						);
+						return Err("biometric hash points at an account without a proof".into())
+					},
+				}
+			}
+
+			for (owner, proof) in IdentityProofs::<T>::iter() {
+				if BiometricHashToOwner::<T>::get(&proof.biometric_hash).as_ref() != Some(&owner) {
+					log::error!(
+						target: LOG_TARGET,
+						"proof of {:?} with hash {:?} is missing from the reverse index",
+						owner,
+						proof.biometric_hash,
+					);
+					return Err("identity proof is missing from the reverse index".into())
+				}
+			}
+
+			Self::ensure_open_dispute_counts()
+		}
+
+		/// Check that `OpenDisputesByHash` counts exactly the open disputes against each hash
+		pub fn ensure_open_dispute_counts() -> Result<(), sp_runtime::TryRuntimeError> {
+			let mut open = sp_std::collections::btree_map::BTreeMap::<T::Hash, u32>::new();
+			for dispute in Disputes::<T>::iter_values().filter(|d| d.status.is_open()) {
+				*open.entry(dispute.face_proof_id).or_default() += 1;
+			}
+
+			let counted = OpenDisputesByHash::<T>::iter().count();
+			for (biometric_hash, expected) in &open {
+				let stored = OpenDisputesByHash::<T>::get(biometric_hash);
+				if stored != *expected {
+					log::error!(
+						target: LOG_TARGET,
+						"hash {:?} has {} open disputes, but {} are counted",
+						biometric_hash,
+						expected,
+						stored,
+					);
+					return Err("open disputes by hash do not match the disputes".into())
+				}
+			}
+			if counted != open.len() {
+				log::error!(
+					target: LOG_TARGET,
+					"{} hashes have open disputes, but {} are counted",
+					open.len(),
+					counted,
+				);
+				return Err("open disputes by hash count hashes without open disputes".into())
+			}
+
+			Ok(())
+		}
+
+		/// Check that no two identities share a biometric hash, as after a runtime upgrade
+		///
+		/// The distinct hashes of `IdentityProofs`, together with the hashes merged into other
+		/// identities, must be exactly the entries of `BiometricHashToOwner`, and no two proofs
+		/// may carry the same hash. A migration that maps two identities to the same key leaves
+		/// fewer distinct hashes than proofs, even when the index holds just one entry for it.
+		pub fn ensure_unique_hashes() -> Result<(), sp_runtime::TryRuntimeError> {
+			let mut proofs = 0usize;
+			let distinct: sp_std::collections::btree_set::BTreeSet<T::Hash> =
+				IdentityProofs::<T>::iter_values()
+					.inspect(|_| proofs += 1)
+					.map(|proof| proof.biometric_hash)
+					.collect();
+			let merged = MergedHashes::<T>::iter_keys().count();
+			let indexed = BiometricHashToOwner::<T>::iter_keys().count();
+
+			if proofs != distinct.len() || distinct.len() + merged != indexed {
+				log::error!(
+					target: LOG_TARGET,
+					"{} proofs hold {} distinct hashes and {} are merged, but {} are indexed",
+					proofs,
+					distinct.len(),
+					merged,
+					indexed,
+				);
+				return Err("biometric hashes are not unique across identities".into())
+			}
+
+			Ok(())
 		}
 	}
 }
@@ -0,0 +1,64 @@
+//! Binary Merkle trees over biometric hashes, used by batch commitments
+//!
+//! Leaves are the hash of the biometric hash and each parent is the hash of its two children
+//! in sorted order, so a proof is just the list of siblings from the leaf up to the root. An
+//! odd node at the end of a level is promoted to the next level unchanged.
+
+use sp_runtime::traits::Hash;
+use sp_std::vec::Vec;
+
+/// Leaf committing to `biometric_hash`
+pub fn leaf<H: Hash>(biometric_hash: &[u8]) -> H::Output {
+	H::hash(biometric_hash)
+}
+
+/// Parent of two sibling nodes
+fn parent<H: Hash>(a: &H::Output, b: &H::Output) -> H::Output {
+	let (left, right) = if a <= b { (a, b) } else { (b, a) };
+	H::hash(&[left.as_ref(), right.as_ref()].concat())
+}
+
+/// Parents of the nodes in `level`, taken pairwise
+fn next_level<H: Hash>(level: &[H::Output]) -> Vec<H::Output> {
+	level
+		.chunks(2)
+		.map(|pair| match pair {
+			[a, b] => parent::<H>(a, b),
+			[a] => *a,
+			_ => unreachable!("chunks(2) yields one or two nodes"),
+		})
+		.collect()
+}
+
+/// Root of the tree whose leaves are `leaves`, or the default hash if there are none
+pub fn root<H: Hash>(leaves: &[H::Output]) -> H::Output {
+	let mut level = leaves.to_vec();
+	while level.len() > 1 {
+		level = next_level::<H>(&level);
+	}
+	level.first().copied().unwrap_or_default()
+}
+
+/// Siblings proving that the leaf at `index` is part of the tree over `leaves`
+pub fn proof<H: Hash>(leaves: &[H::Output], mut index: usize) -> Vec<H::Output> {
+	let mut siblings = Vec::new();
+	let mut level = leaves.to_vec();
+	while level.len() > 1 {
+		if let Some(sibling) = level.get(index ^ 1) {
+			siblings.push(*sibling);
+		}
+		level = next_level::<H>(&level);
+		index /= 2;
+	}
+	siblings
+}
+
+/// Root reached by following `siblings` up from `leaf`
+pub fn root_from_proof<H: Hash>(leaf: H::Output, siblings: &[H::Output]) -> H::Output {
+	siblings.iter().fold(leaf, |node, sibling| parent::<H>(&node, sibling))
+}
+
+/// Whether `siblings` lead from `leaf` up to `root`
+pub fn verify<H: Hash>(root: &H::Output, leaf: H::Output, siblings: &[H::Output]) -> bool {
+	root_from_proof::<H>(leaf, siblings) == *root
+}
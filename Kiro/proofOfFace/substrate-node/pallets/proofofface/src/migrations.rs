@@ -0,0 +1,490 @@
+//! Storage migrations for pallet-proofofface
+//!
+//! Each `MigrateToVn` brings storage from version `n - 1` to version `n` and leaves a chain
+//! at any other version untouched, so they can all be listed in a single runtime upgrade.
+//! Version 0 is the layout the pallet was first deployed with. Values that did not exist
+//! before a migration get the default that new values would have had at the time, e.g.
+//! identities migrated to v1 do not expire.
+
+use crate::{
+	BiometricProofOf, Config, Dispute, DisputeCategory, DisputeStatus, Disputes,
+	OpenDisputesByHash, Pallet, ResolutionReason, DISPUTE_QUORUM, LOG_TARGET,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	storage::migration::{put_storage_value, storage_iter},
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{marker::PhantomData, vec, vec::Vec};
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+/// Every migration, in the order they have to run
+pub type All<T> = (
+	MigrateToV1<T>,
+	MigrateToV2<T>,
+	MigrateToV3<T>,
+	MigrateToV4<T>,
+	MigrateToV5<T>,
+	MigrateToV6<T>,
+	MigrateToV7<T>,
+	MigrateToV8<T>,
+	MigrateToV9<T>,
+	MigrateToV10<T>,
+	MigrateToV11<T>,
+	MigrateToV12<T>,
+);
+
+/// Re-encode every value of the storage map `item` from `Old` to `New`
+///
+/// Returns how many values were migrated. Values that do not decode as `Old` are left as
+/// they are.
+fn translate<T: Config, Old: Decode, New: Encode>(item: &[u8], f: impl Fn(Old) -> New) -> u64 {
+	let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+	let entries: Vec<(Vec<u8>, Old)> = storage_iter::<Old>(pallet, item).collect();
+	let migrated = entries.len() as u64;
+	for (hashed_key, old) in entries {
+		put_storage_value(pallet, item, &hashed_key, f(old));
+	}
+	migrated
+}
+
+/// Run `migrate` if storage is at version `from`, then record version `from + 1`
+fn step<T: Config>(from: u16, migrate: impl FnOnce() -> u64) -> Weight {
+	if Pallet::<T>::on_chain_storage_version() != from {
+		log::info!(target: LOG_TARGET, "storage is not at v{}, skipping migration", from);
+		return T::DbWeight::get().reads(1)
+	}
+
+	let migrated = migrate();
+	StorageVersion::new(from + 1).put::<Pallet<T>>();
+	log::info!(target: LOG_TARGET, "migrated {} values to v{}", migrated, from + 1);
+
+	T::DbWeight::get().reads_writes(migrated + 1, migrated + 1)
+}
+
+/// `BiometricProof` as of v0
+#[derive(Decode)]
+struct ProofV0<AccountId, Hash, BlockNumber> {
+	owner: AccountId,
+	biometric_hash: Hash,
+	ipfs_cid: Vec<u8>,
+	timestamp: BlockNumber,
+	is_active: bool,
+}
+
+/// `BiometricProof` as of v1, with `expires_at`
+#[derive(Encode, Decode)]
+struct ProofV1<AccountId, Hash, BlockNumber> {
+	owner: AccountId,
+	biometric_hash: Hash,
+	ipfs_cid: Vec<u8>,
+	timestamp: BlockNumber,
+	is_active: bool,
+	expires_at: Option<BlockNumber>,
+}
+
+/// `BiometricProof` as of v5, with `gateway`
+#[derive(Encode, Decode)]
+struct ProofV5<AccountId, Hash, BlockNumber> {
+	owner: AccountId,
+	biometric_hash: Hash,
+	ipfs_cid: Vec<u8>,
+	timestamp: BlockNumber,
+	is_active: bool,
+	expires_at: Option<BlockNumber>,
+	gateway: Option<Vec<u8>>,
+}
+
+/// `Dispute` as of v0
+#[derive(Decode)]
+struct DisputeV0<AccountId, Hash, BlockNumber> {
+	dispute_id: u64,
+	face_proof_id: Hash,
+	creator: AccountId,
+	evidence_url: Vec<u8>,
+	votes_for: u32,
+	votes_against: u32,
+	status: DisputeStatus,
+	created_at: BlockNumber,
+}
+
+/// `Dispute` as of v2, with `category`
+#[derive(Encode, Decode)]
+struct DisputeV2<AccountId, Hash, BlockNumber> {
+	dispute_id: u64,
+	face_proof_id: Hash,
+	creator: AccountId,
+	category: DisputeCategory,
+	evidence_url: Vec<u8>,
+	votes_for: u32,
+	votes_against: u32,
+	status: DisputeStatus,
+	created_at: BlockNumber,
+}
+
+/// `Dispute` as of v3, with `acknowledged_at`
+#[derive(Encode, Decode)]
+struct DisputeV3<AccountId, Hash, BlockNumber> {
+	dispute_id: u64,
+	face_proof_id: Hash,
+	creator: AccountId,
+	category: DisputeCategory,
+	evidence_url: Vec<u8>,
+	votes_for: u32,
+	votes_against: u32,
+	status: DisputeStatus,
+	created_at: BlockNumber,
+	acknowledged_at: Option<BlockNumber>,
+}
+
+/// `Dispute` as of v4, with a list of evidence URLs
+#[derive(Encode, Decode)]
+struct DisputeV4<AccountId, Hash, BlockNumber> {
+	dispute_id: u64,
+	face_proof_id: Hash,
+	creator: AccountId,
+	category: DisputeCategory,
+	evidence: Vec<Vec<u8>>,
+	votes_for: u32,
+	votes_against: u32,
+	status: DisputeStatus,
+	created_at: BlockNumber,
+	acknowledged_at: Option<BlockNumber>,
+}
+
+/// `Dispute` as of v6, with `ready_at`
+#[derive(Encode, Decode)]
+struct DisputeV6<AccountId, Hash, BlockNumber> {
+	dispute_id: u64,
+	face_proof_id: Hash,
+	creator: AccountId,
+	category: DisputeCategory,
+	evidence: Vec<Vec<u8>>,
+	votes_for: u32,
+	votes_against: u32,
+	status: DisputeStatus,
+	created_at: BlockNumber,
+	acknowledged_at: Option<BlockNumber>,
+	ready_at: Option<BlockNumber>,
+}
+
+/// `Dispute` as of v7, with `frozen`
+#[derive(Encode, Decode)]
+struct DisputeV7<AccountId, Hash, BlockNumber> {
+	dispute_id: u64,
+	face_proof_id: Hash,
+	creator: AccountId,
+	category: DisputeCategory,
+	evidence: Vec<Vec<u8>>,
+	votes_for: u32,
+	votes_against: u32,
+	status: DisputeStatus,
+	created_at: BlockNumber,
+	acknowledged_at: Option<BlockNumber>,
+	ready_at: Option<BlockNumber>,
+	frozen: bool,
+}
+
+/// `Dispute` as of v9, with `resolution_reason`
+#[derive(Encode, Decode)]
+struct DisputeV9<AccountId, Hash, BlockNumber> {
+	dispute_id: u64,
+	face_proof_id: Hash,
+	creator: AccountId,
+	category: DisputeCategory,
+	evidence: Vec<Vec<u8>>,
+	votes_for: u32,
+	votes_against: u32,
+	status: DisputeStatus,
+	created_at: BlockNumber,
+	acknowledged_at: Option<BlockNumber>,
+	ready_at: Option<BlockNumber>,
+	frozen: bool,
+	resolution_reason: Option<ResolutionReason>,
+}
+
+/// Identities get an `expires_at`, unset for existing ones
+pub struct MigrateToV1<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(0, || {
+			translate::<T, ProofV0<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"IdentityProofs",
+				|old| ProofV1 {
+					owner: old.owner,
+					biometric_hash: old.biometric_hash,
+					ipfs_cid: old.ipfs_cid,
+					timestamp: old.timestamp,
+					is_active: old.is_active,
+					expires_at: None,
+				},
+			)
+		})
+	}
+}
+
+/// Disputes get a `category`, `Other` for existing ones
+pub struct MigrateToV2<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(1, || {
+			translate::<T, DisputeV0<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"Disputes",
+				|old| DisputeV2 {
+					dispute_id: old.dispute_id,
+					face_proof_id: old.face_proof_id,
+					creator: old.creator,
+					category: DisputeCategory::Other,
+					evidence_url: old.evidence_url,
+					votes_for: old.votes_for,
+					votes_against: old.votes_against,
+					status: old.status,
+					created_at: old.created_at,
+				},
+			)
+		})
+	}
+}
+
+/// Disputes get an `acknowledged_at`, unset for existing ones
+pub struct MigrateToV3<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(2, || {
+			translate::<T, DisputeV2<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"Disputes",
+				|old| DisputeV3 {
+					dispute_id: old.dispute_id,
+					face_proof_id: old.face_proof_id,
+					creator: old.creator,
+					category: old.category,
+					evidence_url: old.evidence_url,
+					votes_for: old.votes_for,
+					votes_against: old.votes_against,
+					status: old.status,
+					created_at: old.created_at,
+					acknowledged_at: None,
+				},
+			)
+		})
+	}
+}
+
+/// A dispute's evidence URL becomes the first item of its evidence list
+pub struct MigrateToV4<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(3, || {
+			translate::<T, DisputeV3<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"Disputes",
+				|old| DisputeV4 {
+					dispute_id: old.dispute_id,
+					face_proof_id: old.face_proof_id,
+					creator: old.creator,
+					category: old.category,
+					evidence: vec![old.evidence_url],
+					votes_for: old.votes_for,
+					votes_against: old.votes_against,
+					status: old.status,
+					created_at: old.created_at,
+					acknowledged_at: old.acknowledged_at,
+				},
+			)
+		})
+	}
+}
+
+/// Identities get a `gateway`, unset for existing ones
+pub struct MigrateToV5<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(4, || {
+			translate::<T, ProofV1<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"IdentityProofs",
+				|old| ProofV5 {
+					owner: old.owner,
+					biometric_hash: old.biometric_hash,
+					ipfs_cid: old.ipfs_cid,
+					timestamp: old.timestamp,
+					is_active: old.is_active,
+					expires_at: old.expires_at,
+					gateway: None,
+				},
+			)
+		})
+	}
+}
+
+/// Disputes get a `ready_at`, unset for existing ones
+pub struct MigrateToV6<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(5, || {
+			translate::<T, DisputeV4<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"Disputes",
+				|old| DisputeV6 {
+					dispute_id: old.dispute_id,
+					face_proof_id: old.face_proof_id,
+					creator: old.creator,
+					category: old.category,
+					evidence: old.evidence,
+					votes_for: old.votes_for,
+					votes_against: old.votes_against,
+					status: old.status,
+					created_at: old.created_at,
+					acknowledged_at: old.acknowledged_at,
+					ready_at: None,
+				},
+			)
+		})
+	}
+}
+
+/// Disputes get a `frozen` flag, cleared for existing ones
+pub struct MigrateToV7<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(6, || {
+			translate::<T, DisputeV6<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"Disputes",
+				|old| DisputeV7 {
+					dispute_id: old.dispute_id,
+					face_proof_id: old.face_proof_id,
+					creator: old.creator,
+					category: old.category,
+					evidence: old.evidence,
+					votes_for: old.votes_for,
+					votes_against: old.votes_against,
+					status: old.status,
+					created_at: old.created_at,
+					acknowledged_at: old.acknowledged_at,
+					ready_at: old.ready_at,
+					frozen: false,
+				},
+			)
+		})
+	}
+}
+
+/// Identities get a `private` flag, cleared for existing ones
+pub struct MigrateToV8<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(7, || {
+			translate::<T, ProofV5<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"IdentityProofs",
+				|old| -> BiometricProofOf<T> {
+					BiometricProofOf::<T> {
+						owner: old.owner,
+						biometric_hash: old.biometric_hash,
+						ipfs_cid: BoundedVec::truncate_from(old.ipfs_cid),
+						timestamp: old.timestamp,
+						is_active: old.is_active,
+						expires_at: old.expires_at,
+						gateway: old.gateway.map(BoundedVec::truncate_from),
+						private: false,
+					}
+				},
+			)
+		})
+	}
+}
+
+/// Disputes get a `resolution_reason`, unset for existing ones
+pub struct MigrateToV9<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(8, || {
+			translate::<T, DisputeV7<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"Disputes",
+				|old| DisputeV9 {
+					dispute_id: old.dispute_id,
+					face_proof_id: old.face_proof_id,
+					creator: old.creator,
+					category: old.category,
+					evidence: old.evidence,
+					votes_for: old.votes_for,
+					votes_against: old.votes_against,
+					status: old.status,
+					created_at: old.created_at,
+					acknowledged_at: old.acknowledged_at,
+					ready_at: old.ready_at,
+					frozen: old.frozen,
+					resolution_reason: None,
+				},
+			)
+		})
+	}
+}
+
+/// Disputes get a `quorum_snapshot`, `DISPUTE_QUORUM` for existing ones since that was the
+/// quorum they were opened under
+pub struct MigrateToV10<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV10<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(9, || {
+			translate::<T, DisputeV9<AccountIdOf<T>, T::Hash, BlockNumberFor<T>>, _>(
+				b"Disputes",
+				|old| -> Dispute<T> {
+					Dispute {
+						dispute_id: old.dispute_id,
+						face_proof_id: old.face_proof_id,
+						creator: old.creator,
+						category: old.category,
+						evidence: BoundedVec::truncate_from(
+							old.evidence.into_iter().map(BoundedVec::truncate_from).collect(),
+						),
+						votes_for: old.votes_for,
+						votes_against: old.votes_against,
+						status: old.status,
+						created_at: old.created_at,
+						acknowledged_at: old.acknowledged_at,
+						ready_at: old.ready_at,
+						frozen: old.frozen,
+						resolution_reason: old.resolution_reason,
+						quorum_snapshot: DISPUTE_QUORUM,
+					}
+				},
+			)
+		})
+	}
+}
+
+/// `OpenDisputesByHash` is filled in from the disputes that are open at the upgrade, which
+/// it did not count before
+pub struct MigrateToV11<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV11<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(10, || {
+			let _ = OpenDisputesByHash::<T>::clear(u32::MAX, None);
+			let mut disputes = 0u64;
+			for dispute in Disputes::<T>::iter_values() {
+				disputes += 1;
+				if dispute.status.is_open() {
+					OpenDisputesByHash::<T>::mutate(dispute.face_proof_id, |open| {
+						*open = open.saturating_add(1)
+					});
+				}
+			}
+			disputes
+		})
+	}
+}
+
+/// Recovery accounts no longer record when they were designated, as the `RecoveryDelay` now
+/// counts from `initiate_recovery`
+pub struct MigrateToV12<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV12<T> {
+	fn on_runtime_upgrade() -> Weight {
+		step::<T>(11, || {
+			translate::<T, (AccountIdOf<T>, BlockNumberFor<T>), _>(
+				b"RecoveryAccounts",
+				|(recovery, _designated_at)| recovery,
+			)
+		})
+	}
+}
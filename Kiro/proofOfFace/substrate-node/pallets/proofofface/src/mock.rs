@@ -1,12 +1,14 @@
 use crate as pallet_proofofface;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU16, ConstU32, ConstU64},
+	PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Permill,
 };
 use frame_system as system;
 
@@ -18,6 +20,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		ProofOfFaceModule: pallet_proofofface::{Pallet, Call, Storage, Event<T>},
 		InsecureRandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip::{Pallet, Storage},
 	}
@@ -40,31 +43,196 @@ impl system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
 }
 
 impl pallet_insecure_randomness_collective_flip::Config for Test {}
 
 parameter_types! {
 	pub const MaxIpfsCidLength: u32 = 100;
+	pub static MinIpfsCidLength: u32 = 1;
 	pub const MaxEvidenceUrlLength: u32 = 256;
+	pub const MaxEvidenceItems: u32 = 3;
+	pub static PruneEvidenceOnResolve: bool = false;
+	pub const DefaultIdentityTtl: u64 = 100;
+	pub const MaxExpiriesPerBlock: u32 = 10;
+	pub const MaxImportBatch: u32 = 5;
+	pub const MaxTakedownBatch: u32 = 3;
+	pub const MaxQueryBatch: u32 = 3;
+	pub const MaxReputation: u32 = 1_000;
+	pub static TieResolvesInFavor: bool = false;
+	pub const MaxCreatedDisputes: u32 = 3;
+	pub static MinDisputeAge: u64 = 0;
+	pub static JurySize: u32 = 0;
+	pub const MaxJuryCandidates: u32 = 10;
+	pub const PricePerVerification: u64 = 10;
+	pub static RegistrationDeposit: u64 = 0;
+	pub static FreeVerificationTier: bool = true;
+	pub static SummarizeBatchVerifications: bool = false;
+	pub static SkipSelfVerificationEvents: bool = false;
+	pub static VerificationPolicy: pallet_proofofface::VerificationPolicy =
+		pallet_proofofface::VerificationPolicy::ActiveAndPublic;
+	pub static RequireVerificationBeforeDispute: bool = false;
+	pub const VerificationValidity: u64 = 10;
+	pub const MigrationAuthority: u64 = 99;
+	pub static MaxRegistrationsPerBlock: u32 = 0;
+	pub static MaxFailedVerifications: u32 = 3;
+	pub const FailedVerificationWindow: u64 = 10;
+	pub static DeactivateFlaggedIdentities: bool = false;
+	pub static ResolutionDelay: u64 = 0;
+	pub const MaxScheduledResolutions: u32 = 10;
+	pub static MaxResolutionsPerBlock: u32 = 10;
+	pub static MilestoneInterval: u32 = 0;
+	pub static EnforceGatewayAllowlist: bool = false;
+	pub const MaxCommentsPerDispute: u32 = 3;
+	pub const MaxVotersPerDispute: u32 = 50;
+	pub const MaxDelegators: u32 = 5;
+	pub static MinDistinctVoters: u32 = 0;
+	pub static QuorumShare: Permill = Permill::zero();
+	pub static RejectDisputedHashes: bool = false;
+	pub static DisputeVotingPeriod: u64 = 20;
+	pub static VotingWindow: u64 = 0;
+	pub const RecoveryDelay: u64 = 10;
+	pub const MaxSweepPerCall: u32 = 5;
+	pub const SweepReward: u64 = 2;
+	pub const ProofOfFacePalletId: PalletId = PalletId(*b"py/pofce");
+	pub const MaxLinkedAccounts: u32 = 3;
+	pub const ReputationThreshold: u32 = 100;
+	pub static HonestVoterReward: u32 = 0;
+	pub static DishonestVoterPenalty: u32 = 0;
+	pub static ReputationCrossings: Vec<(u64, u32, bool)> = Vec::new();
+	pub static RejectedBiometricHash: Option<H256> = None;
+	pub static DeniedRegistrant: Option<u64> = None;
+}
+
+/// Rejects the hash stored in `RejectedBiometricHash`, if any
+pub struct MockHashValidator;
+
+impl pallet_proofofface::ValidateBiometricHash<H256> for MockHashValidator {
+	fn validate(hash: &H256) -> bool {
+		RejectedBiometricHash::get() != Some(*hash)
+	}
+}
+
+/// Denies registration to the account stored in `DeniedRegistrant`, if any
+pub struct MockRegistrationGate;
+
+impl pallet_proofofface::EnsureRegistrationAllowed<u64> for MockRegistrationGate {
+	fn is_allowed(who: &u64) -> bool {
+		DeniedRegistrant::get() != Some(*who)
+	}
+}
+
+/// Records every threshold crossing as `(account, score, above)` in `ReputationCrossings`
+pub struct RecordReputationCrossings;
+
+impl pallet_proofofface::OnReputationThreshold<u64> for RecordReputationCrossings {
+	fn on_reputation_threshold(who: &u64, score: u32, above: bool) {
+		let mut crossings = ReputationCrossings::get();
+		crossings.push((*who, score, above));
+		ReputationCrossings::set(&crossings);
+	}
 }
 
 impl pallet_proofofface::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxIpfsCidLength = MaxIpfsCidLength;
+	type MinIpfsCidLength = MinIpfsCidLength;
 	type MaxEvidenceUrlLength = MaxEvidenceUrlLength;
+	type MaxGatewayLen = ConstU32<64>;
+	type MaxHandleLen = ConstU32<32>;
+	type MaxNoteLen = ConstU32<16>;
+	type MaxEvidenceItems = MaxEvidenceItems;
+	type PruneEvidenceOnResolve = PruneEvidenceOnResolve;
 	type Randomness = InsecureRandomnessCollectiveFlip;
+	type DefaultIdentityTtl = DefaultIdentityTtl;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type MaxImportBatch = MaxImportBatch;
+	type MaxTakedownBatch = MaxTakedownBatch;
+	type MaxQueryBatch = MaxQueryBatch;
+	type MaxReputation = MaxReputation;
+	type ReputationThreshold = ReputationThreshold;
+	type OnReputationThreshold = RecordReputationCrossings;
+	type HonestVoterReward = HonestVoterReward;
+	type DishonestVoterPenalty = DishonestVoterPenalty;
+	type HashValidator = MockHashValidator;
+	type RegistrationGate = MockRegistrationGate;
+	type TieResolvesInFavor = TieResolvesInFavor;
+	type MaxCreatedDisputes = MaxCreatedDisputes;
+	type MinDisputeAge = MinDisputeAge;
+	type JurySize = JurySize;
+	type MaxJuryCandidates = MaxJuryCandidates;
+	type Currency = Balances;
+	type PricePerVerification = PricePerVerification;
+	type RegistrationDeposit = RegistrationDeposit;
+	type FreeVerificationTier = FreeVerificationTier;
+	type SummarizeBatchVerifications = SummarizeBatchVerifications;
+	type SkipSelfVerificationEvents = SkipSelfVerificationEvents;
+	type VerificationPolicy = VerificationPolicy;
+	type VerifierOrigin = frame_system::EnsureSigned<u64>;
+	type RequireVerificationBeforeDispute = RequireVerificationBeforeDispute;
+	type VerificationValidity = VerificationValidity;
+	type MigrationSignature = TestSignature;
+	type MigrationPublic = UintAuthorityId;
+	type LinkSignature = TestSignature;
+	type LinkPublic = UintAuthorityId;
+	type ReassignSignature = TestSignature;
+	type ReassignPublic = UintAuthorityId;
+	type MaxLinkedAccounts = MaxLinkedAccounts;
+	type MigrationAuthority = MigrationAuthority;
+	type MaxRegistrationsPerBlock = MaxRegistrationsPerBlock;
+	type MaxFailedVerifications = MaxFailedVerifications;
+	type FailedVerificationWindow = FailedVerificationWindow;
+	type DeactivateFlaggedIdentities = DeactivateFlaggedIdentities;
+	type ResolutionDelay = ResolutionDelay;
+	type MaxScheduledResolutions = MaxScheduledResolutions;
+	type MaxResolutionsPerBlock = MaxResolutionsPerBlock;
+	type MilestoneInterval = MilestoneInterval;
+	type EnforceGatewayAllowlist = EnforceGatewayAllowlist;
+	type MaxCommentLen = ConstU32<64>;
+	type MaxCommentsPerDispute = MaxCommentsPerDispute;
+	type MaxVotersPerDispute = MaxVotersPerDispute;
+	type MaxDelegators = MaxDelegators;
+	type MinDistinctVoters = MinDistinctVoters;
+	type QuorumShare = QuorumShare;
+	type RejectDisputedHashes = RejectDisputedHashes;
+	type DisputeVotingPeriod = DisputeVotingPeriod;
+	type VotingWindow = VotingWindow;
+	type RecoveryDelay = RecoveryDelay;
+	type MaxSweepPerCall = MaxSweepPerCall;
+	type SweepReward = SweepReward;
+	type PalletId = ProofOfFacePalletId;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut t = system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1_000), (2, 1_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	t.into()
 }
\ No newline at end of file
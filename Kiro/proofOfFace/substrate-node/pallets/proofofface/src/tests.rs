@@ -1,7 +1,16 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use crate::{
+	merkle, mock::*, weights::WeightInfo, BiometricHashToOwner, DisputeCategory, DisputeStatus,
+	Error, Event, IdentityProofs, RegisterEligibility, ResolutionReason, DISPUTE_QUORUM,
+	LINK_CONTEXT, MAX_CREATOR_COUNT_ACCOUNTS, MIGRATION_CONTEXT, REASSIGN_CONTEXT,
+};
+use codec::{Decode, Encode};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, BoundedVec};
 use sp_core::H256;
-use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{BlakeTwo256, Hash},
+	Permill,
+};
 
 /// Helper function to create a test biometric hash
 fn test_biometric_hash(seed: u8) -> H256 {
@@ -13,6 +22,84 @@ fn test_ipfs_cid(content: &str) -> BoundedVec<u8, frame_support::traits::ConstU3
 	BoundedVec::try_from(content.as_bytes().to_vec()).unwrap()
 }
 
+/// Helper function to create a test evidence URL
+fn test_evidence_url(content: &str) -> BoundedVec<u8, MaxEvidenceUrlLength> {
+	BoundedVec::try_from(content.as_bytes().to_vec()).unwrap()
+}
+
+/// Helper function to get the sequence number of the most recently emitted pallet event
+fn last_seq() -> u64 {
+	ProofOfFaceModule::event_seq() - 1
+}
+
+/// Helper function to zero the sequence number of a pallet event, so events can be compared
+/// without knowing how many were emitted before them
+fn unsequenced(event: RuntimeEvent) -> RuntimeEvent {
+	match event {
+		RuntimeEvent::ProofOfFaceModule(event) => {
+			let mut encoded = event.encode();
+			// The sequence number is the u64 right after the variant index
+			encoded[1..9].fill(0);
+			RuntimeEvent::ProofOfFaceModule(
+				Event::decode(&mut &encoded[..]).expect("only the sequence number changed"),
+			)
+		},
+		other => other,
+	}
+}
+
+/// Helper function to count how often a pallet event was emitted, whatever its sequence number
+fn count_unsequenced_events(event: RuntimeEvent) -> usize {
+	let event = unsequenced(event);
+	System::events().into_iter().filter(|record| unsequenced(record.event.clone()) == event).count()
+}
+
+/// Helper function to assert that a pallet event was emitted, whatever its sequence number
+fn assert_has_unsequenced_event(event: RuntimeEvent) {
+	assert!(
+		count_unsequenced_events(event.clone()) > 0,
+		"expected event {:?} was not emitted",
+		event
+	);
+}
+
+/// Helper function to register account 1 with hash 1 and have account 2 dispute it.
+/// Returns the hash and the new dispute ID.
+fn setup_dispute() -> (H256, u64) {
+	let biometric_hash = test_biometric_hash(1);
+	assert_ok!(ProofOfFaceModule::register_identity(
+		RuntimeOrigin::signed(1),
+		biometric_hash,
+		test_ipfs_cid("QmTestHash123456789abcdef")
+	));
+	let dispute_id = ProofOfFaceModule::next_dispute_id();
+	assert_ok!(ProofOfFaceModule::create_dispute(
+		RuntimeOrigin::signed(2),
+		biometric_hash,
+		DisputeCategory::Impersonation,
+		test_evidence_url("https://evidence.example/1")
+	));
+	(biometric_hash, dispute_id)
+}
+
+/// Helper function to cast votes from fresh voter accounts (starting at 100)
+fn cast_votes(dispute_id: u64, votes_for: u32, votes_against: u32) {
+	let mut voter = 100u64;
+	for vote in (0..votes_for).map(|_| true).chain((0..votes_against).map(|_| false)) {
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(voter), dispute_id, vote));
+		voter += 1;
+	}
+}
+
+/// Helper function to advance the chain, running the pallet's block hooks
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		ProofOfFaceModule::on_initialize(next);
+	}
+}
+
 #[test]
 fn register_identity_works() {
 	new_test_ext().execute_with(|| {
@@ -37,6 +124,7 @@ fn register_identity_works() {
 		assert_eq!(stored_proof.ipfs_cid, ipfs_cid);
 		assert_eq!(stored_proof.timestamp, 1); // Current block number
 		assert_eq!(stored_proof.is_active, true);
+		assert_eq!(stored_proof.expires_at, Some(1 + DefaultIdentityTtl::get()));
 
 		// Check that reverse lookup was stored
 		let owner = ProofOfFaceModule::biometric_hash_to_owner(biometric_hash).unwrap();
@@ -44,7 +132,7 @@ fn register_identity_works() {
 
 		// Check that event was emitted
 		System::assert_last_event(
-			Event::IdentityRegistered(account_id, biometric_hash).into(),
+			Event::IdentityRegistered(last_seq(), account_id, biometric_hash).into(),
 		);
 	});
 }
@@ -266,7 +354,7 @@ fn verify_identity_works_for_existing_identity() {
 		// Step 3: Check that the correct event was emitted
 		// The event should indicate successful verification (true)
 		System::assert_last_event(
-			Event::VerificationPerformed(biometric_hash, true).into(),
+			Event::VerificationPerformed(last_seq(), biometric_hash, true, 1).into(),
 		);
 	});
 }
@@ -290,7 +378,7 @@ fn verify_identity_works_for_non_existent_identity() {
 		// Check that the correct event was emitted
 		// The event should indicate failed verification (false)
 		System::assert_last_event(
-			Event::VerificationPerformed(non_existent_hash, false).into(),
+			Event::VerificationPerformed(last_seq(), non_existent_hash, false, 0).into(),
 		);
 	});
 }
@@ -338,11 +426,11 @@ fn verify_identity_creates_audit_trail() {
 		// Check the verification events
 		assert!(matches!(
 			events[1].event,
-			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, true))
+			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, _, true, _))
 		));
 		assert!(matches!(
 			events[2].event,
-			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, true))
+			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, _, true, _))
 		));
 	});
 }
@@ -363,12 +451,120 @@ fn verify_identity_fails_with_unsigned_origin() {
 	});
 }
 
+#[test]
+fn verification_nonce_increments_per_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_two_identities();
+		let first = test_biometric_hash(1);
+		let second = test_biometric_hash(2);
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(5), first));
+		System::assert_last_event(Event::VerificationPerformed(last_seq(), first, true, 1).into());
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(6), first));
+		System::assert_last_event(Event::VerificationPerformed(last_seq(), first, true, 2).into());
+
+		// Each identity counts on its own
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(5), second));
+		System::assert_last_event(Event::VerificationPerformed(last_seq(), second, true, 1).into());
+
+		// Misses do not advance any nonce
+		assert_ok!(ProofOfFaceModule::verify_identity(
+			RuntimeOrigin::signed(5),
+			test_biometric_hash(99)
+		));
+		assert_eq!(ProofOfFaceModule::verification_nonce(1), 2);
+		assert_eq!(ProofOfFaceModule::verification_nonce(2), 1);
+	});
+}
+
+#[test]
+fn dry_run_verify_reports_match_without_side_effects() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		System::reset_events();
+
+		let root_before = sp_io::storage::root(sp_runtime::StateVersion::V1);
+
+		assert!(ProofOfFaceModule::dry_run_verify(&biometric_hash));
+		assert!(!ProofOfFaceModule::dry_run_verify(&test_biometric_hash(99)));
+
+		// No audit events and no storage changes
+		assert!(System::events().is_empty());
+		assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root_before);
+	});
+}
+
+#[test]
+fn config_constants_match_runtime_configuration() {
+	new_test_ext().execute_with(|| {
+		let constants = ProofOfFaceModule::config_constants();
+
+		assert_eq!(constants.max_ipfs_cid_length, MaxIpfsCidLength::get());
+		assert_eq!(constants.max_evidence_url_length, MaxEvidenceUrlLength::get());
+		assert_eq!(constants.max_gateway_len, 64);
+		assert_eq!(constants.max_handle_len, 32);
+		assert_eq!(constants.max_evidence_items, MaxEvidenceItems::get());
+		assert_eq!(constants.default_identity_ttl, DefaultIdentityTtl::get());
+		assert_eq!(constants.max_reputation, MaxReputation::get());
+		assert_eq!(constants.dispute_quorum, DISPUTE_QUORUM);
+		assert_eq!(constants.jury_size, JurySize::get());
+		assert_eq!(constants.price_per_verification, PricePerVerification::get());
+		assert_eq!(constants.registration_deposit, RegistrationDeposit::get());
+		assert_eq!(constants.max_linked_accounts, MaxLinkedAccounts::get());
+		assert_eq!(constants.resolution_delay, ResolutionDelay::get());
+		assert_eq!(constants.milestone_interval, MilestoneInterval::get());
+
+		// Values toggled by a test are reported as currently configured
+		JurySize::set(&5);
+		assert_eq!(ProofOfFaceModule::config_constants().jury_size, 5);
+	});
+}
+
+#[test]
+fn hash_is_active_follows_owner_status() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert!(ProofOfFaceModule::hash_is_active(&biometric_hash));
+
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(1), false));
+		assert!(!ProofOfFaceModule::hash_is_active(&biometric_hash));
+
+		// Unknown hash
+		assert!(!ProofOfFaceModule::hash_is_active(&test_biometric_hash(99)));
+	});
+}
+
+#[test]
+fn hash_is_active_treats_dangling_owner_as_inactive() {
+	new_test_ext().execute_with(|| {
+		let biometric_hash = test_biometric_hash(1);
+		BiometricHashToOwner::<Test>::insert(biometric_hash, 1);
+
+		assert!(!ProofOfFaceModule::hash_is_active(&biometric_hash));
+	});
+}
+
 #[test]
 fn verify_identity_works_after_identity_deactivation() {
 	new_test_ext().execute_with(|| {
 		// This test shows that verification still works even if identity is deactivated
 		// The biometric hash remains in storage, only the is_active flag changes
-		
+		VerificationPolicy::set(&crate::VerificationPolicy::MatchAll);
 		System::set_block_number(1);
 
 		let identity_owner = 1u64;
@@ -385,7 +581,8 @@ fn verify_identity_works_after_identity_deactivation() {
 
 		// Deactivate the identity
 		assert_ok!(ProofOfFaceModule::deactivate_identity(
-			RuntimeOrigin::signed(identity_owner)
+			RuntimeOrigin::signed(identity_owner),
+			false
 		));
 
 		// Verification should still work (hash still exists in BiometricHashToOwner)
@@ -396,7 +593,7 @@ fn verify_identity_works_after_identity_deactivation() {
 
 		// Should emit successful verification event
 		System::assert_last_event(
-			Event::VerificationPerformed(biometric_hash, true).into(),
+			Event::VerificationPerformed(last_seq(), biometric_hash, true, 1).into(),
 		);
 	});
 }
@@ -456,15 +653,5837 @@ fn verify_identity_multiple_hashes_same_session() {
 		// Check verification results
 		assert!(matches!(
 			events[2].event,
-			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, true))
+			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, _, true, _))
 		));
 		assert!(matches!(
 			events[3].event,
-			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, true))
+			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, _, true, _))
 		));
 		assert!(matches!(
 			events[4].event,
-			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, false))
+			RuntimeEvent::ProofOfFaceModule(Event::VerificationPerformed(_, _, false, _))
+		));
+	});
+}
+
+// ================================
+// IDENTITY EXPIRY TESTS
+// ================================
+
+#[test]
+fn register_identity_schedules_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let account_id = 1u64;
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(account_id),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		// The account is indexed under its expiry block
+		let expires_at = 1 + DefaultIdentityTtl::get();
+		assert_eq!(ProofOfFaceModule::identity_expiries(expires_at).into_inner(), vec![account_id]);
+	});
+}
+
+#[test]
+fn identity_is_deactivated_once_expired() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let account_id = 1u64;
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(account_id),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		let expires_at = 1 + DefaultIdentityTtl::get();
+
+		// Still active the block before expiry
+		run_to_block(expires_at - 1);
+		assert!(ProofOfFaceModule::is_identity_active(&account_id));
+
+		// Deactivated on the expiry block, and the index entry is consumed
+		run_to_block(expires_at);
+		assert!(!ProofOfFaceModule::is_identity_active(&account_id));
+		assert!(ProofOfFaceModule::identity_expiries(expires_at).is_empty());
+		System::assert_last_event(Event::IdentityExpired(last_seq(), account_id).into());
+
+		// The biometric hash itself is kept
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(1)), Some(account_id));
+	});
+}
+
+#[test]
+fn expired_identity_cannot_be_reactivated_until_refreshed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let account_id = 1u64;
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(account_id),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		let expires_at = 1 + DefaultIdentityTtl::get();
+		run_to_block(expires_at);
+		assert_noop!(
+			ProofOfFaceModule::reactivate_identity(RuntimeOrigin::signed(account_id)),
+			Error::<Test>::IdentityExpired
+		);
+
+		// Refreshing schedules a new expiry, after which reactivation is allowed
+		assert_ok!(ProofOfFaceModule::refresh_identity(RuntimeOrigin::signed(account_id)));
+		assert_ok!(ProofOfFaceModule::reactivate_identity(RuntimeOrigin::signed(account_id)));
+		assert!(ProofOfFaceModule::is_identity_active(&account_id));
+	});
+}
+
+#[test]
+fn refresh_identity_extends_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let account_id = 1u64;
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(account_id),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		let original_expiry = 1 + DefaultIdentityTtl::get();
+
+		// Refresh halfway through the TTL
+		run_to_block(50);
+		assert_ok!(ProofOfFaceModule::refresh_identity(RuntimeOrigin::signed(account_id)));
+
+		let new_expiry = 50 + DefaultIdentityTtl::get();
+		let stored_proof = ProofOfFaceModule::identity_proofs(account_id).unwrap();
+		assert_eq!(stored_proof.expires_at, Some(new_expiry));
+		System::assert_last_event(
+			Event::IdentityRefreshed(last_seq(), account_id, new_expiry).into(),
+		);
+
+		// The old index entry is gone and the new one is in place
+		assert!(ProofOfFaceModule::identity_expiries(original_expiry).is_empty());
+		assert_eq!(ProofOfFaceModule::identity_expiries(new_expiry).into_inner(), vec![account_id]);
+
+		// Passing the original expiry no longer deactivates the identity
+		run_to_block(original_expiry);
+		assert!(ProofOfFaceModule::is_identity_active(&account_id));
+
+		run_to_block(new_expiry);
+		assert!(!ProofOfFaceModule::is_identity_active(&account_id));
+	});
+}
+
+#[test]
+fn refresh_identity_fails_without_identity() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProofOfFaceModule::refresh_identity(RuntimeOrigin::signed(1)),
+			Error::<Test>::IdentityNotFound
+		);
+	});
+}
+
+#[test]
+fn register_identity_fails_when_expiry_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Fill the expiry bucket for this block
+		for seed in 0..MaxExpiriesPerBlock::get() {
+			let account_id = seed as u64 + 1;
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account_id),
+				test_biometric_hash(seed as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(100),
+				test_biometric_hash(100),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			Error::<Test>::ExpiryQueueFull
+		);
+	});
+}
+
+// ================================
+// BATCH REGISTRATION TESTS
+// ================================
+
+/// Helper function to build a `batch_register` payload
+fn import_batch(
+	entries: Vec<(u64, u8)>,
+) -> BoundedVec<(u64, H256, BoundedVec<u8, frame_support::traits::ConstU32<100>>), MaxImportBatch> {
+	let items: Vec<_> = entries
+		.into_iter()
+		.map(|(account_id, seed)| (account_id, test_biometric_hash(seed), test_ipfs_cid("QmImportedHash")))
+		.collect();
+	BoundedVec::try_from(items).unwrap()
+}
+
+#[test]
+fn batch_register_skips_existing_hashes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Hash 2 is already registered to account 9
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(9),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_ok!(ProofOfFaceModule::batch_register(
+			RuntimeOrigin::root(),
+			import_batch(vec![(1, 1), (2, 2), (3, 3)])
+		));
+
+		// The duplicate is skipped, the others are imported
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(1)), Some(1));
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(2)), Some(9));
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(3)), Some(3));
+		assert!(ProofOfFaceModule::identity_proofs(2).is_none());
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+
+		System::assert_last_event(Event::BatchRegistered(last_seq(), 2, 1).into());
+	});
+}
+
+#[test]
+fn batch_register_accepts_full_batch() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let entries: Vec<_> = (1..=MaxImportBatch::get()).map(|i| (i as u64, i as u8)).collect();
+		assert_ok!(ProofOfFaceModule::batch_register(RuntimeOrigin::root(), import_batch(entries)));
+
+		System::assert_last_event(
+			Event::BatchRegistered(last_seq(), MaxImportBatch::get(), 0).into(),
+		);
+
+		// One entry over the bound cannot even be constructed
+		let oversized: Vec<_> = (0..=MaxImportBatch::get())
+			.map(|i| (i as u64, test_biometric_hash(i as u8), test_ipfs_cid("QmImportedHash")))
+			.collect();
+		assert!(BoundedVec::<_, MaxImportBatch>::try_from(oversized).is_err());
+	});
+}
+
+#[test]
+fn batch_register_requires_force_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProofOfFaceModule::batch_register(RuntimeOrigin::signed(1), import_batch(vec![(1, 1)])),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+// ================================
+// REPUTATION TESTS
+// ================================
+
+#[test]
+fn reputation_increases_saturate_at_max() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+
+		assert_eq!(ProofOfFaceModule::increase_reputation(&account_id, 400), 400);
+
+		// Repeated increments stop at the configured maximum
+		for _ in 0..10 {
+			ProofOfFaceModule::increase_reputation(&account_id, 400);
+		}
+		assert_eq!(ProofOfFaceModule::reputation(account_id), MaxReputation::get());
+
+		// Even an increment that would overflow u32 is clamped without panicking
+		assert_eq!(ProofOfFaceModule::increase_reputation(&account_id, u32::MAX), MaxReputation::get());
+	});
+}
+
+#[test]
+fn reputation_decreases_floor_at_zero() {
+	new_test_ext().execute_with(|| {
+		let account_id = 1u64;
+
+		ProofOfFaceModule::increase_reputation(&account_id, 50);
+		assert_eq!(ProofOfFaceModule::decrease_reputation(&account_id, 30), 20);
+
+		// Repeated decrements stop at zero
+		for _ in 0..5 {
+			ProofOfFaceModule::decrease_reputation(&account_id, 30);
+		}
+		assert_eq!(ProofOfFaceModule::reputation(account_id), 0);
+		assert_eq!(ProofOfFaceModule::decrease_reputation(&account_id, u32::MAX), 0);
+	});
+}
+
+// ================================
+// REGISTRATION ELIGIBILITY TESTS
+// ================================
+
+#[test]
+fn can_register_reports_eligible_for_fresh_account_and_hash() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_eq!(
+			ProofOfFaceModule::can_register(&1, &test_biometric_hash(1)),
+			RegisterEligibility::Eligible
+		);
+	});
+}
+
+#[test]
+fn can_register_reports_account_with_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_eq!(
+			ProofOfFaceModule::can_register(&1, &test_biometric_hash(2)),
+			RegisterEligibility::AccountHasIdentity
+		);
+	});
+}
+
+#[test]
+fn can_register_reports_taken_hash() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
 		));
+
+		assert_eq!(
+			ProofOfFaceModule::can_register(&2, &test_biometric_hash(1)),
+			RegisterEligibility::HashTaken
+		);
+	});
+}
+
+#[test]
+fn can_register_reports_cooldown_when_registrations_are_throttled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Fill this block's expiry bucket so further registrations are rejected
+		for seed in 0..MaxExpiriesPerBlock::get() {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(seed as u64 + 1),
+				test_biometric_hash(seed as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+
+		assert_eq!(
+			ProofOfFaceModule::can_register(&100, &test_biometric_hash(100)),
+			RegisterEligibility::CooldownActive
+		);
+
+		// The next block has room again
+		System::set_block_number(2);
+		assert_eq!(
+			ProofOfFaceModule::can_register(&100, &test_biometric_hash(100)),
+			RegisterEligibility::Eligible
+		);
+	});
+}
+
+#[test]
+fn can_register_does_not_mutate_state() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		ProofOfFaceModule::can_register(&1, &test_biometric_hash(1));
+
+		assert!(ProofOfFaceModule::identity_proofs(1).is_none());
+		assert!(System::events().is_empty());
+	});
+}
+
+// ================================
+// DISPUTE TIE-BREAK TESTS
+// ================================
+
+#[test]
+fn tied_dispute_is_rejected_by_default() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		TieResolvesInFavor::set(&false);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 5, 5);
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.status, DisputeStatus::Rejected);
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+#[test]
+fn tied_dispute_is_resolved_when_configured() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		TieResolvesInFavor::set(&true);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 5, 5);
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.status, DisputeStatus::Resolved);
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+#[test]
+fn clear_majority_ignores_tie_rule() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		TieResolvesInFavor::set(&true);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 4, 6);
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.status, DisputeStatus::Rejected);
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+#[test]
+fn tied_dispute_emits_tied_and_resolved_events() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		TieResolvesInFavor::set(&true);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 5, 5);
+
+		let events: Vec<_> =
+			System::events().into_iter().map(|record| unsequenced(record.event)).collect();
+		let tied = events
+			.iter()
+			.position(|e| *e == Event::DisputeTied(0, dispute_id).into())
+			.expect("DisputeTied should be emitted");
+		let resolved_event: RuntimeEvent = Event::DisputeResolved(
+			0,
+			dispute_id,
+			DisputeStatus::Resolved,
+			5,
+			5,
+			ResolutionReason::Vote,
+		)
+		.into();
+		let resolved = events
+			.iter()
+			.position(|e| *e == resolved_event)
+			.expect("DisputeResolved should be emitted");
+		assert!(tied < resolved);
+	});
+}
+
+#[test]
+fn untied_dispute_does_not_emit_tied_event() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 6, 4);
+
+		assert_eq!(count_unsequenced_events(Event::DisputeTied(0, dispute_id).into()), 0);
+		assert_has_unsequenced_event(
+			Event::DisputeResolved(
+				0,
+				dispute_id,
+				DisputeStatus::Resolved,
+				6,
+				4,
+				ResolutionReason::Vote,
+			)
+			.into(),
+		);
+	});
+}
+
+// ================================
+// DISPUTES BY CREATOR TESTS
+// ================================
+
+#[test]
+fn created_disputes_accumulate_per_creator() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (biometric_hash, first_id) = setup_dispute();
+
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(2),
+			biometric_hash,
+			DisputeCategory::Impersonation,
+			test_evidence_url("https://evidence.example/2")
+		));
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Impersonation,
+			test_evidence_url("https://evidence.example/3")
+		));
+
+		assert_eq!(ProofOfFaceModule::disputes_created_by(&2), vec![first_id, first_id + 1]);
+		assert_eq!(ProofOfFaceModule::disputes_created_by(&3), vec![first_id + 2]);
+		assert!(ProofOfFaceModule::disputes_created_by(&4).is_empty());
+	});
+}
+
+#[test]
+fn create_dispute_fails_past_creator_cap() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (biometric_hash, _) = setup_dispute();
+		for _ in 1..MaxCreatedDisputes::get() {
+			assert_ok!(ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(2),
+				biometric_hash,
+				DisputeCategory::Impersonation,
+				test_evidence_url("https://evidence.example/more")
+			));
+		}
+
+		assert_noop!(
+			ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(2),
+				biometric_hash,
+				DisputeCategory::Impersonation,
+				test_evidence_url("https://evidence.example/too-many")
+			),
+			Error::<Test>::TooManyDisputes
+		);
+		assert_eq!(
+			ProofOfFaceModule::disputes_created_by(&2).len(),
+			MaxCreatedDisputes::get() as usize
+		);
+	});
+}
+
+#[test]
+fn creator_dispute_counts_match_created_disputes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (biometric_hash, _) = setup_dispute();
+		for (creator, url) in [(2, "https://evidence.example/2"), (3, "https://evidence.example/3")] {
+			assert_ok!(ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(creator),
+				biometric_hash,
+				DisputeCategory::Impersonation,
+				test_evidence_url(url)
+			));
+		}
+
+		assert_eq!(
+			ProofOfFaceModule::creator_dispute_counts(vec![3, 2, 4]),
+			vec![(3, 1), (2, 2), (4, 0)]
+		);
+
+		// Oversized queries are truncated rather than rejected
+		let accounts: Vec<u64> = (0..MAX_CREATOR_COUNT_ACCOUNTS as u64 + 5).collect();
+		assert_eq!(
+			ProofOfFaceModule::creator_dispute_counts(accounts).len(),
+			MAX_CREATOR_COUNT_ACCOUNTS as usize
+		);
+	});
+}
+
+// ================================
+// MINIMUM DISPUTE AGE TESTS
+// ================================
+
+#[test]
+fn create_dispute_fails_for_new_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MinDisputeAge::set(&5);
+
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		// Immediately after registration
+		assert_noop!(
+			ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(2),
+				biometric_hash,
+				DisputeCategory::Impersonation,
+				test_evidence_url("https://evidence.example/1")
+			),
+			Error::<Test>::IdentityTooNew
+		);
+
+		// One block short of the required age
+		run_to_block(5);
+		assert_noop!(
+			ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(2),
+				biometric_hash,
+				DisputeCategory::Impersonation,
+				test_evidence_url("https://evidence.example/1")
+			),
+			Error::<Test>::IdentityTooNew
+		);
+	});
+}
+
+#[test]
+fn create_dispute_succeeds_once_identity_is_old_enough() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MinDisputeAge::set(&5);
+
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		run_to_block(6);
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(2),
+			biometric_hash,
+			DisputeCategory::Impersonation,
+			test_evidence_url("https://evidence.example/1")
+		));
+		assert_eq!(ProofOfFaceModule::disputes(0).unwrap().created_at, 6);
+	});
+}
+
+// ================================
+// UPDATE EVIDENCE TESTS
+// ================================
+
+#[test]
+fn creator_can_update_evidence() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		let new_url = test_evidence_url("https://evidence.example/fixed");
+
+		assert_ok!(ProofOfFaceModule::update_evidence(
+			RuntimeOrigin::signed(2),
+			dispute_id,
+			new_url.clone()
+		));
+
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().evidence_url(), Some(&new_url));
+		System::assert_last_event(Event::EvidenceUpdated(last_seq(), dispute_id).into());
+	});
+}
+
+#[test]
+fn non_creator_cannot_update_evidence() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+
+		// Not even the disputed identity's owner
+		assert_noop!(
+			ProofOfFaceModule::update_evidence(
+				RuntimeOrigin::signed(1),
+				dispute_id,
+				test_evidence_url("https://evidence.example/forged")
+			),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn update_evidence_fails_on_resolved_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 7, 3);
+
+		assert_noop!(
+			ProofOfFaceModule::update_evidence(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				test_evidence_url("https://evidence.example/late")
+			),
+			Error::<Test>::DisputeAlreadyResolved
+		);
+	});
+}
+
+#[test]
+fn update_evidence_rejects_invalid_url() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+
+		assert_noop!(
+			ProofOfFaceModule::update_evidence(RuntimeOrigin::signed(2), dispute_id, test_evidence_url("")),
+			Error::<Test>::InvalidEvidenceUrl
+		);
+		assert_noop!(
+			ProofOfFaceModule::update_evidence(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				test_evidence_url("https://evidence.example/with space")
+			),
+			Error::<Test>::InvalidEvidenceUrl
+		);
+	});
+}
+
+// ================================
+// DISPUTE CATEGORY TESTS
+// ================================
+
+#[test]
+fn disputes_store_their_category() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (biometric_hash, first_id) = setup_dispute();
+		assert_eq!(
+			ProofOfFaceModule::disputes(first_id).unwrap().category,
+			DisputeCategory::Impersonation
+		);
+
+		let categories = [DisputeCategory::Duplicate, DisputeCategory::Fraud, DisputeCategory::Other];
+		for (offset, category) in categories.iter().enumerate() {
+			let dispute_id = first_id + 1 + offset as u64;
+			assert_ok!(ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(3 + offset as u64),
+				biometric_hash,
+				*category,
+				test_evidence_url("https://evidence.example/category")
+			));
+			System::assert_last_event(
+				Event::DisputeCreated(last_seq(), dispute_id, 3 + offset as u64, *category).into(),
+			);
+			assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().category, *category);
+		}
+	});
+}
+
+// ================================
+// DISPUTES BY STATUS TESTS
+// ================================
+
+#[test]
+fn disputes_by_status_returns_only_matching_disputes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (biometric_hash, resolved_id) = setup_dispute();
+		let pending_id = ProofOfFaceModule::next_dispute_id();
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Duplicate,
+			test_evidence_url("https://evidence.example/2")
+		));
+		cast_votes(resolved_id, 7, 3);
+
+		let pending = ProofOfFaceModule::disputes_by_status(DisputeStatus::Pending, 0, 10);
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].0, pending_id);
+		assert_eq!(pending[0].1.creator, 3);
+
+		let resolved = ProofOfFaceModule::disputes_by_status(DisputeStatus::Resolved, 0, 10);
+		assert_eq!(resolved.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![resolved_id]);
+
+		assert!(ProofOfFaceModule::disputes_by_status(DisputeStatus::Rejected, 0, 10).is_empty());
+	});
+}
+
+#[test]
+fn disputes_by_status_paginates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (biometric_hash, first_id) = setup_dispute();
+		for creator in 3..5 {
+			assert_ok!(ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(creator),
+				biometric_hash,
+				DisputeCategory::Other,
+				test_evidence_url("https://evidence.example/page")
+			));
+		}
+
+		let ids = |start, limit| {
+			ProofOfFaceModule::disputes_by_status(DisputeStatus::Pending, start, limit)
+				.into_iter()
+				.map(|(id, _)| id)
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(ids(first_id, 2), vec![first_id, first_id + 1]);
+		assert_eq!(ids(first_id + 2, 2), vec![first_id + 2]);
+		assert!(ids(first_id + 3, 2).is_empty());
+		assert!(ids(first_id, 0).is_empty());
+	});
+}
+
+// ================================
+// RESOLUTION TALLY TESTS
+// ================================
+
+#[test]
+fn dispute_resolved_event_carries_final_tally() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 3, 7);
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.status, DisputeStatus::Rejected);
+		assert_has_unsequenced_event(
+			Event::DisputeResolved(
+				0,
+				dispute_id,
+				dispute.status,
+				dispute.votes_for,
+				dispute.votes_against,
+				ResolutionReason::Vote,
+			)
+			.into(),
+		);
+		assert_eq!((dispute.votes_for, dispute.votes_against), (3, 7));
+	});
+}
+
+// ================================
+// JURY MODE TESTS
+// ================================
+
+/// Helper: register identities 1..=4, then have account 10 dispute identity 1 with a jury of 2.
+/// Returns the dispute ID, its jurors and the one eligible identity left off the jury.
+fn setup_juried_dispute() -> (u64, Vec<u64>, u64) {
+	JurySize::set(&2);
+	for account in 1..=4u64 {
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(account),
+			test_biometric_hash(account as u8),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+	}
+
+	let dispute_id = ProofOfFaceModule::next_dispute_id();
+	assert_ok!(ProofOfFaceModule::create_dispute(
+		RuntimeOrigin::signed(10),
+		test_biometric_hash(1),
+		DisputeCategory::Fraud,
+		test_evidence_url("https://evidence.example/jury")
+	));
+
+	let jury = ProofOfFaceModule::dispute_jury(dispute_id).expect("jury should be drawn").into_inner();
+	let left_out = (2..=4u64).find(|account| !jury.contains(account)).unwrap();
+	(dispute_id, jury, left_out)
+}
+
+#[test]
+fn jury_excludes_creator_and_disputed_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, jury, _) = setup_juried_dispute();
+
+		assert_eq!(jury.len(), 2);
+		assert_ne!(jury[0], jury[1]);
+		assert!(jury.iter().all(|juror| (2..=4).contains(juror)));
+	});
+}
+
+#[test]
+fn juror_can_vote() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (dispute_id, jury, _) = setup_juried_dispute();
+
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(jury[0]), dispute_id, true));
+		assert_eq!(ProofOfFaceModule::dispute_votes(dispute_id, jury[0]), Some(true));
+	});
+}
+
+#[test]
+fn non_juror_cannot_vote() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (dispute_id, _, left_out) = setup_juried_dispute();
+
+		assert_noop!(
+			ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(left_out), dispute_id, true),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(100), dispute_id, true),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn jury_majority_resolves_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (dispute_id, jury, _) = setup_juried_dispute();
+
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(jury[0]), dispute_id, true));
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Pending);
+
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(jury[1]), dispute_id, true));
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Resolved);
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+#[test]
+fn create_dispute_fails_without_enough_jurors() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		JurySize::set(&3);
+
+		let biometric_hash = test_biometric_hash(1);
+		for account in 1..=3u64 {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+
+		// Only accounts 2 and 3 are eligible once the owner is excluded
+		assert_noop!(
+			ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(10),
+				biometric_hash,
+				DisputeCategory::Fraud,
+				test_evidence_url("https://evidence.example/jury")
+			),
+			Error::<Test>::NotEnoughJurors
+		);
+	});
+}
+
+#[test]
+fn juries_are_drawn_beyond_the_first_max_jury_candidates_identities() {
+	new_test_ext().execute_with(|| {
+		JurySize::set(&1);
+		let registered = 3 * MaxJuryCandidates::get() as u64;
+		for account in 1..=registered {
+			// One identity per block keeps each block's expiry queue short
+			System::set_block_number(account);
+			register_accounts(account..=account);
+		}
+		let first_candidates: Vec<u64> = IdentityProofs::<Test>::iter_keys()
+			.take(MaxJuryCandidates::get() as usize)
+			.collect();
+
+		let mut jurors = Vec::new();
+		for target in 1..=registered {
+			let dispute_id = ProofOfFaceModule::next_dispute_id();
+			assert_ok!(ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(100 + target),
+				test_biometric_hash(target as u8),
+				DisputeCategory::Fraud,
+				test_evidence_url("https://evidence.example/jury")
+			));
+			jurors.extend(ProofOfFaceModule::dispute_jury(dispute_id).unwrap().into_inner());
+		}
+
+		assert!(jurors.iter().any(|juror| !first_candidates.contains(juror)));
+	});
+}
+
+// ================================
+// STATE CONSISTENCY TESTS
+// ================================
+
+#[test]
+fn try_state_passes_for_consistent_registry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for account in 1..=3u64 {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+
+		assert_ok!(ProofOfFaceModule::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_hash_collision() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		// A second hash pointing at the same account, as a faulty migration might leave behind
+		BiometricHashToOwner::<Test>::insert(test_biometric_hash(2), 1);
+
+		assert!(ProofOfFaceModule::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn unique_hash_check_passes_for_consistent_registry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for account in 1..=3u64 {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+
+		assert_ok!(ProofOfFaceModule::ensure_unique_hashes());
+	});
+}
+
+#[test]
+fn unique_hash_check_fails_after_colliding_migration() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for account in 1..=2u64 {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+
+		// A migration that re-keys both identities onto the same hash
+		let collided = test_biometric_hash(9);
+		for account in 1..=2u64 {
+			let old = IdentityProofs::<Test>::mutate(account, |proof| {
+				let proof = proof.as_mut().unwrap();
+				std::mem::replace(&mut proof.biometric_hash, collided)
+			});
+			let owner = BiometricHashToOwner::<Test>::take(old).unwrap();
+			BiometricHashToOwner::<Test>::insert(BlakeTwo256::hash_of(&(collided, owner)), owner);
+		}
+
+		assert!(ProofOfFaceModule::ensure_unique_hashes().is_err());
+	});
+}
+
+#[test]
+fn unique_hash_check_fails_when_two_proofs_share_a_hash() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for account in 1..=2u64 {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+
+		// Both proofs carry the first hash, which the index maps to account 1 alone
+		IdentityProofs::<Test>::mutate(2, |proof| {
+			proof.as_mut().unwrap().biometric_hash = test_biometric_hash(1)
+		});
+		BiometricHashToOwner::<Test>::remove(test_biometric_hash(2));
+		assert_eq!(BiometricHashToOwner::<Test>::iter_keys().count(), 1);
+
+		assert!(ProofOfFaceModule::ensure_unique_hashes().is_err());
+	});
+}
+
+#[test]
+fn try_state_detects_proof_missing_from_reverse_index() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		BiometricHashToOwner::<Test>::remove(test_biometric_hash(1));
+
+		assert!(ProofOfFaceModule::do_try_state().is_err());
+	});
+}
+
+// ================================
+// VERIFICATION ALLOWANCE TESTS
+// ================================
+
+#[test]
+fn buy_verifications_charges_price_and_tops_up_allowance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(ProofOfFaceModule::buy_verifications(RuntimeOrigin::signed(1), 3));
+
+		assert_eq!(ProofOfFaceModule::verification_allowance(1), 3);
+		assert_eq!(Balances::free_balance(1), 1_000 - 3 * PricePerVerification::get());
+		System::assert_last_event(Event::VerificationsPurchased(last_seq(), 1, 3, 30).into());
+
+		// Purchases accumulate
+		assert_ok!(ProofOfFaceModule::buy_verifications(RuntimeOrigin::signed(1), 2));
+		assert_eq!(ProofOfFaceModule::verification_allowance(1), 5);
+	});
+}
+
+#[test]
+fn buy_verifications_fails_without_funds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert!(ProofOfFaceModule::buy_verifications(RuntimeOrigin::signed(3), 1).is_err());
+		assert_eq!(ProofOfFaceModule::verification_allowance(3), 0);
+	});
+}
+
+#[test]
+fn verify_identity_consumes_allowance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		FreeVerificationTier::set(&false);
+
+		assert_ok!(ProofOfFaceModule::buy_verifications(RuntimeOrigin::signed(1), 2));
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(1), test_biometric_hash(1)));
+
+		assert_eq!(ProofOfFaceModule::verification_allowance(1), 1);
+	});
+}
+
+#[test]
+fn verify_identity_fails_once_allowance_is_exhausted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		FreeVerificationTier::set(&false);
+
+		assert_ok!(ProofOfFaceModule::buy_verifications(RuntimeOrigin::signed(1), 1));
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(1), test_biometric_hash(1)));
+
+		assert_noop!(
+			ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(1), test_biometric_hash(1)),
+			Error::<Test>::NoVerificationAllowance
+		);
+	});
+}
+
+#[test]
+fn free_tier_verification_leaves_allowance_untouched() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(3), test_biometric_hash(1)));
+
+		assert_ok!(ProofOfFaceModule::buy_verifications(RuntimeOrigin::signed(1), 1));
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(1), test_biometric_hash(1)));
+		assert_eq!(ProofOfFaceModule::verification_allowance(1), 1);
+	});
+}
+
+// ================================
+// DISPUTE ACKNOWLEDGEMENT TESTS
+// ================================
+
+#[test]
+fn owner_can_acknowledge_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		run_to_block(3);
+
+		assert_ok!(ProofOfFaceModule::acknowledge_dispute(RuntimeOrigin::signed(1), dispute_id));
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.acknowledged_at, Some(3));
+		assert_eq!(dispute.status, DisputeStatus::Pending);
+		System::assert_last_event(Event::DisputeAcknowledged(last_seq(), dispute_id, 1).into());
+
+		assert_noop!(
+			ProofOfFaceModule::acknowledge_dispute(RuntimeOrigin::signed(1), dispute_id),
+			Error::<Test>::AlreadyAcknowledged
+		);
+	});
+}
+
+#[test]
+fn non_owner_cannot_acknowledge_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+
+		// Neither the dispute's creator nor a bystander may acknowledge it
+		for account in [2, 3] {
+			assert_noop!(
+				ProofOfFaceModule::acknowledge_dispute(RuntimeOrigin::signed(account), dispute_id),
+				Error::<Test>::NotAuthorized
+			);
+		}
+		assert_noop!(
+			ProofOfFaceModule::acknowledge_dispute(RuntimeOrigin::signed(1), dispute_id + 1),
+			Error::<Test>::DisputeNotFound
+		);
+	});
+}
+
+// ================================
+// VERIFICATION WEIGHT REFUND TESTS
+// ================================
+
+#[test]
+fn verify_identity_miss_refunds_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let declared = <() as WeightInfo>::verify_identity();
+		let post_info =
+			ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(1), test_biometric_hash(9)).unwrap();
+
+		let actual = post_info.actual_weight.expect("a miss reports its actual weight");
+		assert!(actual.all_lt(declared));
+	});
+}
+
+#[test]
+fn verify_identity_match_charges_declared_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		let post_info =
+			ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(2), biometric_hash).unwrap();
+		assert_eq!(post_info.actual_weight, None);
+	});
+}
+
+// ================================
+// VERIFIER ORIGIN TESTS
+// ================================
+
+#[test]
+fn verifier_origin_accepts_configured_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(7), test_biometric_hash(1)));
+		System::assert_last_event(
+			Event::VerificationPerformed(last_seq(), test_biometric_hash(1), false, 0).into(),
+		);
+	});
+}
+
+#[test]
+fn verifier_origin_rejects_other_origins() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for origin in [RuntimeOrigin::root(), RuntimeOrigin::none()] {
+			assert_noop!(
+				ProofOfFaceModule::verify_identity(origin, test_biometric_hash(1)),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		}
+	});
+}
+
+// ================================
+// MULTIPLE EVIDENCE TESTS
+// ================================
+
+#[test]
+fn creator_can_add_evidence_up_to_bound() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		for count in 2..=MaxEvidenceItems::get() {
+			assert_ok!(ProofOfFaceModule::add_evidence(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				test_evidence_url("ipfs://QmExtraEvidence")
+			));
+			System::assert_last_event(Event::EvidenceAdded(last_seq(), dispute_id, count).into());
+		}
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.evidence.len() as u32, MaxEvidenceItems::get());
+		// The original URL stays first and is still what `evidence_url` reports
+		assert_eq!(dispute.evidence_url(), Some(&test_evidence_url("https://evidence.example/1")));
+
+		assert_noop!(
+			ProofOfFaceModule::add_evidence(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				test_evidence_url("ipfs://QmOneTooMany")
+			),
+			Error::<Test>::TooManyEvidenceItems
+		);
+	});
+}
+
+#[test]
+fn add_evidence_requires_creator_and_pending_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+
+		assert_noop!(
+			ProofOfFaceModule::add_evidence(
+				RuntimeOrigin::signed(3),
+				dispute_id,
+				test_evidence_url("ipfs://QmExtraEvidence")
+			),
+			Error::<Test>::NotAuthorized
+		);
+
+		cast_votes(dispute_id, 7, 3);
+		assert_noop!(
+			ProofOfFaceModule::add_evidence(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				test_evidence_url("ipfs://QmExtraEvidence")
+			),
+			Error::<Test>::DisputeAlreadyResolved
+		);
+	});
+}
+
+#[test]
+fn update_evidence_replaces_only_first_item() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		let extra = test_evidence_url("ipfs://QmExtraEvidence");
+		assert_ok!(ProofOfFaceModule::add_evidence(RuntimeOrigin::signed(2), dispute_id, extra.clone()));
+
+		let replacement = test_evidence_url("https://evidence.example/fixed");
+		assert_ok!(ProofOfFaceModule::update_evidence(
+			RuntimeOrigin::signed(2),
+			dispute_id,
+			replacement.clone()
+		));
+
+		let evidence = ProofOfFaceModule::disputes(dispute_id).unwrap().evidence.into_inner();
+		assert_eq!(evidence, vec![replacement, extra]);
+	});
+}
+
+// ================================
+// VERIFICATION BEFORE DISPUTE TESTS
+// ================================
+
+/// Helper: register account 1 with hash 1 and turn on the verification requirement
+fn setup_verification_required() -> H256 {
+	RequireVerificationBeforeDispute::set(&true);
+	let biometric_hash = test_biometric_hash(1);
+	assert_ok!(ProofOfFaceModule::register_identity(
+		RuntimeOrigin::signed(1),
+		biometric_hash,
+		test_ipfs_cid("QmTestHash123456789abcdef")
+	));
+	biometric_hash
+}
+
+#[test]
+fn dispute_after_verification_succeeds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = setup_verification_required();
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(2), biometric_hash));
+		assert_eq!(ProofOfFaceModule::recent_verification(2, biometric_hash), Some(1));
+
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(2),
+			biometric_hash,
+			DisputeCategory::Impersonation,
+			test_evidence_url("https://evidence.example/1")
+		));
+		// The verification is used up by the dispute
+		assert_eq!(ProofOfFaceModule::recent_verification(2, biometric_hash), None);
+	});
+}
+
+#[test]
+fn dispute_without_verification_is_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = setup_verification_required();
+
+		// Another account's verification does not count
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(3), biometric_hash));
+
+		assert_noop!(
+			ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(2),
+				biometric_hash,
+				DisputeCategory::Impersonation,
+				test_evidence_url("https://evidence.example/1")
+			),
+			Error::<Test>::VerificationRequired
+		);
+	});
+}
+
+#[test]
+fn dispute_after_stale_verification_is_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = setup_verification_required();
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(2), biometric_hash));
+		run_to_block(2 + VerificationValidity::get());
+
+		assert_noop!(
+			ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(2),
+				biometric_hash,
+				DisputeCategory::Impersonation,
+				test_evidence_url("https://evidence.example/1")
+			),
+			Error::<Test>::VerificationRequired
+		);
+	});
+}
+
+#[test]
+fn verifications_are_not_recorded_when_mode_is_off() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(2), test_biometric_hash(1)));
+		assert_eq!(ProofOfFaceModule::recent_verification(2, test_biometric_hash(1)), None);
+	});
+}
+
+// ================================
+// DISPUTE LEAN TESTS
+// ================================
+
+#[test]
+fn dispute_lean_reports_leaning_for() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 4, 1);
+
+		assert_eq!(ProofOfFaceModule::dispute_lean(dispute_id), Some((3, true)));
+	});
+}
+
+#[test]
+fn dispute_lean_reports_leaning_against() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 1, 3);
+
+		assert_eq!(ProofOfFaceModule::dispute_lean(dispute_id), Some((-2, false)));
+	});
+}
+
+#[test]
+fn dispute_lean_applies_tie_rule() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 2, 2);
+		assert_eq!(ProofOfFaceModule::dispute_lean(dispute_id), Some((0, false)));
+
+		TieResolvesInFavor::set(&true);
+		assert_eq!(ProofOfFaceModule::dispute_lean(dispute_id), Some((0, true)));
+	});
+}
+
+#[test]
+fn dispute_lean_is_none_for_settled_or_unknown_disputes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 7, 3);
+
+		assert_eq!(ProofOfFaceModule::dispute_lean(dispute_id), None);
+		assert_eq!(ProofOfFaceModule::dispute_lean(dispute_id + 1), None);
+	});
+}
+
+// ================================
+// OPEN DISPUTES AGAINST TESTS
+// ================================
+
+#[test]
+fn open_disputes_count_rises_on_creation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 0);
+		let (biometric_hash, _) = setup_dispute();
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 1);
+
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Fraud,
+			test_evidence_url("https://evidence.example/2")
+		));
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 2);
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&2), 0);
+	});
+}
+
+#[test]
+fn open_disputes_count_falls_to_zero_after_resolution() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (biometric_hash, first_id) = setup_dispute();
+		let second_id = ProofOfFaceModule::next_dispute_id();
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Fraud,
+			test_evidence_url("https://evidence.example/2")
+		));
+
+		// One dispute rejected, the other resolved
+		cast_votes(first_id, 3, 7);
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 1);
+		cast_votes(second_id, 7, 3);
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 0);
+	});
+}
+
+// ================================
+// VOTE WEIGHT TESTS
+// ================================
+
+#[test]
+fn resolving_vote_reports_higher_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, DISPUTE_QUORUM - 2, 0);
+
+		let pending_vote =
+			ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(200), dispute_id, true).unwrap();
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Pending);
+
+		let resolving_vote =
+			ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(201), dispute_id, true).unwrap();
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Resolved);
+
+		let pending_weight = pending_vote.actual_weight.unwrap();
+		let resolving_weight = resolving_vote.actual_weight.unwrap();
+		assert_eq!(pending_weight, <() as WeightInfo>::vote_on_dispute_no_resolution());
+		assert_eq!(resolving_weight, <() as WeightInfo>::vote_on_dispute_resolving());
+		assert!(resolving_weight.any_gt(pending_weight));
+	});
+}
+
+// ================================
+// DISPUTE VOTERS TESTS
+// ================================
+
+#[test]
+fn dispute_voters_returns_all_votes_across_pages() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 3, 2);
+
+		let mut voters = Vec::new();
+		let mut start = None;
+		loop {
+			let page = ProofOfFaceModule::dispute_voters(dispute_id, start, 2);
+			assert!(page.len() <= 2);
+			match page.last() {
+				Some((last, _)) => start = Some(*last),
+				None => break,
+			}
+			voters.extend(page);
+		}
+
+		voters.sort();
+		assert_eq!(voters, vec![(100, true), (101, true), (102, true), (103, false), (104, false)]);
+	});
+}
+
+#[test]
+fn dispute_voters_only_lists_the_given_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (biometric_hash, first_id) = setup_dispute();
+		let second_id = ProofOfFaceModule::next_dispute_id();
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Other,
+			test_evidence_url("https://evidence.example/2")
+		));
+		cast_votes(first_id, 2, 0);
+
+		assert_eq!(ProofOfFaceModule::dispute_voters(first_id, None, 10).len(), 2);
+		assert!(ProofOfFaceModule::dispute_voters(second_id, None, 10).is_empty());
+	});
+}
+
+#[test]
+fn dispute_voters_are_listed_in_the_order_cast() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		for (voter, vote) in [(300, true), (7, false), (150, true)] {
+			assert_ok!(ProofOfFaceModule::vote_on_dispute(
+				RuntimeOrigin::signed(voter),
+				dispute_id,
+				vote
+			));
+		}
+
+		assert_eq!(
+			ProofOfFaceModule::dispute_voters(dispute_id, None, 10),
+			vec![(300, true), (7, false), (150, true)]
+		);
+		assert_eq!(
+			ProofOfFaceModule::dispute_voters(dispute_id, Some(300), 10),
+			vec![(7, false), (150, true)]
+		);
+	});
+}
+
+// ================================
+// EVIDENCE PRUNING TESTS
+// ================================
+
+/// Helper: open a dispute, attach a second evidence item and settle it
+fn resolve_dispute_with_extra_evidence() -> u64 {
+	let (_, dispute_id) = setup_dispute();
+	assert_ok!(ProofOfFaceModule::add_evidence(
+		RuntimeOrigin::signed(2),
+		dispute_id,
+		test_evidence_url("ipfs://QmExtraEvidence")
+	));
+	cast_votes(dispute_id, 7, 3);
+	dispute_id
+}
+
+#[test]
+fn evidence_is_pruned_after_resolution_when_enabled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		PruneEvidenceOnResolve::set(&true);
+
+		let dispute_id = resolve_dispute_with_extra_evidence();
+
+		let evidence = ProofOfFaceModule::disputes(dispute_id).unwrap().evidence.into_inner();
+		assert_eq!(evidence, vec![test_evidence_url("https://evidence.example/1")]);
+	});
+}
+
+#[test]
+fn evidence_is_pruned_after_expiry_when_enabled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		PruneEvidenceOnResolve::set(&true);
+		let (_, dispute_id) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::add_evidence(
+			RuntimeOrigin::signed(2),
+			dispute_id,
+			test_evidence_url("ipfs://QmExtraEvidence")
+		));
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.resolution_reason, Some(ResolutionReason::Expiry));
+		assert_eq!(
+			dispute.evidence.into_inner(),
+			vec![test_evidence_url("https://evidence.example/1")]
+		);
+	});
+}
+
+#[test]
+fn evidence_is_retained_after_resolution_when_disabled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let dispute_id = resolve_dispute_with_extra_evidence();
+
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().evidence.len(), 2);
+	});
+}
+
+// ================================
+// PROOF MISSING TESTS
+// ================================
+
+#[test]
+fn create_dispute_reports_dangling_reverse_map() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Reverse map entry whose owner has no stored proof
+		let dangling_hash = test_biometric_hash(5);
+		BiometricHashToOwner::<Test>::insert(dangling_hash, 5);
+
+		assert_noop!(
+			ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(2),
+				dangling_hash,
+				DisputeCategory::Fraud,
+				test_evidence_url("https://evidence.example/1")
+			),
+			Error::<Test>::ProofMissing
+		);
+		assert_noop!(
+			ProofOfFaceModule::create_dispute(
+				RuntimeOrigin::signed(2),
+				test_biometric_hash(6),
+				DisputeCategory::Fraud,
+				test_evidence_url("https://evidence.example/1")
+			),
+			Error::<Test>::IdentityNotFound
+		);
+	});
+}
+
+#[test]
+fn acknowledge_dispute_reports_dangling_reverse_map() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (biometric_hash, dispute_id) = setup_dispute();
+		IdentityProofs::<Test>::remove(1);
+
+		assert_noop!(
+			ProofOfFaceModule::acknowledge_dispute(RuntimeOrigin::signed(1), dispute_id),
+			Error::<Test>::ProofMissing
+		);
+		assert_eq!(ProofOfFaceModule::get_owner_by_hash(&biometric_hash), Some(1));
+	});
+}
+
+// ================================
+// MIGRATION CLAIM TESTS
+// ================================
+
+/// Helper: the migration authority's attestation for `who` registering `hash` with `cid`
+fn migration_attestation(
+	signer: u64,
+	who: u64,
+	biometric_hash: H256,
+	ipfs_cid: &BoundedVec<u8, frame_support::traits::ConstU32<100>>,
+) -> TestSignature {
+	TestSignature(signer, (MIGRATION_CONTEXT, who, biometric_hash, ipfs_cid).encode())
+}
+
+#[test]
+fn valid_attestation_registers_migrated_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let biometric_hash = test_biometric_hash(1);
+		let ipfs_cid = test_ipfs_cid("QmTestHash123456789abcdef");
+		let proof = migration_attestation(MigrationAuthority::get(), 1, biometric_hash, &ipfs_cid);
+
+		assert_ok!(ProofOfFaceModule::claim_migrated_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			ipfs_cid,
+			proof
+		));
+
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+		assert_eq!(ProofOfFaceModule::get_owner_by_hash(&biometric_hash), Some(1));
+		System::assert_last_event(Event::IdentityMigrated(last_seq(), 1, biometric_hash).into());
+	});
+}
+
+#[test]
+fn invalid_attestation_is_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let biometric_hash = test_biometric_hash(1);
+		let ipfs_cid = test_ipfs_cid("QmTestHash123456789abcdef");
+
+		// Signed by someone other than the migration authority
+		let forged = migration_attestation(98, 1, biometric_hash, &ipfs_cid);
+		assert_noop!(
+			ProofOfFaceModule::claim_migrated_identity(
+				RuntimeOrigin::signed(1),
+				biometric_hash,
+				ipfs_cid.clone(),
+				forged
+			),
+			Error::<Test>::InvalidMigrationProof
+		);
+
+		// Valid signature, but for a different account
+		let other_account =
+			migration_attestation(MigrationAuthority::get(), 2, biometric_hash, &ipfs_cid);
+		assert_noop!(
+			ProofOfFaceModule::claim_migrated_identity(
+				RuntimeOrigin::signed(1),
+				biometric_hash,
+				ipfs_cid,
+				other_account
+			),
+			Error::<Test>::InvalidMigrationProof
+		);
+	});
+}
+
+#[test]
+fn migrated_identity_follows_registration_rules() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(2),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		let ipfs_cid = test_ipfs_cid("QmTestHash123456789abcdef");
+		let proof = migration_attestation(MigrationAuthority::get(), 1, biometric_hash, &ipfs_cid);
+		assert_noop!(
+			ProofOfFaceModule::claim_migrated_identity(
+				RuntimeOrigin::signed(1),
+				biometric_hash,
+				ipfs_cid,
+				proof
+			),
+			Error::<Test>::InvalidBiometricHash
+		);
+	});
+}
+
+// ================================
+// REGISTRATION RATE LIMIT TESTS
+// ================================
+
+#[test]
+fn registration_limit_is_enforced_within_a_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxRegistrationsPerBlock::set(&2);
+
+		for account in 1..=2 {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+		assert_eq!(ProofOfFaceModule::registrations_this_block(), 2);
+
+		assert_eq!(
+			ProofOfFaceModule::can_register(&3, &test_biometric_hash(3)),
+			RegisterEligibility::CooldownActive
+		);
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(3),
+				test_biometric_hash(3),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			Error::<Test>::RegistrationRateLimited
+		);
+	});
+}
+
+#[test]
+fn registration_limit_resets_next_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxRegistrationsPerBlock::set(&1);
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(2),
+				test_biometric_hash(2),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			Error::<Test>::RegistrationRateLimited
+		);
+
+		run_to_block(2);
+		assert_eq!(ProofOfFaceModule::registrations_this_block(), 0);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(2),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+	});
+}
+
+#[test]
+fn zero_or_max_registration_limit_disables_throttle() {
+	for limit in [0, u32::MAX] {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			MaxRegistrationsPerBlock::set(&limit);
+
+			for account in 1..=5 {
+				assert_ok!(ProofOfFaceModule::register_identity(
+					RuntimeOrigin::signed(account),
+					test_biometric_hash(account as u8),
+					test_ipfs_cid("QmTestHash123456789abcdef")
+				));
+			}
+			assert_eq!(
+				ProofOfFaceModule::can_register(&6, &test_biometric_hash(6)),
+				RegisterEligibility::Eligible
+			);
+		});
+	}
+}
+
+// ================================
+// GATEWAY PREFERENCE TESTS
+// ================================
+
+/// Helper function to create a test gateway URL prefix
+fn test_gateway(url: &str) -> BoundedVec<u8, frame_support::traits::ConstU32<64>> {
+	BoundedVec::try_from(url.as_bytes().to_vec()).unwrap()
+}
+
+#[test]
+fn set_gateway_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_eq!(ProofOfFaceModule::identity_proofs(1).unwrap().gateway, None);
+
+		let gateway = test_gateway("https://ipfs.io/ipfs/");
+		assert_ok!(ProofOfFaceModule::set_gateway(RuntimeOrigin::signed(1), Some(gateway.clone())));
+
+		assert_eq!(ProofOfFaceModule::identity_proofs(1).unwrap().gateway, Some(gateway));
+		System::assert_last_event(Event::GatewayUpdated(last_seq(), 1).into());
+	});
+}
+
+#[test]
+fn set_gateway_none_clears_it() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_ok!(ProofOfFaceModule::set_gateway(
+			RuntimeOrigin::signed(1),
+			Some(test_gateway("http://localhost:8080/ipfs/"))
+		));
+
+		assert_ok!(ProofOfFaceModule::set_gateway(RuntimeOrigin::signed(1), None));
+
+		assert_eq!(ProofOfFaceModule::identity_proofs(1).unwrap().gateway, None);
+	});
+}
+
+#[test]
+fn set_gateway_validates_url_prefix() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		let invalid_gateways =
+			["ipfs.io/ipfs/", "ftp://ipfs.io/", "https://", "https:///ipfs/", "https://ipfs .io/", ""];
+		for invalid in invalid_gateways {
+			assert_noop!(
+				ProofOfFaceModule::set_gateway(RuntimeOrigin::signed(1), Some(test_gateway(invalid))),
+				Error::<Test>::InvalidGateway
+			);
+		}
+
+		// Only registered identities have a gateway to set
+		assert_noop!(
+			ProofOfFaceModule::set_gateway(
+				RuntimeOrigin::signed(2),
+				Some(test_gateway("https://ipfs.io/ipfs/"))
+			),
+			Error::<Test>::IdentityNotFound
+		);
+	});
+}
+
+// ================================
+// FAILED VERIFICATION FLAG TESTS
+// ================================
+
+#[test]
+fn failed_verifications_are_counted_per_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_eq!(ProofOfFaceModule::note_failed_verification(&1), 1);
+		assert_eq!(ProofOfFaceModule::note_failed_verification(&1), 2);
+		assert_eq!(ProofOfFaceModule::failed_verifications(1).0, 2);
+
+		// A new window starts the count over
+		System::set_block_number(1 + FailedVerificationWindow::get());
+		assert_eq!(ProofOfFaceModule::note_failed_verification(&1), 1);
+	});
+}
+
+#[test]
+fn exceeding_failed_verifications_flags_identity_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		for _ in 0..MaxFailedVerifications::get() {
+			ProofOfFaceModule::note_failed_verification(&1);
+		}
+		assert_eq!(count_unsequenced_events(Event::IdentityFlagged(0, 1).into()), 0);
+
+		ProofOfFaceModule::note_failed_verification(&1);
+		System::assert_last_event(Event::IdentityFlagged(last_seq(), 1).into());
+
+		// Further failures in the same window do not flag again
+		ProofOfFaceModule::note_failed_verification(&1);
+		assert_eq!(count_unsequenced_events(Event::IdentityFlagged(0, 1).into()), 1);
+
+		// Flagging is advisory by default
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+#[test]
+fn flagged_identity_is_deactivated_when_configured() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		DeactivateFlaggedIdentities::set(&true);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		for _ in 0..=MaxFailedVerifications::get() {
+			ProofOfFaceModule::note_failed_verification(&1);
+		}
+
+		System::assert_last_event(Event::IdentityFlagged(last_seq(), 1).into());
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+#[test]
+fn flagged_identity_stays_inactive_for_the_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		DeactivateFlaggedIdentities::set(&true);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		for _ in 0..=MaxFailedVerifications::get() {
+			ProofOfFaceModule::note_failed_verification(&1);
+		}
+		assert_eq!(ProofOfFaceModule::deactivated_by_flag(1), Some(1));
+
+		System::set_block_number(FailedVerificationWindow::get());
+		assert_noop!(
+			ProofOfFaceModule::reactivate_identity(RuntimeOrigin::signed(1)),
+			Error::<Test>::IdentityDeactivatedByFlag
+		);
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+
+		System::set_block_number(1 + FailedVerificationWindow::get());
+		assert_ok!(ProofOfFaceModule::reactivate_identity(RuntimeOrigin::signed(1)));
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+		assert_eq!(ProofOfFaceModule::deactivated_by_flag(1), None);
+	});
+}
+
+#[test]
+fn failed_verifications_are_counted_with_flagging_disabled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxFailedVerifications::set(&0);
+		DeactivateFlaggedIdentities::set(&true);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_eq!(ProofOfFaceModule::note_failed_verification(&1), 1);
+		assert_eq!(ProofOfFaceModule::note_failed_verification(&1), 2);
+
+		assert_eq!(ProofOfFaceModule::failed_verifications(1).0, 2);
+		assert_eq!(count_unsequenced_events(Event::IdentityFlagged(0, 1).into()), 0);
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+// ================================
+// RESOLUTION DELAY TESTS
+// ================================
+
+#[test]
+fn dispute_at_quorum_settles_after_resolution_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ResolutionDelay::set(&5);
+		let (_, dispute_id) = setup_dispute();
+
+		cast_votes(dispute_id, DISPUTE_QUORUM, 0);
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.status, DisputeStatus::ReadyToResolve);
+		assert_eq!(dispute.ready_at, Some(1));
+		assert_has_unsequenced_event(Event::DisputeReadyToResolve(0, dispute_id, 6).into());
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+
+		run_to_block(5);
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::ReadyToResolve
+		);
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+
+		run_to_block(6);
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Resolved);
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 0);
+		assert_has_unsequenced_event(
+			Event::DisputeResolved(
+				0,
+				dispute_id,
+				DisputeStatus::Resolved,
+				DISPUTE_QUORUM,
+				0,
+				ResolutionReason::Vote,
+			)
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn votes_during_resolution_delay_can_flip_outcome() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ResolutionDelay::set(&5);
+		let (_, dispute_id) = setup_dispute();
+
+		cast_votes(dispute_id, 6, 4);
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::ReadyToResolve
+		);
+
+		// Late voters swing the tally before the delay ends
+		for voter in 200..203 {
+			assert_ok!(ProofOfFaceModule::vote_on_dispute(
+				RuntimeOrigin::signed(voter),
+				dispute_id,
+				false
+			));
+		}
+
+		run_to_block(6);
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Rejected);
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+		assert_has_unsequenced_event(
+			Event::DisputeResolved(
+				0,
+				dispute_id,
+				DisputeStatus::Rejected,
+				6,
+				7,
+				ResolutionReason::Vote,
+			)
+			.into(),
+		);
+
+		// Settled disputes no longer take votes
+		assert_noop!(
+			ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(300), dispute_id, true),
+			Error::<Test>::DisputeAlreadyResolved
+		);
+	});
+}
+
+// ================================
+// ACCOUNT LINKING TESTS
+// ================================
+
+/// Helper: `secondary`'s consent to being linked to `primary`'s identity at the pair's
+/// current nonce
+fn link_consent(signer: u64, primary: u64, secondary: u64) -> TestSignature {
+	let nonce = ProofOfFaceModule::link_nonce(primary, secondary);
+	TestSignature(signer, (LINK_CONTEXT, primary, secondary, nonce).encode())
+}
+
+/// Helper: register account 1 and link account 5 to it
+fn setup_link() {
+	assert_ok!(ProofOfFaceModule::register_identity(
+		RuntimeOrigin::signed(1),
+		test_biometric_hash(1),
+		test_ipfs_cid("QmTestHash123456789abcdef")
+	));
+	assert_ok!(ProofOfFaceModule::link_account(RuntimeOrigin::signed(1), 5, link_consent(5, 1, 5)));
+}
+
+#[test]
+fn link_account_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_link();
+
+		assert!(crate::LinkedAccounts::<Test>::contains_key(1, 5));
+		assert_eq!(ProofOfFaceModule::linked_to_primary(5), Some(1));
+		System::assert_last_event(Event::AccountLinked(last_seq(), 1, 5).into());
+	});
+}
+
+#[test]
+fn linked_account_reports_primary_status() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_link();
+
+		assert!(ProofOfFaceModule::is_identity_active(&5));
+
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(1), false));
+		assert!(!ProofOfFaceModule::is_identity_active(&5));
+	});
+}
+
+#[test]
+fn link_account_rejects_self_link() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_noop!(
+			ProofOfFaceModule::link_account(RuntimeOrigin::signed(1), 1, link_consent(1, 1, 1)),
+			Error::<Test>::CannotLinkSelf
+		);
+		assert_eq!(ProofOfFaceModule::linked_to_primary(1), None);
+	});
+}
+
+#[test]
+fn links_cannot_chain_into_a_cycle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_link();
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(2),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		// A primary cannot become someone else's secondary
+		assert_noop!(
+			ProofOfFaceModule::link_account(RuntimeOrigin::signed(2), 1, link_consent(1, 2, 1)),
+			Error::<Test>::IdentityAlreadyExists
+		);
+		// A secondary cannot link accounts of its own or register an identity
+		assert_noop!(
+			ProofOfFaceModule::link_account(RuntimeOrigin::signed(5), 1, link_consent(1, 5, 1)),
+			Error::<Test>::IdentityNotFound
+		);
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(5),
+				test_biometric_hash(5),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			Error::<Test>::AccountAlreadyLinked
+		);
+		assert_eq!(ProofOfFaceModule::linked_to_primary(5), Some(1));
+		assert_eq!(ProofOfFaceModule::linked_to_primary(1), None);
+	});
+}
+
+#[test]
+fn link_account_requires_secondary_consent() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		// Signed by the primary instead of the secondary
+		assert_noop!(
+			ProofOfFaceModule::link_account(RuntimeOrigin::signed(1), 5, link_consent(1, 1, 5)),
+			Error::<Test>::InvalidLinkProof
+		);
+		// Consent given to a different primary
+		assert_noop!(
+			ProofOfFaceModule::link_account(RuntimeOrigin::signed(1), 5, link_consent(5, 3, 5)),
+			Error::<Test>::InvalidLinkProof
+		);
+		// Only identity owners can link accounts
+		assert_noop!(
+			ProofOfFaceModule::link_account(RuntimeOrigin::signed(3), 5, link_consent(5, 3, 5)),
+			Error::<Test>::IdentityNotFound
+		);
+	});
+}
+
+#[test]
+fn link_consent_cannot_be_replayed_after_unlink() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		let consent = link_consent(5, 1, 5);
+		assert_ok!(ProofOfFaceModule::link_account(RuntimeOrigin::signed(1), 5, consent.clone()));
+		assert_eq!(ProofOfFaceModule::link_nonce(1, 5), 1);
+		assert_ok!(ProofOfFaceModule::unlink_account(RuntimeOrigin::signed(5), 1));
+
+		assert_noop!(
+			ProofOfFaceModule::link_account(RuntimeOrigin::signed(1), 5, consent),
+			Error::<Test>::InvalidLinkProof
+		);
+		assert_ok!(ProofOfFaceModule::link_account(
+			RuntimeOrigin::signed(1),
+			5,
+			link_consent(5, 1, 5)
+		));
+	});
+}
+
+#[test]
+fn secondary_can_unlink_itself() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_link();
+
+		assert_ok!(ProofOfFaceModule::unlink_account(RuntimeOrigin::signed(5), 1));
+
+		assert!(!crate::LinkedAccounts::<Test>::contains_key(1, 5));
+		assert_eq!(ProofOfFaceModule::linked_to_primary(5), None);
+		System::assert_last_event(Event::AccountUnlinked(last_seq(), 1, 5).into());
+	});
+}
+
+#[test]
+fn unlink_account_removes_relationship() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_link();
+
+		assert_ok!(ProofOfFaceModule::unlink_account(RuntimeOrigin::signed(1), 5));
+
+		assert!(!crate::LinkedAccounts::<Test>::contains_key(1, 5));
+		assert_eq!(ProofOfFaceModule::linked_to_primary(5), None);
+		assert!(!ProofOfFaceModule::is_identity_active(&5));
+		System::assert_last_event(Event::AccountUnlinked(last_seq(), 1, 5).into());
+	});
+}
+
+#[test]
+fn linked_accounts_are_enumerated_and_bounded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		let secondaries = 5..5 + MaxLinkedAccounts::get() as u64;
+		for secondary in secondaries.clone() {
+			assert_ok!(ProofOfFaceModule::link_account(
+				RuntimeOrigin::signed(1),
+				secondary,
+				link_consent(secondary, 1, secondary)
+			));
+		}
+
+		let mut linked = ProofOfFaceModule::linked_accounts(&1);
+		linked.sort();
+		assert_eq!(linked, secondaries.collect::<Vec<_>>());
+
+		assert_noop!(
+			ProofOfFaceModule::link_account(RuntimeOrigin::signed(1), 20, link_consent(20, 1, 20)),
+			Error::<Test>::TooManyLinkedAccounts
+		);
+
+		// Unlinking one frees a slot and leaves the others in place
+		assert_ok!(ProofOfFaceModule::unlink_account(RuntimeOrigin::signed(1), 6));
+		let mut linked = ProofOfFaceModule::linked_accounts(&1);
+		linked.sort();
+		assert_eq!(linked, vec![5, 7]);
+		assert_ok!(ProofOfFaceModule::link_account(
+			RuntimeOrigin::signed(1),
+			20,
+			link_consent(20, 1, 20)
+		));
+	});
+}
+
+#[test]
+fn unlink_account_fails_for_unlinked_account() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_link();
+
+		// Never linked
+		assert_noop!(
+			ProofOfFaceModule::unlink_account(RuntimeOrigin::signed(1), 6),
+			Error::<Test>::NotAuthorized
+		);
+		// Linked, but to someone else's identity
+		assert_noop!(
+			ProofOfFaceModule::unlink_account(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			ProofOfFaceModule::unlink_account(RuntimeOrigin::signed(5), 2),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+// ================================
+// REPUTATION THRESHOLD TESTS
+// ================================
+
+#[test]
+fn reputation_crossing_below_threshold_fires_once() {
+	new_test_ext().execute_with(|| {
+		let threshold = ReputationThreshold::get();
+
+		// Climbing while still below the threshold is not a crossing
+		ProofOfFaceModule::increase_reputation(&1, threshold - 1);
+		assert!(ReputationCrossings::get().is_empty());
+
+		ProofOfFaceModule::increase_reputation(&1, 51);
+		assert_eq!(ReputationCrossings::get(), vec![(1, threshold + 50, true)]);
+
+		ProofOfFaceModule::decrease_reputation(&1, 60);
+		assert_eq!(
+			ReputationCrossings::get(),
+			vec![(1, threshold + 50, true), (1, threshold - 10, false)]
+		);
+
+		// Staying below does not fire again
+		ProofOfFaceModule::decrease_reputation(&1, 20);
+		ProofOfFaceModule::increase_reputation(&1, 5);
+		assert_eq!(ReputationCrossings::get().len(), 2);
+	});
+}
+
+// ================================
+// HANDLE TESTS
+// ================================
+
+/// Helper function to create a test handle
+fn test_handle(handle: &str) -> BoundedVec<u8, frame_support::traits::ConstU32<32>> {
+	BoundedVec::try_from(handle.as_bytes().to_vec()).unwrap()
+}
+
+/// Helper: register identities for accounts 1 and 2
+fn register_two_identities() {
+	for account in 1..=2 {
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(account),
+			test_biometric_hash(account as u8),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+	}
+}
+
+#[test]
+fn set_handle_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_two_identities();
+
+		assert_ok!(ProofOfFaceModule::set_handle(RuntimeOrigin::signed(1), test_handle("alice")));
+
+		assert_eq!(ProofOfFaceModule::handle_of(&1), Some(test_handle("alice")));
+		assert_eq!(ProofOfFaceModule::account_of_handle(&test_handle("alice")), Some(1));
+		System::assert_last_event(Event::HandleSet(last_seq(), 1, test_handle("alice")).into());
+	});
+}
+
+#[test]
+fn set_handle_rejects_taken_handle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_two_identities();
+		assert_ok!(ProofOfFaceModule::set_handle(RuntimeOrigin::signed(1), test_handle("alice")));
+
+		assert_noop!(
+			ProofOfFaceModule::set_handle(RuntimeOrigin::signed(2), test_handle("alice")),
+			Error::<Test>::HandleTaken
+		);
+
+		// Handles must be printable, and only identity owners can hold one
+		assert_noop!(
+			ProofOfFaceModule::set_handle(RuntimeOrigin::signed(2), test_handle("al ice")),
+			Error::<Test>::InvalidHandle
+		);
+		assert_noop!(
+			ProofOfFaceModule::set_handle(RuntimeOrigin::signed(3), test_handle("carol")),
+			Error::<Test>::IdentityNotFound
+		);
+	});
+}
+
+#[test]
+fn updating_handle_frees_the_old_one() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_two_identities();
+		assert_ok!(ProofOfFaceModule::set_handle(RuntimeOrigin::signed(1), test_handle("alice")));
+
+		assert_ok!(ProofOfFaceModule::set_handle(RuntimeOrigin::signed(1), test_handle("alice2")));
+
+		assert_eq!(ProofOfFaceModule::handle_of(&1), Some(test_handle("alice2")));
+		assert_eq!(ProofOfFaceModule::account_of_handle(&test_handle("alice")), None);
+		assert_ok!(ProofOfFaceModule::set_handle(RuntimeOrigin::signed(2), test_handle("alice")));
+		assert_eq!(ProofOfFaceModule::account_of_handle(&test_handle("alice")), Some(2));
+	});
+}
+
+#[test]
+fn verify_with_handle_returns_owner_handle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_two_identities();
+		assert_ok!(ProofOfFaceModule::set_handle(RuntimeOrigin::signed(1), test_handle("alice")));
+		System::reset_events();
+
+		assert_eq!(
+			ProofOfFaceModule::verify_with_handle(&test_biometric_hash(1)),
+			Some((1, Some(test_handle("alice"))))
+		);
+		// Matched, but the owner never set a handle
+		assert_eq!(ProofOfFaceModule::verify_with_handle(&test_biometric_hash(2)), Some((2, None)));
+		// No match at all
+		assert_eq!(ProofOfFaceModule::verify_with_handle(&test_biometric_hash(3)), None);
+		// The on-chain audit trail is untouched
+		assert!(System::events().is_empty());
+	});
+}
+
+// ================================
+// REGISTRATION DEPOSIT TESTS
+// ================================
+
+#[test]
+fn registration_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RegistrationDeposit::set(&100);
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_eq!(Balances::reserved_balance(1), 100);
+		assert_eq!(Balances::free_balance(1), 900);
+		assert_eq!(ProofOfFaceModule::registration_deposit(1), Some(100));
+	});
+}
+
+#[test]
+fn registration_fails_without_balance_for_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RegistrationDeposit::set(&100);
+
+		// Account 3 has no funds at all
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(3),
+				test_biometric_hash(1),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn purge_releases_deposit_and_frees_hash() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RegistrationDeposit::set(&100);
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(1), true));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(ProofOfFaceModule::registration_deposit(1), None);
+		assert!(!IdentityProofs::<Test>::contains_key(1));
+		assert!(!BiometricHashToOwner::<Test>::contains_key(biometric_hash));
+		System::assert_last_event(Event::IdentityPurged(last_seq(), 1, biometric_hash).into());
+
+		// The hash is free to be registered again
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(2),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+	});
+}
+
+#[test]
+fn purge_fails_while_disputed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_dispute();
+
+		assert_noop!(
+			ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(1), true),
+			Error::<Test>::IdentityUnderDispute
+		);
+	});
+}
+
+// ================================
+// IDENTITY MILESTONE TESTS
+// ================================
+
+#[test]
+fn milestone_emitted_once_when_crossed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MilestoneInterval::set(&2);
+
+		register_two_identities();
+
+		assert_eq!(ProofOfFaceModule::total_identities(), 2);
+		assert_eq!(ProofOfFaceModule::last_milestone(), 2);
+		System::assert_last_event(Event::IdentityMilestone(last_seq(), 2).into());
+
+		// The next registration stays below the next milestone
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(3),
+			test_biometric_hash(3),
+			test_ipfs_cid("QmTestHash3")
+		));
+		assert_eq!(ProofOfFaceModule::total_identities(), 3);
+		assert_eq!(count_unsequenced_events(Event::IdentityMilestone(0, 2).into()), 1);
+		System::assert_last_event(
+			Event::IdentityRegistered(last_seq(), 3, test_biometric_hash(3)).into(),
+		);
+	});
+}
+
+#[test]
+fn milestone_not_repeated_after_purge() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MilestoneInterval::set(&2);
+		register_two_identities();
+
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(2), true));
+		assert_eq!(ProofOfFaceModule::total_identities(), 1);
+		System::reset_events();
+
+		// Climbing back to 2 does not announce the milestone again
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(3),
+			test_biometric_hash(3),
+			test_ipfs_cid("QmTestHash3")
+		));
+		assert_eq!(ProofOfFaceModule::total_identities(), 2);
+		System::assert_last_event(
+			Event::IdentityRegistered(last_seq(), 3, test_biometric_hash(3)).into(),
+		);
+	});
+}
+
+// ================================
+// SPOOF REPORT TESTS
+// ================================
+
+#[test]
+fn report_spoof_records_failure_and_opens_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		let dispute_id = ProofOfFaceModule::next_dispute_id();
+
+		assert_ok!(ProofOfFaceModule::report_spoof(
+			RuntimeOrigin::signed(2),
+			biometric_hash,
+			test_evidence_url("https://evidence.example/spoof")
+		));
+
+		// The failed match is attested against the owner
+		assert_eq!(ProofOfFaceModule::failed_verifications(1).0, 1);
+
+		// And a pending dispute is open against the identity
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.status, DisputeStatus::Pending);
+		assert_eq!(dispute.creator, 2);
+		assert_eq!(dispute.face_proof_id, biometric_hash);
+		assert_eq!(dispute.category, DisputeCategory::Impersonation);
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 1);
+
+		assert_has_unsequenced_event(
+			Event::DisputeCreated(0, dispute_id, 2, DisputeCategory::Impersonation).into(),
+		);
+		System::assert_last_event(
+			Event::SpoofReported(last_seq(), 2, biometric_hash, dispute_id).into(),
+		);
+	});
+}
+
+#[test]
+fn report_spoof_enforces_dispute_checks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		// Unknown hash
+		assert_noop!(
+			ProofOfFaceModule::report_spoof(
+				RuntimeOrigin::signed(2),
+				test_biometric_hash(99),
+				test_evidence_url("https://evidence.example/spoof")
+			),
+			Error::<Test>::IdentityNotFound
+		);
+
+		// Too new to dispute; nothing is attested either
+		MinDisputeAge::set(&5);
+		assert_noop!(
+			ProofOfFaceModule::report_spoof(
+				RuntimeOrigin::signed(2),
+				biometric_hash,
+				test_evidence_url("https://evidence.example/spoof")
+			),
+			Error::<Test>::IdentityTooNew
+		);
+		assert_eq!(ProofOfFaceModule::failed_verifications(1).0, 0);
+	});
+}
+
+// ================================
+// DISPUTE COMMENT TESTS
+// ================================
+
+/// Helper function to create a test dispute comment
+fn test_comment(text: &str) -> BoundedVec<u8, frame_support::traits::ConstU32<64>> {
+	BoundedVec::try_from(text.as_bytes().to_vec()).unwrap()
+}
+
+#[test]
+fn participants_can_comment_on_open_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 1, 0);
+
+		// Creator, disputed owner and voter
+		assert_ok!(ProofOfFaceModule::add_comment(
+			RuntimeOrigin::signed(2),
+			dispute_id,
+			test_comment("photo is a print")
+		));
+		assert_ok!(ProofOfFaceModule::add_comment(
+			RuntimeOrigin::signed(1),
+			dispute_id,
+			test_comment("that is me")
+		));
+		assert_ok!(ProofOfFaceModule::add_comment(
+			RuntimeOrigin::signed(100),
+			dispute_id,
+			test_comment("moire visible")
+		));
+
+		assert_eq!(ProofOfFaceModule::dispute_comment_count(dispute_id), 3);
+		assert_eq!(
+			ProofOfFaceModule::dispute_comment(dispute_id, 1),
+			Some((1, test_comment("that is me")))
+		);
+		System::assert_last_event(Event::CommentAdded(last_seq(), dispute_id, 100, 2).into());
+	});
+}
+
+#[test]
+fn add_comment_rejects_outsiders_and_empty_text() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		assert_noop!(
+			ProofOfFaceModule::add_comment(
+				RuntimeOrigin::signed(50),
+				dispute_id,
+				test_comment("drive-by")
+			),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			ProofOfFaceModule::add_comment(RuntimeOrigin::signed(2), dispute_id, test_comment("")),
+			Error::<Test>::InvalidComment
+		);
+		assert_noop!(
+			ProofOfFaceModule::add_comment(RuntimeOrigin::signed(2), 99, test_comment("hello")),
+			Error::<Test>::DisputeNotFound
+		);
+	});
+}
+
+#[test]
+fn add_comment_enforces_per_dispute_cap() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		for _ in 0..MaxCommentsPerDispute::get() {
+			assert_ok!(ProofOfFaceModule::add_comment(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				test_comment("more context")
+			));
+		}
+
+		assert_noop!(
+			ProofOfFaceModule::add_comment(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				test_comment("one too many")
+			),
+			Error::<Test>::TooManyComments
+		);
+	});
+}
+
+#[test]
+fn dispute_comments_are_paginated_in_order() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		for text in ["first", "second", "third"] {
+			assert_ok!(ProofOfFaceModule::add_comment(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				test_comment(text)
+			));
+		}
+
+		assert_eq!(
+			ProofOfFaceModule::dispute_comments(dispute_id, 0, 2),
+			vec![(0, 2, test_comment("first")), (1, 2, test_comment("second"))]
+		);
+		assert_eq!(
+			ProofOfFaceModule::dispute_comments(dispute_id, 2, 2),
+			vec![(2, 2, test_comment("third"))]
+		);
+		assert!(ProofOfFaceModule::dispute_comments(dispute_id, 3, 2).is_empty());
+	});
+}
+
+// ================================
+// APPEAL REACTIVATION TESTS
+// ================================
+
+#[test]
+fn overturned_dispute_reactivates_identity_it_deactivated() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 10, 0);
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+		assert_eq!(ProofOfFaceModule::deactivated_by_dispute(1), Some(dispute_id));
+
+		assert_eq!(ProofOfFaceModule::overturn_dispute(dispute_id), Ok(true));
+
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+		assert_eq!(ProofOfFaceModule::deactivated_by_dispute(1), None);
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::Rejected
+		);
+		System::assert_last_event(
+			Event::IdentityReactivatedByAppeal(last_seq(), 1, dispute_id).into(),
+		);
+	});
+}
+
+#[test]
+fn overturned_dispute_leaves_identity_deactivated_by_others() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (biometric_hash, first_id) = setup_dispute();
+		let second_id = ProofOfFaceModule::next_dispute_id();
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Fraud,
+			test_evidence_url("https://evidence.example/2")
+		));
+		cast_votes(first_id, 10, 0);
+		// The identity is already inactive when the second dispute resolves
+		cast_votes(second_id, 10, 0);
+
+		assert_eq!(ProofOfFaceModule::overturn_dispute(second_id), Ok(false));
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+
+		// Nor does an owner's own deactivation get undone
+		assert_ok!(ProofOfFaceModule::reactivate_identity(RuntimeOrigin::signed(1)));
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(1), false));
+		assert_eq!(ProofOfFaceModule::overturn_dispute(first_id), Ok(false));
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+#[test]
+fn only_resolved_disputes_can_be_overturned() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		assert_noop!(ProofOfFaceModule::overturn_dispute(dispute_id), Error::<Test>::NotAuthorized);
+		assert_noop!(ProofOfFaceModule::overturn_dispute(99), Error::<Test>::DisputeNotFound);
+	});
+}
+
+#[test]
+fn resolve_appeal_reactivates_identity_deactivated_by_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 10, 0);
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+
+		assert_ok!(ProofOfFaceModule::resolve_appeal(RuntimeOrigin::root(), dispute_id));
+
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().resolution_reason,
+			Some(ResolutionReason::Appeal)
+		);
+		assert_has_unsequenced_event(Event::IdentityReactivatedByAppeal(0, 1, dispute_id).into());
+		System::assert_last_event(Event::DisputeOverturned(last_seq(), dispute_id).into());
+
+		// The overturned dispute cannot be appealed again
+		assert_noop!(
+			ProofOfFaceModule::resolve_appeal(RuntimeOrigin::root(), dispute_id),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn resolve_appeal_requires_force_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 10, 0);
+
+		assert_noop!(
+			ProofOfFaceModule::resolve_appeal(RuntimeOrigin::signed(1), dispute_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+// ================================
+// IDENTITY MERGE TESTS
+// ================================
+
+#[test]
+fn merge_identities_moves_hash_to_kept_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_two_identities();
+		let dropped_hash = test_biometric_hash(2);
+
+		assert_ok!(ProofOfFaceModule::merge_identities(RuntimeOrigin::root(), 1, 2));
+
+		assert!(!IdentityProofs::<Test>::contains_key(2));
+		assert_eq!(ProofOfFaceModule::get_owner_by_hash(&dropped_hash), Some(1));
+		assert_eq!(ProofOfFaceModule::get_owner_by_hash(&test_biometric_hash(1)), Some(1));
+		assert!(ProofOfFaceModule::hash_is_active(&dropped_hash));
+		assert_eq!(ProofOfFaceModule::total_identities(), 1);
+		assert_ok!(ProofOfFaceModule::do_try_state());
+		System::assert_last_event(Event::IdentitiesMerged(last_seq(), 1, 2).into());
+
+		// Purging the kept identity releases the merged hash too
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(1), true));
+		assert_eq!(ProofOfFaceModule::get_owner_by_hash(&dropped_hash), None);
+		assert_has_unsequenced_event(Event::MergedHashReleased(0, 1, dropped_hash).into());
+		assert_ok!(ProofOfFaceModule::do_try_state());
+	});
+}
+
+#[test]
+fn merge_identities_requires_both_identities() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_two_identities();
+
+		assert_noop!(
+			ProofOfFaceModule::merge_identities(RuntimeOrigin::root(), 1, 3),
+			Error::<Test>::IdentityNotFound
+		);
+		assert_noop!(
+			ProofOfFaceModule::merge_identities(RuntimeOrigin::root(), 3, 1),
+			Error::<Test>::IdentityNotFound
+		);
+		assert_noop!(
+			ProofOfFaceModule::merge_identities(RuntimeOrigin::root(), 1, 1),
+			Error::<Test>::CannotMergeSelf
+		);
+		assert_noop!(
+			ProofOfFaceModule::merge_identities(RuntimeOrigin::signed(1), 1, 2),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+// ================================
+// EXPIRED DISPUTE SWEEP TESTS
+// ================================
+
+#[test]
+fn sweep_rejects_expired_disputes_and_skips_fresh_ones() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (biometric_hash, expired_id) = setup_dispute();
+		cast_votes(expired_id, 3, 0);
+
+		System::set_block_number(10);
+		let fresh_id = ProofOfFaceModule::next_dispute_id();
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Other,
+			test_evidence_url("https://evidence.example/2")
+		));
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+
+		assert_eq!(
+			ProofOfFaceModule::disputes(expired_id).unwrap().status,
+			DisputeStatus::Rejected
+		);
+		assert_eq!(ProofOfFaceModule::disputes(fresh_id).unwrap().status, DisputeStatus::Pending);
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 1);
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+		assert_has_unsequenced_event(Event::DisputeExpired(0, expired_id).into());
+
+		// The fresh dispute is swept once it expires in turn
+		System::set_block_number(10 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+		assert_eq!(ProofOfFaceModule::disputes(fresh_id).unwrap().status, DisputeStatus::Rejected);
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 0);
+		assert_eq!(ProofOfFaceModule::sweep_cursor(), fresh_id + 1);
+	});
+}
+
+#[test]
+fn sweep_is_bounded_by_max() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (biometric_hash, first_id) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Other,
+			test_evidence_url("https://evidence.example/2")
+		));
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 1));
+
+		assert_eq!(ProofOfFaceModule::disputes(first_id).unwrap().status, DisputeStatus::Rejected);
+		assert_eq!(
+			ProofOfFaceModule::disputes(first_id + 1).unwrap().status,
+			DisputeStatus::Pending
+		);
+	});
+}
+
+#[test]
+fn sweep_pays_reward_per_closed_dispute() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let pot = ProofOfFaceModule::account_id();
+		Balances::make_free_balance_be(&pot, 100);
+		let (biometric_hash, _) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Other,
+			test_evidence_url("https://evidence.example/2")
+		));
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+
+		assert_eq!(Balances::free_balance(50), 2 * SweepReward::get());
+		assert_eq!(Balances::free_balance(pot), 100 - 2 * SweepReward::get());
+	});
+}
+
+#[test]
+fn sweep_pays_what_the_pallet_account_can_afford() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let pot = ProofOfFaceModule::account_id();
+		// One short of two rewards once the existential deposit is kept
+		let balance = 2 * SweepReward::get();
+		Balances::make_free_balance_be(&pot, balance);
+		let (biometric_hash, _) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(3),
+			biometric_hash,
+			DisputeCategory::Other,
+			test_evidence_url("https://evidence.example/2")
+		));
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+
+		// The mock's existential deposit is 1
+		assert_eq!(Balances::free_balance(50), balance - 1);
+		assert_eq!(Balances::free_balance(pot), 1);
+	});
+}
+
+// ================================
+// GATEWAY ALLOWLIST TESTS
+// ================================
+
+#[test]
+fn allowlisted_gateway_can_be_set() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		EnforceGatewayAllowlist::set(&true);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_noop!(
+			ProofOfFaceModule::add_allowed_gateway(RuntimeOrigin::signed(1), test_gateway("ipfs.io")),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ProofOfFaceModule::add_allowed_gateway(
+			RuntimeOrigin::root(),
+			test_gateway("ipfs.io")
+		));
+		System::assert_last_event(
+			Event::GatewayAllowed(last_seq(), test_gateway("ipfs.io")).into(),
+		);
+
+		let gateway = test_gateway("https://ipfs.io/ipfs/");
+		assert_ok!(ProofOfFaceModule::set_gateway(RuntimeOrigin::signed(1), Some(gateway.clone())));
+		assert_eq!(ProofOfFaceModule::identity_proofs(1).unwrap().gateway, Some(gateway));
+	});
+}
+
+#[test]
+fn gateway_outside_allowlist_is_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		EnforceGatewayAllowlist::set(&true);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_ok!(ProofOfFaceModule::add_allowed_gateway(
+			RuntimeOrigin::root(),
+			test_gateway("ipfs.io")
+		));
+
+		for gateway in ["https://evil.example/ipfs/", "http://ipfs.io.evil.example/"] {
+			assert_noop!(
+				ProofOfFaceModule::set_gateway(RuntimeOrigin::signed(1), Some(test_gateway(gateway))),
+				Error::<Test>::GatewayNotAllowed
+			);
+		}
+
+		// Removing a host closes it to identities that do not use it yet
+		assert_ok!(ProofOfFaceModule::remove_allowed_gateway(
+			RuntimeOrigin::root(),
+			test_gateway("ipfs.io")
+		));
+		System::assert_last_event(
+			Event::GatewayDisallowed(last_seq(), test_gateway("ipfs.io")).into(),
+		);
+		assert_noop!(
+			ProofOfFaceModule::set_gateway(
+				RuntimeOrigin::signed(1),
+				Some(test_gateway("https://ipfs.io/ipfs/"))
+			),
+			Error::<Test>::GatewayNotAllowed
+		);
+		assert_noop!(
+			ProofOfFaceModule::remove_allowed_gateway(RuntimeOrigin::root(), test_gateway("ipfs.io")),
+			Error::<Test>::GatewayNotAllowed
+		);
+	});
+}
+
+#[test]
+fn add_allowed_gateway_requires_bare_host() {
+	new_test_ext().execute_with(|| {
+		for invalid in ["", "https://ipfs.io", "ipfs.io/ipfs/", "localhost:8080"] {
+			assert_noop!(
+				ProofOfFaceModule::add_allowed_gateway(RuntimeOrigin::root(), test_gateway(invalid)),
+				Error::<Test>::InvalidGateway
+			);
+		}
+	});
+}
+
+// ================================
+// DISPUTE REASSIGNMENT TESTS
+// ================================
+
+/// Helper: `new_creator`'s consent to take over `dispute_id` from `creator`
+fn reassign_consent(signer: u64, dispute_id: u64, creator: u64, new_creator: u64) -> TestSignature {
+	TestSignature(signer, (REASSIGN_CONTEXT, dispute_id, creator, new_creator).encode())
+}
+
+#[test]
+fn reassign_dispute_moves_it_to_new_creator() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		assert_ok!(ProofOfFaceModule::reassign_dispute(
+			RuntimeOrigin::signed(2),
+			dispute_id,
+			3,
+			reassign_consent(3, dispute_id, 2, 3)
+		));
+
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().creator, 3);
+		assert!(ProofOfFaceModule::disputes_created_by(&2).is_empty());
+		assert_eq!(ProofOfFaceModule::disputes_created_by(&3), vec![dispute_id]);
+		System::assert_last_event(Event::DisputeReassigned(last_seq(), dispute_id, 2, 3).into());
+	});
+}
+
+#[test]
+fn reassign_dispute_rejects_non_creator_and_missing_consent() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		assert_noop!(
+			ProofOfFaceModule::reassign_dispute(
+				RuntimeOrigin::signed(3),
+				dispute_id,
+				3,
+				reassign_consent(3, dispute_id, 3, 3)
+			),
+			Error::<Test>::NotAuthorized
+		);
+		// Signed by the wrong account, so not the new creator's consent
+		assert_noop!(
+			ProofOfFaceModule::reassign_dispute(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				3,
+				reassign_consent(2, dispute_id, 2, 3)
+			),
+			Error::<Test>::InvalidReassignProof
+		);
+		// The disputed identity's owner cannot take over the dispute against them
+		assert_noop!(
+			ProofOfFaceModule::reassign_dispute(
+				RuntimeOrigin::signed(2),
+				dispute_id,
+				1,
+				reassign_consent(1, dispute_id, 2, 1)
+			),
+			Error::<Test>::CannotDisputeOwnIdentity
+		);
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().creator, 2);
+	});
+}
+
+#[test]
+fn dispute_waiting_out_resolution_delay_can_be_reassigned() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ResolutionDelay::set(&5);
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, 7, 3);
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::ReadyToResolve
+		);
+
+		assert_ok!(ProofOfFaceModule::reassign_dispute(
+			RuntimeOrigin::signed(2),
+			dispute_id,
+			3,
+			reassign_consent(3, dispute_id, 2, 3)
+		));
+
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().creator, 3);
+	});
+}
+
+// ================================
+// BATCH VERIFICATION SUMMARY TESTS
+// ================================
+
+#[test]
+fn verification_root_is_deterministic() {
+	new_test_ext().execute_with(|| {
+		let results = vec![(test_biometric_hash(1), true, 1), (test_biometric_hash(2), false, 0)];
+
+		let root = ProofOfFaceModule::verification_root(&results);
+		assert_eq!(ProofOfFaceModule::verification_root(&results), root);
+		assert_eq!(
+			root,
+			BlakeTwo256::ordered_trie_root(
+				results.iter().map(|result| result.encode()).collect(),
+				sp_runtime::StateVersion::V1
+			)
+		);
+
+		// Order and every field of a result are committed to
+		let mut reordered = results.clone();
+		reordered.reverse();
+		assert_ne!(ProofOfFaceModule::verification_root(&reordered), root);
+		let mut flipped = results;
+		flipped[1].1 = true;
+		assert_ne!(ProofOfFaceModule::verification_root(&flipped), root);
+	});
+}
+
+#[test]
+fn verification_results_are_reported_per_hash_when_not_summarized() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		SummarizeBatchVerifications::set(&false);
+		let results = vec![
+			(test_biometric_hash(1), true, 1),
+			(test_biometric_hash(2), false, 0),
+			(test_biometric_hash(3), true, 4),
+		];
+
+		ProofOfFaceModule::deposit_verification_results(&results);
+
+		// One event per result, in batch order, and no summary
+		assert_eq!(System::events().len(), 3);
+		for (hash, matched, similarity) in &results {
+			assert_has_unsequenced_event(
+				Event::VerificationPerformed(0, *hash, *matched, *similarity).into(),
+			);
+		}
+		let root = ProofOfFaceModule::verification_root(&results);
+		assert_eq!(
+			count_unsequenced_events(Event::BatchVerificationSummary(0, root, 2, 3).into()),
+			0
+		);
+		System::assert_last_event(
+			Event::VerificationPerformed(last_seq(), test_biometric_hash(3), true, 4).into(),
+		);
+	});
+}
+
+#[test]
+fn verification_results_are_summarized_when_enabled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		SummarizeBatchVerifications::set(&true);
+		let results = vec![
+			(test_biometric_hash(1), true, 1),
+			(test_biometric_hash(2), false, 0),
+			(test_biometric_hash(3), true, 4),
+		];
+
+		ProofOfFaceModule::deposit_verification_results(&results);
+
+		// A single summary carrying the root, the match count and the batch size
+		assert_eq!(System::events().len(), 1);
+		let root = ProofOfFaceModule::verification_root(&results);
+		System::assert_last_event(Event::BatchVerificationSummary(last_seq(), root, 2, 3).into());
+		for (hash, matched, similarity) in &results {
+			assert_eq!(
+				count_unsequenced_events(
+					Event::VerificationPerformed(0, *hash, *matched, *similarity).into()
+				),
+				0
+			);
+		}
+	});
+}
+
+// ================================
+// BATCH COMMITMENT TESTS
+// ================================
+
+/// Helper: leaves of a batch holding the biometric hashes for `seeds`
+fn batch_leaves(seeds: &[u8]) -> Vec<H256> {
+	seeds
+		.iter()
+		.map(|seed| merkle::leaf::<BlakeTwo256>(test_biometric_hash(*seed).as_ref()))
+		.collect()
+}
+
+#[test]
+fn merkle_proofs_verify_for_every_leaf() {
+	let leaves = batch_leaves(&[1, 2, 3, 4, 5]);
+	let root = merkle::root::<BlakeTwo256>(&leaves);
+
+	for (index, leaf) in leaves.iter().enumerate() {
+		let proof = merkle::proof::<BlakeTwo256>(&leaves, index);
+		assert!(merkle::verify::<BlakeTwo256>(&root, *leaf, &proof));
+	}
+	let proof = merkle::proof::<BlakeTwo256>(&leaves, 0);
+	assert!(!merkle::verify::<BlakeTwo256>(&root, leaves[1], &proof));
+}
+
+#[test]
+fn register_from_commitment_works_with_valid_proof() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let leaves = batch_leaves(&[1, 2, 3]);
+		let root = merkle::root::<BlakeTwo256>(&leaves);
+
+		assert_noop!(
+			ProofOfFaceModule::commit_batch(RuntimeOrigin::signed(1), root),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ProofOfFaceModule::commit_batch(RuntimeOrigin::root(), root));
+		assert_eq!(ProofOfFaceModule::batch_commitment(root), Some(1));
+		System::assert_last_event(Event::BatchCommitted(last_seq(), root).into());
+
+		let proof = BoundedVec::truncate_from(merkle::proof::<BlakeTwo256>(&leaves, 1));
+		assert_ok!(ProofOfFaceModule::register_from_commitment(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmTestHash123456789abcdef"),
+			proof
+		));
+
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(2)), Some(1));
+	});
+}
+
+#[test]
+fn register_from_commitment_rejects_invalid_proof() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let leaves = batch_leaves(&[1, 2, 3]);
+		assert_ok!(ProofOfFaceModule::commit_batch(
+			RuntimeOrigin::root(),
+			merkle::root::<BlakeTwo256>(&leaves)
+		));
+
+		// A proof for another leaf, and a hash that was never part of the batch
+		let proof = BoundedVec::truncate_from(merkle::proof::<BlakeTwo256>(&leaves, 0));
+		for seed in [2, 9] {
+			assert_noop!(
+				ProofOfFaceModule::register_from_commitment(
+					RuntimeOrigin::signed(1),
+					test_biometric_hash(seed),
+					test_ipfs_cid("QmTestHash123456789abcdef"),
+					proof.clone()
+				),
+				Error::<Test>::InvalidMerkleProof
+			);
+		}
+	});
+}
+
+// ================================
+// RE-ENROLLMENT TESTS
+// ================================
+
+#[test]
+fn reenroll_swaps_hash_and_cid() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_ok!(ProofOfFaceModule::reenroll(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmNewHash987654321")
+		));
+
+		let proof = ProofOfFaceModule::identity_proofs(1).unwrap();
+		assert_eq!(proof.biometric_hash, test_biometric_hash(2));
+		assert_eq!(proof.ipfs_cid, test_ipfs_cid("QmNewHash987654321"));
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(2)), Some(1));
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(1)), None);
+		System::assert_last_event(
+			Event::IdentityReenrolled(
+				last_seq(),
+				1,
+				test_biometric_hash(1),
+				test_biometric_hash(2),
+			)
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn reenroll_fails_on_taken_hash() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for account in [1, 2] {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+
+		assert_noop!(
+			ProofOfFaceModule::reenroll(
+				RuntimeOrigin::signed(1),
+				test_biometric_hash(2),
+				test_ipfs_cid("QmNewHash987654321")
+			),
+			Error::<Test>::InvalidBiometricHash
+		);
+	});
+}
+
+#[test]
+fn reenroll_fails_on_empty_cid() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_noop!(
+			ProofOfFaceModule::reenroll(
+				RuntimeOrigin::signed(1),
+				test_biometric_hash(2),
+				test_ipfs_cid("")
+			),
+			Error::<Test>::InvalidIpfsCid
+		);
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(1)), Some(1));
+	});
+}
+
+// ================================
+// HALT TESTS
+// ================================
+
+#[test]
+fn halting_blocks_registration_and_voting() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		assert_noop!(
+			ProofOfFaceModule::set_halted(RuntimeOrigin::signed(1), true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ProofOfFaceModule::set_halted(RuntimeOrigin::root(), true));
+		System::assert_last_event(Event::HaltedSet(last_seq(), true).into());
+
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(2),
+				test_biometric_hash(2),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			Error::<Test>::PalletHalted
+		);
+		assert_noop!(
+			ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(100), dispute_id, true),
+			Error::<Test>::PalletHalted
+		);
+
+		// Queries keep answering while halted
+		assert!(ProofOfFaceModule::hash_is_active(&test_biometric_hash(1)));
+	});
+}
+
+#[test]
+fn force_resolve_works_while_halted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::set_halted(RuntimeOrigin::root(), true));
+
+		assert_ok!(ProofOfFaceModule::force_resolve_dispute(RuntimeOrigin::root(), dispute_id, true));
+
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Resolved);
+		assert!(!ProofOfFaceModule::identity_proofs(1).unwrap().is_active);
+		System::assert_last_event(Event::DisputeForceResolved(last_seq(), dispute_id, true).into());
+		assert_noop!(
+			ProofOfFaceModule::force_resolve_dispute(RuntimeOrigin::root(), dispute_id, false),
+			Error::<Test>::DisputeAlreadyResolved
+		);
+	});
+}
+
+#[test]
+fn unhalting_restores_normal_operation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::set_halted(RuntimeOrigin::root(), true));
+		assert_ok!(ProofOfFaceModule::set_halted(RuntimeOrigin::root(), false));
+		assert!(!ProofOfFaceModule::halted());
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(2),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		cast_votes(dispute_id, 1, 0);
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().votes_for, 1);
+	});
+}
+
+// ================================
+// MINIMUM DISTINCT VOTERS TESTS
+// ================================
+
+#[test]
+fn dispute_waits_for_min_distinct_voters() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MinDistinctVoters::set(&(DISPUTE_QUORUM + 2));
+		let (_, dispute_id) = setup_dispute();
+
+		// The vote count reaches quorum, but from too few accounts
+		cast_votes(dispute_id, DISPUTE_QUORUM, 0);
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Pending);
+		assert!(ProofOfFaceModule::identity_proofs(1).unwrap().is_active);
+
+		for voter in [200, 201] {
+			assert_ok!(ProofOfFaceModule::vote_on_dispute(
+				RuntimeOrigin::signed(voter),
+				dispute_id,
+				true
+			));
+		}
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::Resolved
+		);
+	});
+}
+
+// ================================
+// REGISTRATION BLOCK TESTS
+// ================================
+
+#[test]
+fn registered_at_returns_registration_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(7);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		System::set_block_number(12);
+
+		assert_eq!(ProofOfFaceModule::registered_at(&1), Some(7));
+		assert_eq!(ProofOfFaceModule::registered_at(&2), None);
+	});
+}
+
+// ================================
+// HASH VALIDATOR TESTS
+// ================================
+
+#[test]
+fn hash_validator_rejects_registration() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RejectedBiometricHash::set(&Some(test_biometric_hash(1)));
+
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(1),
+				test_biometric_hash(1),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			Error::<Test>::InvalidBiometricHash
+		);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+	});
+}
+
+#[test]
+fn hash_validator_rejects_reenroll() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		RejectedBiometricHash::set(&Some(test_biometric_hash(2)));
+
+		assert_noop!(
+			ProofOfFaceModule::reenroll(
+				RuntimeOrigin::signed(1),
+				test_biometric_hash(2),
+				test_ipfs_cid("QmNewHash987654321")
+			),
+			Error::<Test>::InvalidBiometricHash
+		);
+	});
+}
+
+// ================================
+// UNIQUE VERIFIER TESTS
+// ================================
+
+#[test]
+fn unique_verifiers_are_counted_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		for _ in 0..2 {
+			assert_ok!(ProofOfFaceModule::verify_identity(
+				RuntimeOrigin::signed(2),
+				test_biometric_hash(1)
+			));
+		}
+		assert_eq!(ProofOfFaceModule::unique_verifier_count(1), 1);
+
+		assert_ok!(ProofOfFaceModule::verify_identity(
+			RuntimeOrigin::signed(3),
+			test_biometric_hash(1)
+		));
+		assert_eq!(ProofOfFaceModule::unique_verifier_count(1), 2);
+		assert_eq!(ProofOfFaceModule::verification_nonce(1), 3);
+
+		// Misses are not attributed to any identity
+		assert_ok!(ProofOfFaceModule::verify_identity(
+			RuntimeOrigin::signed(4),
+			test_biometric_hash(9)
+		));
+		assert_eq!(ProofOfFaceModule::unique_verifier_count(1), 2);
+	});
+}
+
+// ================================
+// RECOVERY TESTS
+// ================================
+
+#[test]
+fn set_recovery_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			ProofOfFaceModule::set_recovery(RuntimeOrigin::signed(1), Some(2)),
+			Error::<Test>::IdentityNotFound
+		);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_noop!(
+			ProofOfFaceModule::set_recovery(RuntimeOrigin::signed(1), Some(1)),
+			Error::<Test>::InvalidRecoveryAccount
+		);
+
+		assert_ok!(ProofOfFaceModule::set_recovery(RuntimeOrigin::signed(1), Some(2)));
+
+		assert_eq!(ProofOfFaceModule::recovery_account(1), Some(2));
+		System::assert_last_event(Event::RecoverySet(last_seq(), 1, Some(2)).into());
+
+		assert_ok!(ProofOfFaceModule::set_recovery(RuntimeOrigin::signed(1), None));
+
+		assert_eq!(ProofOfFaceModule::recovery_account(1), None);
+		System::assert_last_event(Event::RecoverySet(last_seq(), 1, None).into());
+	});
+}
+
+#[test]
+fn recover_identity_moves_identity_after_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RegistrationDeposit::set(&10);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_ok!(ProofOfFaceModule::set_recovery(RuntimeOrigin::signed(1), Some(2)));
+
+		System::set_block_number(5);
+		assert_ok!(ProofOfFaceModule::initiate_recovery(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(Event::RecoveryInitiated(last_seq(), 1, 2).into());
+
+		System::set_block_number(5 + RecoveryDelay::get());
+		assert_ok!(ProofOfFaceModule::recover_identity(RuntimeOrigin::signed(2), 1));
+
+		assert!(ProofOfFaceModule::identity_proofs(1).is_none());
+		let proof = ProofOfFaceModule::identity_proofs(2).unwrap();
+		assert_eq!(proof.owner, 2);
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(1)), Some(2));
+		assert_eq!(ProofOfFaceModule::recovery_account(1), None);
+		assert_eq!(ProofOfFaceModule::pending_recovery(1), None);
+		assert_eq!(
+			ProofOfFaceModule::identity_expiries(proof.expires_at.unwrap()).into_inner(),
+			vec![2]
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		System::assert_last_event(Event::IdentityRecovered(last_seq(), 1, 2).into());
+	});
+}
+
+#[test]
+fn recover_identity_rejects_early_or_undesignated_caller() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_ok!(ProofOfFaceModule::set_recovery(RuntimeOrigin::signed(1), Some(2)));
+
+		// The delay counts from initiating the recovery, not from designating the account
+		System::set_block_number(1 + RecoveryDelay::get());
+		assert_noop!(
+			ProofOfFaceModule::recover_identity(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::RecoveryNotInitiated
+		);
+		assert_noop!(
+			ProofOfFaceModule::initiate_recovery(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::NotAuthorized
+		);
+		assert_ok!(ProofOfFaceModule::initiate_recovery(RuntimeOrigin::signed(2), 1));
+		assert_noop!(
+			ProofOfFaceModule::initiate_recovery(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::RecoveryAlreadyInitiated
+		);
+
+		System::set_block_number(2 * RecoveryDelay::get());
+		assert_noop!(
+			ProofOfFaceModule::recover_identity(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::RecoveryTooEarly
+		);
+
+		System::set_block_number(1 + 2 * RecoveryDelay::get());
+		assert_noop!(
+			ProofOfFaceModule::recover_identity(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::NotAuthorized
+		);
+		assert_eq!(ProofOfFaceModule::identity_proofs(1).unwrap().owner, 1);
+	});
+}
+
+#[test]
+fn owner_can_cancel_pending_recovery() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_ok!(ProofOfFaceModule::set_recovery(RuntimeOrigin::signed(1), Some(2)));
+		assert_noop!(
+			ProofOfFaceModule::cancel_recovery(RuntimeOrigin::signed(1)),
+			Error::<Test>::RecoveryNotInitiated
+		);
+		assert_ok!(ProofOfFaceModule::initiate_recovery(RuntimeOrigin::signed(2), 1));
+
+		assert_ok!(ProofOfFaceModule::cancel_recovery(RuntimeOrigin::signed(1)));
+
+		System::assert_last_event(Event::RecoveryCancelled(last_seq(), 1).into());
+		assert_eq!(ProofOfFaceModule::recovery_account(1), Some(2));
+		System::set_block_number(1 + RecoveryDelay::get());
+		assert_noop!(
+			ProofOfFaceModule::recover_identity(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::RecoveryNotInitiated
+		);
+	});
+}
+
+#[test]
+fn removing_recovery_account_cancels_pending_recovery() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_ok!(ProofOfFaceModule::set_recovery(RuntimeOrigin::signed(1), Some(2)));
+		assert_ok!(ProofOfFaceModule::initiate_recovery(RuntimeOrigin::signed(2), 1));
+
+		assert_ok!(ProofOfFaceModule::set_recovery(RuntimeOrigin::signed(1), None));
+
+		assert_eq!(ProofOfFaceModule::pending_recovery(1), None);
+		System::set_block_number(1 + RecoveryDelay::get());
+		assert_noop!(
+			ProofOfFaceModule::recover_identity(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+// ================================
+// BATCH IDENTITY QUERY TESTS
+// ================================
+
+#[test]
+fn identities_of_returns_mixed_batch() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(2),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		let identities = ProofOfFaceModule::identities_of(vec![1, 2]);
+
+		assert_eq!(identities.len(), 2);
+		assert_eq!(identities[0], (1, None));
+		assert_eq!(identities[1].0, 2);
+		assert_eq!(identities[1].1, ProofOfFaceModule::identity_proofs(2));
+		assert!(identities[1].1.is_some());
+	});
+}
+
+#[test]
+fn identities_of_is_capped_at_max_query_batch() {
+	new_test_ext().execute_with(|| {
+		let accounts: Vec<u64> = (1..=MaxQueryBatch::get() as u64 + 2).collect();
+
+		let identities = ProofOfFaceModule::identities_of(accounts);
+
+		assert_eq!(identities.len(), MaxQueryBatch::get() as usize);
+		assert_eq!(identities.last().unwrap().0, MaxQueryBatch::get() as u64);
+	});
+}
+
+// ================================
+// VOTER REWARD TESTS
+// ================================
+
+/// Helper function to register identities for the voters `cast_votes` uses
+fn register_voters(count: u32) {
+	for voter in 100..100 + count as u64 {
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(voter),
+			test_biometric_hash(voter as u8),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+	}
+}
+
+#[test]
+fn majority_voters_gain_and_minority_voters_lose_reputation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		HonestVoterReward::set(&5);
+		DishonestVoterPenalty::set(&2);
+		let (_, dispute_id) = setup_dispute();
+		register_voters(DISPUTE_QUORUM);
+		ProofOfFaceModule::increase_reputation(&109, 10);
+
+		cast_votes(dispute_id, 7, 3);
+
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Resolved);
+		for voter in 100..107 {
+			assert_eq!(ProofOfFaceModule::reputation(voter), 5);
+		}
+		assert_eq!(ProofOfFaceModule::reputation(107), 0);
+		assert_eq!(ProofOfFaceModule::reputation(109), 8);
+	});
+}
+
+#[test]
+fn voter_rewards_skip_voters_without_identity_and_cap_at_max() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		HonestVoterReward::set(&5);
+		let (_, dispute_id) = setup_dispute();
+		register_voters(1);
+		ProofOfFaceModule::increase_reputation(&100, MaxReputation::get());
+
+		cast_votes(dispute_id, DISPUTE_QUORUM, 0);
+
+		assert_eq!(ProofOfFaceModule::reputation(100), MaxReputation::get());
+		assert_eq!(ProofOfFaceModule::reputation(101), 0);
+	});
+}
+
+#[test]
+fn voter_rewards_are_disabled_by_default() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		register_voters(DISPUTE_QUORUM);
+
+		cast_votes(dispute_id, 6, 4);
+
+		for voter in 100..100 + DISPUTE_QUORUM as u64 {
+			assert_eq!(ProofOfFaceModule::reputation(voter), 0);
+		}
+	});
+}
+
+// ================================
+// REGISTRATION FEE TESTS
+// ================================
+
+#[test]
+fn register_weight_grows_with_cid_length() {
+	use frame_support::dispatch::GetDispatchInfo;
+
+	let register_call = |cid_len: usize| {
+		RuntimeCall::ProofOfFace(crate::Call::register_identity {
+			biometric_hash: test_biometric_hash(1),
+			ipfs_cid: BoundedVec::truncate_from(vec![b'Q'; cid_len]),
+		})
+	};
+
+	let short = register_call(10).get_dispatch_info().weight;
+	let long = register_call(100).get_dispatch_info().weight;
+
+	assert_eq!(short, <() as WeightInfo>::register_identity(10));
+	assert!(long.any_gt(short));
+}
+
+// ================================
+// SELF-VERIFICATION TESTS
+// ================================
+
+#[test]
+fn self_verification_is_silent_when_skipped() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		SkipSelfVerificationEvents::set(&true);
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		System::reset_events();
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(1), biometric_hash));
+		assert!(System::events().is_empty());
+		assert!(ProofOfFaceModule::dry_run_verify(&biometric_hash));
+		// The silent verification takes no nonce, so audited nonces have no gaps
+		assert_eq!(ProofOfFaceModule::verification_nonce(1), 0);
+
+		// Other verifiers are still audited
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(2), biometric_hash));
+		System::assert_last_event(
+			Event::VerificationPerformed(last_seq(), biometric_hash, true, 1).into(),
+		);
+	});
+}
+
+#[test]
+fn self_verification_is_audited_by_default() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(1), biometric_hash));
+		System::assert_last_event(
+			Event::VerificationPerformed(last_seq(), biometric_hash, true, 1).into(),
+		);
+	});
+}
+
+#[test]
+fn owner_is_not_counted_as_a_verifier_of_their_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(1), biometric_hash));
+
+		assert_eq!(ProofOfFaceModule::unique_verifier_count(1), 0);
+		assert!(!crate::IdentityVerifiers::<Test>::contains_key(1, 1));
+	});
+}
+
+// ================================
+// DISPUTE FREEZE TESTS
+// ================================
+
+#[test]
+fn voting_is_blocked_while_frozen_and_resumes_after_unfreeze() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		assert_noop!(
+			ProofOfFaceModule::freeze_dispute(RuntimeOrigin::signed(1), dispute_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ProofOfFaceModule::freeze_dispute(RuntimeOrigin::root(), dispute_id));
+		System::assert_last_event(Event::DisputeFrozen(last_seq(), dispute_id).into());
+		assert_noop!(
+			ProofOfFaceModule::freeze_dispute(RuntimeOrigin::root(), dispute_id),
+			Error::<Test>::DisputeFrozen
+		);
+		assert_noop!(
+			ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(100), dispute_id, true),
+			Error::<Test>::DisputeFrozen
+		);
+
+		assert_ok!(ProofOfFaceModule::unfreeze_dispute(RuntimeOrigin::root(), dispute_id));
+		System::assert_last_event(Event::DisputeUnfrozen(last_seq(), dispute_id).into());
+		assert_noop!(
+			ProofOfFaceModule::unfreeze_dispute(RuntimeOrigin::root(), dispute_id),
+			Error::<Test>::DisputeNotFrozen
+		);
+
+		cast_votes(dispute_id, 1, 0);
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().votes_for, 1);
+	});
+}
+
+#[test]
+fn expiry_is_paused_while_frozen() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::freeze_dispute(RuntimeOrigin::root(), dispute_id));
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Pending);
+
+		// Unfreezing rewinds the sweep so the dispute can still expire
+		assert_ok!(ProofOfFaceModule::unfreeze_dispute(RuntimeOrigin::root(), dispute_id));
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::Rejected
+		);
+	});
+}
+
+// ================================
+// PRIVATE MODE TESTS
+// ================================
+
+#[test]
+fn private_mode_hides_identity_from_verification() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_ok!(ProofOfFaceModule::set_private(RuntimeOrigin::signed(1), true));
+		System::assert_last_event(Event::PrivacySet(last_seq(), 1, true).into());
+
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(2), biometric_hash));
+		System::assert_last_event(
+			Event::VerificationPerformed(last_seq(), biometric_hash, false, 0).into(),
+		);
+		assert!(!ProofOfFaceModule::dry_run_verify(&biometric_hash));
+		assert_eq!(ProofOfFaceModule::verify_with_handle(&biometric_hash), None);
+		// The hash stays registered
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(biometric_hash), Some(1));
+
+		assert_ok!(ProofOfFaceModule::set_private(RuntimeOrigin::signed(1), false));
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(2), biometric_hash));
+		System::assert_last_event(
+			Event::VerificationPerformed(last_seq(), biometric_hash, true, 1).into(),
+		);
+		assert!(ProofOfFaceModule::dry_run_verify(&biometric_hash));
+	});
+}
+
+#[test]
+fn set_private_requires_identity() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProofOfFaceModule::set_private(RuntimeOrigin::signed(1), true),
+			Error::<Test>::IdentityNotFound
+		);
+	});
+}
+
+// ================================
+// EVENT NAME TESTS
+// ================================
+
+#[test]
+fn event_variant_names_match_metadata() {
+	assert_eq!(
+		Event::<Test>::DisputeResolved(0, 0, DisputeStatus::Resolved, 10, 0, ResolutionReason::Vote)
+			.variant_name(),
+		Some("DisputeResolved")
+	);
+	assert_eq!(Event::<Test>::PrivacySet(0, 1, true).variant_name(), Some("PrivacySet"));
+}
+
+// ================================
+// MINIMUM CID LENGTH TESTS
+// ================================
+
+#[test]
+fn cid_shorter_than_minimum_is_rejected() {
+	new_test_ext().execute_with(|| {
+		MinIpfsCidLength::set(&32);
+
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(1),
+				test_biometric_hash(1),
+				test_ipfs_cid("Q")
+			),
+			Error::<Test>::InvalidIpfsCid
+		);
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(1),
+				test_biometric_hash(1),
+				test_ipfs_cid(&"Q".repeat(31))
+			),
+			Error::<Test>::InvalidIpfsCid
+		);
+	});
+}
+
+#[test]
+fn cid_of_minimum_length_is_accepted() {
+	new_test_ext().execute_with(|| {
+		MinIpfsCidLength::set(&32);
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid(&"Q".repeat(32))
+		));
+	});
+}
+
+#[test]
+fn empty_cid_is_rejected_even_without_minimum() {
+	new_test_ext().execute_with(|| {
+		MinIpfsCidLength::set(&0);
+
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(1),
+				test_biometric_hash(1),
+				test_ipfs_cid("")
+			),
+			Error::<Test>::InvalidIpfsCid
+		);
+	});
+}
+
+// ================================
+// RESOLUTION REASON TESTS
+// ================================
+
+/// Helper: what settled `dispute_id`
+fn resolution_reason(dispute_id: u64) -> Option<ResolutionReason> {
+	ProofOfFaceModule::disputes(dispute_id).unwrap().resolution_reason
+}
+
+#[test]
+fn open_disputes_have_no_resolution_reason() {
+	new_test_ext().execute_with(|| {
+		let (_, dispute_id) = setup_dispute();
+		assert_eq!(resolution_reason(dispute_id), None);
+	});
+}
+
+#[test]
+fn vote_resolution_records_vote_reason() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		cast_votes(dispute_id, 7, 3);
+
+		assert_eq!(resolution_reason(dispute_id), Some(ResolutionReason::Vote));
+		assert_has_unsequenced_event(
+			Event::DisputeResolved(
+				0,
+				dispute_id,
+				DisputeStatus::Resolved,
+				7,
+				3,
+				ResolutionReason::Vote,
+			)
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn force_resolution_records_force_reason() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		assert_ok!(ProofOfFaceModule::force_resolve_dispute(
+			RuntimeOrigin::root(),
+			dispute_id,
+			false
+		));
+
+		assert_eq!(resolution_reason(dispute_id), Some(ResolutionReason::Force));
+		let reason = ResolutionReason::Force;
+		assert_has_unsequenced_event(
+			Event::DisputeResolved(0, dispute_id, DisputeStatus::Rejected, 0, 0, reason).into(),
+		);
+	});
+}
+
+#[test]
+fn expiry_records_expiry_reason() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+
+		assert_eq!(resolution_reason(dispute_id), Some(ResolutionReason::Expiry));
+	});
+}
+
+#[test]
+fn appeal_records_appeal_reason() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, DISPUTE_QUORUM, 0);
+
+		assert_eq!(ProofOfFaceModule::overturn_dispute(dispute_id), Ok(true));
+
+		assert_eq!(resolution_reason(dispute_id), Some(ResolutionReason::Appeal));
+	});
+}
+
+// ================================
+// BATCH ACTIVE STATUS TESTS
+// ================================
+
+#[test]
+fn active_status_of_reports_mixed_batch() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for account in 1..=2u64 {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(2), false));
+
+		let hashes = vec![test_biometric_hash(1), test_biometric_hash(2), test_biometric_hash(3)];
+		assert_eq!(
+			ProofOfFaceModule::active_status_of(hashes),
+			vec![
+				(test_biometric_hash(1), true),
+				(test_biometric_hash(2), false),
+				(test_biometric_hash(3), false),
+			]
+		);
+	});
+}
+
+#[test]
+fn active_status_of_is_capped_at_max_query_batch() {
+	new_test_ext().execute_with(|| {
+		let hashes: Vec<H256> = (0..MaxQueryBatch::get() as u8 + 2).map(test_biometric_hash).collect();
+
+		assert_eq!(
+			ProofOfFaceModule::active_status_of(hashes).len(),
+			MaxQueryBatch::get() as usize
+		);
+	});
+}
+
+// ================================
+// VOTING WINDOW TESTS
+// ================================
+
+#[test]
+fn votes_within_voting_window_succeed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		VotingWindow::set(&5);
+		let (_, dispute_id) = setup_dispute();
+
+		System::set_block_number(6);
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(100), dispute_id, true));
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().votes_for, 1);
+	});
+}
+
+#[test]
+fn votes_after_voting_window_fail_and_sweep_settles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		VotingWindow::set(&5);
+		let (_, dispute_id) = setup_dispute();
+
+		System::set_block_number(7);
+		assert_noop!(
+			ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(100), dispute_id, true),
+			Error::<Test>::VotingClosed
+		);
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::Rejected
+		);
+	});
+}
+
+#[test]
+fn sweep_expires_dispute_after_voting_window_without_voting_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		DisputeVotingPeriod::set(&0);
+		VotingWindow::set(&5);
+		let (_, dispute_id) = setup_dispute();
+
+		System::set_block_number(5);
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Pending);
+
+		System::set_block_number(6);
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::Rejected
+		);
+		assert_eq!(ProofOfFaceModule::open_disputes_against(&1), 0);
+	});
+}
+
+#[test]
+fn sweep_waits_for_voting_window_longer_than_voting_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		VotingWindow::set(&(DisputeVotingPeriod::get() + 10));
+		let (_, dispute_id) = setup_dispute();
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Pending);
+
+		System::set_block_number(1 + VotingWindow::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::Rejected
+		);
+	});
+}
+
+// ================================
+// DISPUTE FINALIZATION TESTS
+// ================================
+
+/// Helper: leave a dispute pending at exactly quorum, as if `MinDistinctVoters` was lowered
+/// after its votes were cast
+fn setup_stuck_dispute(votes_for: u32) -> u64 {
+	MinDistinctVoters::set(&(DISPUTE_QUORUM + 1));
+	let (_, dispute_id) = setup_dispute();
+	cast_votes(dispute_id, votes_for, DISPUTE_QUORUM - votes_for);
+	assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Pending);
+	MinDistinctVoters::set(&0);
+	dispute_id
+}
+
+#[test]
+fn third_party_finalizes_dispute_stuck_at_quorum() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let dispute_id = setup_stuck_dispute(7);
+
+		assert_ok!(ProofOfFaceModule::finalize_dispute(RuntimeOrigin::signed(50), dispute_id));
+
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Resolved);
+		assert!(!ProofOfFaceModule::is_identity_active(&1));
+		assert_has_unsequenced_event(
+			Event::DisputeResolved(
+				0,
+				dispute_id,
+				DisputeStatus::Resolved,
+				7,
+				3,
+				ResolutionReason::Vote,
+			)
+			.into(),
+		);
+		assert_noop!(
+			ProofOfFaceModule::finalize_dispute(RuntimeOrigin::signed(50), dispute_id),
+			Error::<Test>::DisputeAlreadyResolved
+		);
+	});
+}
+
+#[test]
+fn finalize_respects_resolution_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ResolutionDelay::set(&5);
+		let dispute_id = setup_stuck_dispute(3);
+
+		assert_ok!(ProofOfFaceModule::finalize_dispute(RuntimeOrigin::signed(50), dispute_id));
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::ReadyToResolve
+		);
+
+		run_to_block(6);
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().status, DisputeStatus::Rejected);
+	});
+}
+
+#[test]
+fn finalize_requires_quorum() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		cast_votes(dispute_id, DISPUTE_QUORUM - 1, 0);
+
+		assert_noop!(
+			ProofOfFaceModule::finalize_dispute(RuntimeOrigin::signed(50), dispute_id),
+			Error::<Test>::QuorumNotReached
+		);
+		assert_noop!(
+			ProofOfFaceModule::finalize_dispute(RuntimeOrigin::signed(50), 99),
+			Error::<Test>::DisputeNotFound
+		);
+	});
+}
+
+// ================================
+// REGISTRATION GATE TESTS
+// ================================
+
+#[test]
+fn registration_gate_denies_rejected_account() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		DeniedRegistrant::set(&Some(1));
+
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(1),
+				test_biometric_hash(1),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn registration_gate_allows_other_accounts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		DeniedRegistrant::set(&Some(1));
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(2),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert!(ProofOfFaceModule::identity_proofs(&2).is_some());
+	});
+}
+
+// ================================
+// IPFS CID QUERY TESTS
+// ================================
+
+#[test]
+fn ipfs_cid_of_round_trips_registered_cid() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let cid = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid(cid)
+		));
+
+		let stored = ProofOfFaceModule::ipfs_cid_of(&1).unwrap();
+		assert_eq!(String::from_utf8_lossy(&stored), cid);
+		assert_eq!(ProofOfFaceModule::ipfs_cid_of(&2), None);
+	});
+}
+
+// ================================
+// VOTE DELEGATION TESTS
+// ================================
+
+#[test]
+fn delegate_vote_carries_delegated_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(20), 10));
+		assert_ok!(ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(21), 10));
+		System::assert_last_event(Event::VoteDelegated(last_seq(), 21, 10).into());
+
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(10), dispute_id, true));
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!((dispute.votes_for, dispute.votes_against), (3, 0));
+		assert_eq!(ProofOfFaceModule::delegated_votes(dispute_id, 20), Some(true));
+		assert_eq!(ProofOfFaceModule::dispute_voter_order(dispute_id).len(), 1);
+	});
+}
+
+#[test]
+fn delegator_voting_directly_reclaims_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(20), 10));
+		assert_ok!(ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(21), 10));
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(10), dispute_id, true));
+
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(
+			RuntimeOrigin::signed(20),
+			dispute_id,
+			false
+		));
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!((dispute.votes_for, dispute.votes_against), (2, 1));
+		assert_eq!(ProofOfFaceModule::delegated_votes(dispute_id, 20), None);
+	});
+}
+
+#[test]
+fn delegate_skips_delegators_who_already_voted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(20), 10));
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(
+			RuntimeOrigin::signed(20),
+			dispute_id,
+			false
+		));
+
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(10), dispute_id, true));
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!((dispute.votes_for, dispute.votes_against), (1, 1));
+	});
+}
+
+#[test]
+fn redelegating_does_not_count_a_delegator_twice() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(20), 10));
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(10), dispute_id, true));
+		assert_ok!(ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(20), 11));
+		assert!(ProofOfFaceModule::delegators_of(10).is_empty());
+
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(
+			RuntimeOrigin::signed(11),
+			dispute_id,
+			false
+		));
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!((dispute.votes_for, dispute.votes_against), (2, 1));
+	});
+}
+
+#[test]
+fn delegation_checks_and_undelegate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(20), 20),
+			Error::<Test>::CannotDelegateToSelf
+		);
+		assert_noop!(
+			ProofOfFaceModule::undelegate(RuntimeOrigin::signed(20)),
+			Error::<Test>::NotDelegating
+		);
+		for delegator in 0..MaxDelegators::get() as u64 {
+			assert_ok!(ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(20 + delegator), 10));
+		}
+		assert_noop!(
+			ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(40), 10),
+			Error::<Test>::TooManyDelegators
+		);
+
+		assert_ok!(ProofOfFaceModule::undelegate(RuntimeOrigin::signed(20)));
+		System::assert_last_event(Event::VoteUndelegated(last_seq(), 20, 10).into());
+		assert_eq!(ProofOfFaceModule::vote_delegate(20), None);
+		assert_ok!(ProofOfFaceModule::delegate_vote(RuntimeOrigin::signed(40), 10));
+	});
+}
+
+// ================================
+// TAKEDOWN TESTS
+// ================================
+
+#[test]
+fn force_deactivate_hashes_skips_unknown_hashes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for account in 1..=2u64 {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+		let hashes = BoundedVec::try_from(vec![
+			test_biometric_hash(1),
+			test_biometric_hash(9),
+			test_biometric_hash(2),
+		])
+		.unwrap();
+
+		assert_noop!(
+			ProofOfFaceModule::force_deactivate_hashes(RuntimeOrigin::signed(1), hashes.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ProofOfFaceModule::force_deactivate_hashes(RuntimeOrigin::root(), hashes));
+
+		System::assert_last_event(Event::HashesForceDeactivated(last_seq(), 2).into());
+		assert!(!ProofOfFaceModule::identity_proofs(&1).unwrap().is_active);
+		assert!(!ProofOfFaceModule::identity_proofs(&2).unwrap().is_active);
+	});
+}
+
+#[test]
+fn taken_down_identity_cannot_be_reactivated_until_lifted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		let hashes = BoundedVec::try_from(vec![test_biometric_hash(1)]).unwrap();
+		assert_ok!(ProofOfFaceModule::force_deactivate_hashes(RuntimeOrigin::root(), hashes));
+
+		System::set_block_number(2);
+		assert_noop!(
+			ProofOfFaceModule::reactivate_identity(RuntimeOrigin::signed(1)),
+			Error::<Test>::IdentityTakenDown
+		);
+
+		assert_noop!(
+			ProofOfFaceModule::lift_takedown(RuntimeOrigin::signed(1), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ProofOfFaceModule::lift_takedown(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::TakedownLifted(last_seq(), 1).into());
+		assert_noop!(
+			ProofOfFaceModule::lift_takedown(RuntimeOrigin::root(), 1),
+			Error::<Test>::NotTakenDown
+		);
+
+		assert_ok!(ProofOfFaceModule::reactivate_identity(RuntimeOrigin::signed(1)));
+		assert!(ProofOfFaceModule::is_identity_active(&1));
+	});
+}
+
+#[test]
+fn takedown_marks_already_inactive_identities() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(1), false));
+
+		let hashes = BoundedVec::try_from(vec![test_biometric_hash(1)]).unwrap();
+		assert_ok!(ProofOfFaceModule::force_deactivate_hashes(RuntimeOrigin::root(), hashes));
+		System::assert_last_event(Event::HashesForceDeactivated(last_seq(), 0).into());
+
+		assert_noop!(
+			ProofOfFaceModule::reactivate_identity(RuntimeOrigin::signed(1)),
+			Error::<Test>::IdentityTakenDown
+		);
+	});
+}
+
+#[test]
+fn force_deactivate_hashes_is_bounded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let full: Vec<H256> = (0..MaxTakedownBatch::get() as u8).map(test_biometric_hash).collect();
+		assert_ok!(ProofOfFaceModule::force_deactivate_hashes(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from(full).unwrap()
+		));
+		System::assert_last_event(Event::HashesForceDeactivated(last_seq(), 0).into());
+
+		// One hash over the bound cannot even be constructed
+		let oversized: Vec<H256> =
+			(0..=MaxTakedownBatch::get() as u8).map(test_biometric_hash).collect();
+		assert!(BoundedVec::<_, MaxTakedownBatch>::try_from(oversized).is_err());
+	});
+}
+
+// ================================
+// LAST REGISTERED TESTS
+// ================================
+
+#[test]
+fn last_registered_tracks_newest_registration() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ProofOfFaceModule::last_registered(), None);
+
+		for (block, account) in [(1u64, 3u64), (2, 1), (2, 2), (5, 4)] {
+			System::set_block_number(block);
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account),
+				test_biometric_hash(account as u8),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+			assert_eq!(ProofOfFaceModule::last_registered(), Some((account, block)));
+		}
+	});
+}
+
+#[test]
+fn last_registered_ignores_failed_registration() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		System::set_block_number(2);
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(2),
+				test_biometric_hash(1),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			Error::<Test>::InvalidBiometricHash
+		);
+		assert_eq!(ProofOfFaceModule::last_registered(), Some((1, 1)));
+	});
+}
+
+// ================================
+// VERIFICATION POLICY TESTS
+// ================================
+
+/// Helper function to register identities 1 (active, public), 2 (inactive), 3 (private) and
+/// 4 (inactive and private), with hashes seeded by their account
+fn setup_policy_identities() {
+	for account in 1..=4u64 {
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(account),
+			test_biometric_hash(account as u8),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+	}
+	for account in [2u64, 4] {
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(account), false));
+	}
+	for account in [3u64, 4] {
+		assert_ok!(ProofOfFaceModule::set_private(RuntimeOrigin::signed(account), true));
+	}
+}
+
+/// Helper function to verify each identity of `setup_policy_identities` under `policy`
+fn matches_under(policy: crate::VerificationPolicy) -> Vec<bool> {
+	VerificationPolicy::set(&policy);
+	(1..=4u8)
+		.map(|seed| {
+			assert_ok!(ProofOfFaceModule::verify_identity(
+				RuntimeOrigin::signed(10),
+				test_biometric_hash(seed)
+			));
+			let matched = ProofOfFaceModule::dry_run_verify(&test_biometric_hash(seed));
+			System::assert_last_event(
+				Event::VerificationPerformed(
+					last_seq(),
+					test_biometric_hash(seed),
+					matched,
+					ProofOfFaceModule::verification_nonce(seed as u64),
+				)
+				.into(),
+			);
+			matched
+		})
+		.collect()
+}
+
+#[test]
+fn match_all_policy_matches_every_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_policy_identities();
+
+		assert_eq!(matches_under(crate::VerificationPolicy::MatchAll), vec![true; 4]);
+	});
+}
+
+#[test]
+fn active_only_policy_ignores_private_mode() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_policy_identities();
+
+		assert_eq!(
+			matches_under(crate::VerificationPolicy::ActiveOnly),
+			vec![true, false, true, false]
+		);
+	});
+}
+
+#[test]
+fn active_and_public_policy_matches_only_active_public_identities() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_policy_identities();
+
+		assert_eq!(
+			matches_under(crate::VerificationPolicy::ActiveAndPublic),
+			vec![true, false, false, false]
+		);
+	});
+}
+
+// ================================
+// DISPUTE STATUS COUNT TESTS
+// ================================
+
+/// Helper function to check the maintained counters against a scan of `Disputes`
+fn assert_status_counts(expected: (u32, u32, u32)) {
+	let mut scanned = (0, 0, 0);
+	for (_, dispute) in crate::Disputes::<Test>::iter() {
+		match dispute.status {
+			DisputeStatus::Pending | DisputeStatus::ReadyToResolve => scanned.0 += 1,
+			DisputeStatus::Resolved => scanned.1 += 1,
+			DisputeStatus::Rejected => scanned.2 += 1,
+		}
+	}
+	assert_eq!(scanned, expected);
+	assert_eq!(ProofOfFaceModule::dispute_status_counts(), expected);
+}
+
+#[test]
+fn status_counts_follow_create_vote_resolve() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_status_counts((0, 0, 0));
+
+		let (_, resolved) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(3),
+			test_biometric_hash(3),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		let rejected = ProofOfFaceModule::next_dispute_id();
+		assert_ok!(ProofOfFaceModule::create_dispute(
+			RuntimeOrigin::signed(4),
+			test_biometric_hash(3),
+			DisputeCategory::Impersonation,
+			test_evidence_url("https://evidence.example/2")
+		));
+		assert_status_counts((2, 0, 0));
+
+		cast_votes(resolved, DISPUTE_QUORUM - 1, 0);
+		assert_status_counts((2, 0, 0));
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(RuntimeOrigin::signed(200), resolved, false));
+		assert_status_counts((1, 1, 0));
+
+		cast_votes(rejected, 0, DISPUTE_QUORUM);
+		assert_status_counts((0, 1, 1));
+	});
+}
+
+#[test]
+fn status_counts_follow_delay_expiry_and_appeal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ResolutionDelay::set(&5);
+		let (_, dispute_id) = setup_dispute();
+
+		// Waiting out the delay still counts as open
+		cast_votes(dispute_id, DISPUTE_QUORUM, 0);
+		assert_status_counts((1, 0, 0));
+		run_to_block(6);
+		assert_status_counts((0, 1, 0));
+
+		assert_eq!(ProofOfFaceModule::overturn_dispute(dispute_id), Ok(true));
+		assert_status_counts((0, 0, 1));
+	});
+}
+
+#[test]
+fn status_counts_follow_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_dispute();
+
+		System::set_block_number(1 + DisputeVotingPeriod::get());
+		assert_ok!(ProofOfFaceModule::sweep_expired_disputes(RuntimeOrigin::signed(50), 10));
+
+		assert_status_counts((0, 0, 1));
+	});
+}
+
+// ================================
+// IDENTITY NOTE TESTS
+// ================================
+
+/// Helper function to create a note within the mock's `MaxNoteLen`
+fn note(bytes: &[u8]) -> BoundedVec<u8, frame_support::traits::ConstU32<16>> {
+	BoundedVec::try_from(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn set_note_stores_and_overwrites_note() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			ProofOfFaceModule::set_note(RuntimeOrigin::signed(1), note(b"hint")),
+			Error::<Test>::IdentityNotFound
+		);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		let encrypted = [0xde, 0xad, 0x00, 0xff];
+		assert_ok!(ProofOfFaceModule::set_note(RuntimeOrigin::signed(1), note(&encrypted)));
+		System::assert_last_event(Event::NoteSet(last_seq(), 1).into());
+		assert_eq!(ProofOfFaceModule::note_of(1).unwrap().into_inner(), encrypted.to_vec());
+
+		assert_ok!(ProofOfFaceModule::set_note(RuntimeOrigin::signed(1), note(b"second")));
+		assert_eq!(ProofOfFaceModule::note_of(1), Some(note(b"second")));
+
+		assert_ok!(ProofOfFaceModule::set_note(RuntimeOrigin::signed(1), note(b"")));
+		assert_eq!(ProofOfFaceModule::note_of(1), None);
+	});
+}
+
+#[test]
+fn note_is_bounded_and_removed_with_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// One byte over the bound cannot even be constructed
+		let oversized: Result<BoundedVec<u8, frame_support::traits::ConstU32<16>>, _> =
+			BoundedVec::try_from(vec![0; 17]);
+		assert!(oversized.is_err());
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_ok!(ProofOfFaceModule::set_note(RuntimeOrigin::signed(1), note(&[7; 16])));
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(1), true));
+
+		assert_eq!(ProofOfFaceModule::note_of(1), None);
+	});
+}
+
+// ================================
+// EVENT SEQUENCE TESTS
+// ================================
+
+/// Helper function to collect the sequence numbers of the pallet events emitted so far
+fn emitted_seqs() -> Vec<u64> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::ProofOfFaceModule(event) => Some(event.seq()),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn event_seq_increments_by_one_per_event_within_a_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(ProofOfFaceModule::event_seq(), 0);
+
+		for account in 1..=3u8 {
+			assert_ok!(ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(account as u64),
+				test_biometric_hash(account),
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			));
+		}
+		assert_ok!(ProofOfFaceModule::verify_identity(
+			RuntimeOrigin::signed(10),
+			test_biometric_hash(2)
+		));
+		assert_ok!(ProofOfFaceModule::set_private(RuntimeOrigin::signed(1), true));
+
+		let seqs = emitted_seqs();
+		assert!(seqs.len() >= 5);
+		assert_eq!(seqs, (0..seqs.len() as u64).collect::<Vec<_>>());
+		assert_eq!(ProofOfFaceModule::event_seq(), seqs.len() as u64);
+		System::assert_last_event(Event::PrivacySet(last_seq(), 1, true).into());
+	});
+}
+
+#[test]
+fn event_seq_carries_over_between_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		let first_block = ProofOfFaceModule::event_seq();
+
+		System::reset_events();
+		System::set_block_number(2);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(2),
+			test_biometric_hash(2),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		let seqs = emitted_seqs();
+		assert_eq!(seqs.first(), Some(&first_block));
+		assert_eq!(seqs, (first_block..ProofOfFaceModule::event_seq()).collect::<Vec<_>>());
+	});
+}
+
+// ================================
+// FORCE UNREGISTER TESTS
+// ================================
+
+#[test]
+fn force_unregister_purges_identity_and_allows_fresh_registration() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (biometric_hash, _) = setup_dispute();
+		assert_ok!(ProofOfFaceModule::set_note(RuntimeOrigin::signed(1), note(b"hint")));
+		assert_ok!(ProofOfFaceModule::verify_identity(RuntimeOrigin::signed(10), biometric_hash));
+		assert_eq!(ProofOfFaceModule::open_disputes(1), 1);
+		let total = ProofOfFaceModule::total_identities();
+
+		assert_ok!(ProofOfFaceModule::force_unregister(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::IdentityPurged(last_seq(), 1, biometric_hash).into());
+
+		assert!(ProofOfFaceModule::get_identity_proof(&1).is_none());
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(biometric_hash), None);
+		assert_eq!(ProofOfFaceModule::note_of(1), None);
+		assert_eq!(ProofOfFaceModule::open_disputes(1), 0);
+		assert_eq!(ProofOfFaceModule::verification_nonce(1), 0);
+		assert_eq!(ProofOfFaceModule::total_identities(), total - 1);
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(biometric_hash), Some(1));
+		assert_eq!(ProofOfFaceModule::total_identities(), total);
+	});
+}
+
+#[test]
+fn force_unregister_requires_force_origin_and_an_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			test_biometric_hash(1),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_noop!(
+			ProofOfFaceModule::force_unregister(RuntimeOrigin::signed(1), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ProofOfFaceModule::force_unregister(RuntimeOrigin::root(), 2),
+			Error::<Test>::IdentityNotFound
+		);
+		assert!(ProofOfFaceModule::get_identity_proof(&1).is_some());
+	});
+}
+
+// ================================
+// RESOLUTION CAP TESTS
+// ================================
+
+/// Helper function to open a dispute against hash 1 from `creator` and bring it to quorum
+fn ready_dispute(creator: u64) -> u64 {
+	let dispute_id = ProofOfFaceModule::next_dispute_id();
+	assert_ok!(ProofOfFaceModule::create_dispute(
+		RuntimeOrigin::signed(creator),
+		test_biometric_hash(1),
+		DisputeCategory::Impersonation,
+		test_evidence_url("https://evidence.example/cap")
+	));
+	cast_votes(dispute_id, DISPUTE_QUORUM, 0);
+	dispute_id
+}
+
+/// Helper function to list which of `ids` have been settled
+fn settled(ids: &[u64]) -> Vec<bool> {
+	ids.iter()
+		.map(|id| ProofOfFaceModule::disputes(id).unwrap().status == DisputeStatus::Resolved)
+		.collect()
+}
+
+#[test]
+fn resolutions_over_the_cap_settle_in_later_blocks_in_order() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ResolutionDelay::set(&5);
+		MaxResolutionsPerBlock::set(&2);
+		let (_, first) = setup_dispute();
+		cast_votes(first, DISPUTE_QUORUM, 0);
+		let mut ids = vec![first];
+		ids.extend((3..=6).map(ready_dispute));
+		assert_eq!(ProofOfFaceModule::scheduled_resolutions(6).len(), 5);
+
+		run_to_block(5);
+		assert_eq!(settled(&ids), vec![false; 5]);
+
+		run_to_block(6);
+		assert_eq!(settled(&ids), vec![true, true, false, false, false]);
+		assert_eq!(ProofOfFaceModule::scheduled_resolutions(7).into_inner(), ids[2..].to_vec());
+
+		run_to_block(7);
+		assert_eq!(settled(&ids), vec![true, true, true, true, false]);
+
+		run_to_block(8);
+		assert_eq!(settled(&ids), vec![true; 5]);
+		assert!(ProofOfFaceModule::scheduled_resolutions(9).is_empty());
+	});
+}
+
+#[test]
+fn deferred_resolutions_go_ahead_of_later_scheduled_disputes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ResolutionDelay::set(&5);
+		MaxResolutionsPerBlock::set(&2);
+		let (_, first) = setup_dispute();
+		cast_votes(first, DISPUTE_QUORUM, 0);
+		let mut ids = vec![first];
+		ids.extend((3..=4).map(ready_dispute));
+
+		// Scheduled for block 7, behind the dispute deferred from block 6
+		run_to_block(2);
+		ids.push(ready_dispute(5));
+		assert_eq!(ProofOfFaceModule::scheduled_resolutions(7).into_inner(), vec![ids[3]]);
+
+		run_to_block(6);
+		assert_eq!(ProofOfFaceModule::scheduled_resolutions(7).into_inner(), ids[2..].to_vec());
+
+		run_to_block(7);
+		assert_eq!(settled(&ids), vec![true; 4]);
+
+		let resolved: Vec<u64> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::ProofOfFaceModule(Event::DisputeResolved(_, id, ..)) => Some(id),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(resolved, ids);
+	});
+}
+
+// ================================
+// HASH OWNER AND STATUS TESTS
+// ================================
+
+#[test]
+fn hash_owner_and_status_reports_active_and_inactive_owners() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_eq!(ProofOfFaceModule::hash_owner_and_status(&biometric_hash), (Some(1), true));
+
+		assert_ok!(ProofOfFaceModule::deactivate_identity(RuntimeOrigin::signed(1), false));
+		assert_eq!(ProofOfFaceModule::hash_owner_and_status(&biometric_hash), (Some(1), false));
+	});
+}
+
+#[test]
+fn hash_owner_and_status_hides_private_identities() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let biometric_hash = test_biometric_hash(1);
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(1),
+			biometric_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_ok!(ProofOfFaceModule::set_private(RuntimeOrigin::signed(1), true));
+
+		// The default `ActiveAndPublic` policy treats the private identity as unknown
+		assert_eq!(ProofOfFaceModule::hash_owner_and_status(&biometric_hash), (None, false));
+
+		// Policies that match private identities report the owner as before
+		VerificationPolicy::set(&crate::VerificationPolicy::ActiveOnly);
+		assert_eq!(ProofOfFaceModule::hash_owner_and_status(&biometric_hash), (Some(1), true));
+	});
+}
+
+#[test]
+fn hash_owner_and_status_handles_unknown_and_dangling_hashes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let unknown = test_biometric_hash(9);
+		assert_eq!(ProofOfFaceModule::hash_owner_and_status(&unknown), (None, false));
+
+		// A reverse entry whose owner has no stored identity
+		BiometricHashToOwner::<Test>::insert(test_biometric_hash(2), 7);
+		assert_eq!(
+			ProofOfFaceModule::hash_owner_and_status(&test_biometric_hash(2)),
+			(Some(7), false)
+		);
+	});
+}
+
+// ================================
+// QUORUM SNAPSHOT TESTS
+// ================================
+
+/// Helper function to register an identity for each of `accounts`, seeding its hash with
+/// the account
+fn register_accounts(accounts: impl Iterator<Item = u64>) {
+	for account in accounts {
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(account),
+			test_biometric_hash(account as u8),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+	}
+}
+
+/// Helper function to have account 2 dispute account 1's identity, returning the dispute ID
+fn dispute_first_identity() -> u64 {
+	let dispute_id = ProofOfFaceModule::next_dispute_id();
+	assert_ok!(ProofOfFaceModule::create_dispute(
+		RuntimeOrigin::signed(2),
+		test_biometric_hash(1),
+		DisputeCategory::Impersonation,
+		test_evidence_url("https://evidence.example/quorum")
+	));
+	dispute_id
+}
+
+#[test]
+fn dispute_quorum_defaults_to_dispute_quorum() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+
+		let dispute = ProofOfFaceModule::disputes(dispute_id).unwrap();
+		assert_eq!(dispute.quorum_snapshot, DISPUTE_QUORUM);
+	});
+}
+
+#[test]
+fn registrations_after_creation_do_not_raise_a_disputes_quorum() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		QuorumShare::set(&Permill::from_percent(50));
+		register_accounts(1..=24);
+		let dispute_id = dispute_first_identity();
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().quorum_snapshot, 12);
+
+		register_accounts(30..=49);
+		assert_eq!(ProofOfFaceModule::total_identities(), 44);
+
+		cast_votes(dispute_id, 12, 0);
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::Resolved
+		);
+	});
+}
+
+#[test]
+fn purges_after_creation_do_not_lower_a_disputes_quorum() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		QuorumShare::set(&Permill::from_percent(50));
+		register_accounts(1..=30);
+		let dispute_id = dispute_first_identity();
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id).unwrap().quorum_snapshot, 15);
+
+		for account in 21..=30 {
+			assert_ok!(ProofOfFaceModule::deactivate_identity(
+				RuntimeOrigin::signed(account),
+				true
+			));
+		}
+		assert_eq!(ProofOfFaceModule::total_identities(), 20);
+
+		cast_votes(dispute_id, 14, 0);
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::Pending
+		);
+		assert_ok!(ProofOfFaceModule::vote_on_dispute(
+			RuntimeOrigin::signed(200),
+			dispute_id,
+			true
+		));
+		assert_eq!(
+			ProofOfFaceModule::disputes(dispute_id).unwrap().status,
+			DisputeStatus::Resolved
+		);
+	});
+}
+
+// ================================
+// BATCH ATTESTATION TESTS
+// ================================
+
+#[test]
+fn attest_batch_stores_root_with_its_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let leaves: Vec<H256> = [1u8, 2, 3]
+			.iter()
+			.map(|seed| merkle::leaf::<BlakeTwo256>(test_biometric_hash(*seed).as_ref()))
+			.collect();
+		let root = merkle::root::<BlakeTwo256>(&leaves);
+
+		assert_ok!(ProofOfFaceModule::attest_batch(RuntimeOrigin::signed(10), root));
+		assert_eq!(ProofOfFaceModule::attestation_root(root), Some(5));
+		System::assert_last_event(Event::BatchAttested(last_seq(), root, 5).into());
+
+		// Each result can be proven against the stored root off-chain
+		let proof = merkle::proof::<BlakeTwo256>(&leaves, 1);
+		assert!(merkle::verify::<BlakeTwo256>(&root, leaves[1], &proof));
+
+		System::set_block_number(6);
+		assert_noop!(
+			ProofOfFaceModule::attest_batch(RuntimeOrigin::signed(11), root),
+			Error::<Test>::AlreadyAttested
+		);
+		assert_eq!(ProofOfFaceModule::attestation_root(root), Some(5));
+	});
+}
+
+#[test]
+fn attest_batch_requires_verifier_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let root = test_biometric_hash(9);
+
+		for origin in [RuntimeOrigin::root(), RuntimeOrigin::none()] {
+			assert_noop!(
+				ProofOfFaceModule::attest_batch(origin, root),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		}
+		assert_eq!(ProofOfFaceModule::attestation_root(root), None);
+	});
+}
+
+// ================================
+// DISPUTED HASH TESTS
+// ================================
+
+/// Helper function to open a dispute on hash 1 and then release it from its owner
+fn release_disputed_hash() -> (H256, u64) {
+	RejectDisputedHashes::set(&true);
+	let (biometric_hash, dispute_id) = setup_dispute();
+	assert_ok!(ProofOfFaceModule::force_unregister(RuntimeOrigin::root(), 1));
+	assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(biometric_hash), None);
+	assert_eq!(ProofOfFaceModule::open_disputes_by_hash(biometric_hash), 1);
+	(biometric_hash, dispute_id)
+}
+
+#[test]
+fn rotating_to_a_disputed_hash_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (disputed_hash, _) = release_disputed_hash();
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(3),
+			test_biometric_hash(3),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_noop!(
+			ProofOfFaceModule::reenroll(
+				RuntimeOrigin::signed(3),
+				disputed_hash,
+				test_ipfs_cid("QmNewHash987654321")
+			),
+			Error::<Test>::HashUnderDispute
+		);
+		assert_noop!(
+			ProofOfFaceModule::register_identity(
+				RuntimeOrigin::signed(4),
+				disputed_hash,
+				test_ipfs_cid("QmTestHash123456789abcdef")
+			),
+			Error::<Test>::HashUnderDispute
+		);
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(3)), Some(3));
+	});
+}
+
+#[test]
+fn rotating_to_an_undisputed_hash_succeeds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		release_disputed_hash();
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(3),
+			test_biometric_hash(3),
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+
+		assert_ok!(ProofOfFaceModule::reenroll(
+			RuntimeOrigin::signed(3),
+			test_biometric_hash(4),
+			test_ipfs_cid("QmNewHash987654321")
+		));
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(4)), Some(3));
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(3)), None);
+	});
+}
+
+#[test]
+fn disputed_hash_is_released_once_its_dispute_settles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (disputed_hash, dispute_id) = release_disputed_hash();
+
+		assert_ok!(ProofOfFaceModule::force_resolve_dispute(
+			RuntimeOrigin::root(),
+			dispute_id,
+			false
+		));
+		assert_eq!(ProofOfFaceModule::open_disputes_by_hash(disputed_hash), 0);
+
+		assert_ok!(ProofOfFaceModule::register_identity(
+			RuntimeOrigin::signed(4),
+			disputed_hash,
+			test_ipfs_cid("QmTestHash123456789abcdef")
+		));
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(disputed_hash), Some(4));
+	});
+}
+
+#[test]
+fn batch_register_skips_disputed_hashes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (disputed_hash, _) = release_disputed_hash();
+
+		assert_ok!(ProofOfFaceModule::batch_register(
+			RuntimeOrigin::root(),
+			import_batch(vec![(3, 1), (4, 4)])
+		));
+
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(disputed_hash), None);
+		assert!(ProofOfFaceModule::identity_proofs(3).is_none());
+		assert_eq!(ProofOfFaceModule::biometric_hash_to_owner(test_biometric_hash(4)), Some(4));
+		System::assert_last_event(Event::BatchRegistered(last_seq(), 1, 1).into());
+	});
+}
+
+// ================================
+// MIGRATION TESTS
+// ================================
+
+#[test]
+fn migrations_bring_v0_proofs_and_disputes_to_the_current_layout() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let biometric_hash = test_biometric_hash(1);
+		let url = b"https://evidence.example/1".to_vec();
+		StorageVersion::new(0).put::<ProofOfFaceModule>();
+		frame_support::storage::unhashed::put_raw(
+			&IdentityProofs::<Test>::hashed_key_for(1),
+			&(1u64, biometric_hash, b"QmOldHash".to_vec(), 5u64, true).encode(),
+		);
+		frame_support::storage::unhashed::put_raw(
+			&crate::Disputes::<Test>::hashed_key_for(0),
+			&(0u64, biometric_hash, 2u64, url.clone(), 3u32, 1u32, DisputeStatus::Pending, 7u64)
+				.encode(),
+		);
+
+		crate::migrations::All::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProofOfFaceModule::on_chain_storage_version(), 12);
+		let proof = ProofOfFaceModule::identity_proofs(1).unwrap();
+		assert_eq!(proof.biometric_hash, biometric_hash);
+		assert_eq!(proof.ipfs_cid.to_vec(), b"QmOldHash".to_vec());
+		assert_eq!(proof.timestamp, 5);
+		assert_eq!((proof.expires_at, proof.gateway, proof.private), (None, None, false));
+
+		let dispute = ProofOfFaceModule::disputes(0).unwrap();
+		assert_eq!(dispute.creator, 2);
+		assert_eq!(dispute.category, DisputeCategory::Other);
+		assert_eq!(dispute.evidence_url().unwrap().to_vec(), url);
+		assert_eq!((dispute.votes_for, dispute.votes_against), (3, 1));
+		assert_eq!(dispute.status, DisputeStatus::Pending);
+		assert_eq!((dispute.acknowledged_at, dispute.ready_at), (None, None));
+		assert!(!dispute.frozen);
+		assert_eq!(dispute.resolution_reason, None);
+		assert_eq!(dispute.quorum_snapshot, DISPUTE_QUORUM);
+	});
+}
+
+#[test]
+fn migrations_leave_current_storage_untouched() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, dispute_id) = setup_dispute();
+		let proof = ProofOfFaceModule::identity_proofs(1);
+		let dispute = ProofOfFaceModule::disputes(dispute_id);
+
+		crate::migrations::All::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProofOfFaceModule::on_chain_storage_version(), 12);
+		assert_eq!(ProofOfFaceModule::identity_proofs(1), proof);
+		assert_eq!(ProofOfFaceModule::disputes(dispute_id), dispute);
+	});
+}
+
+#[test]
+fn migration_to_v11_counts_open_disputes_by_hash() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (biometric_hash, _) = setup_dispute();
+		// As on a chain from before the counter existed
+		crate::OpenDisputesByHash::<Test>::remove(biometric_hash);
+		assert!(ProofOfFaceModule::do_try_state().is_err());
+		StorageVersion::new(10).put::<ProofOfFaceModule>();
+
+		crate::migrations::All::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProofOfFaceModule::on_chain_storage_version(), 12);
+		assert_eq!(ProofOfFaceModule::open_disputes_by_hash(biometric_hash), 1);
+		assert_ok!(ProofOfFaceModule::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_stale_open_dispute_count() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_dispute();
+		assert_ok!(ProofOfFaceModule::ensure_open_dispute_counts());
+
+		crate::OpenDisputesByHash::<Test>::insert(test_biometric_hash(9), 1);
+
+		assert!(ProofOfFaceModule::ensure_open_dispute_counts().is_err());
+	});
+}
+
+#[test]
+fn migration_to_v12_drops_recovery_designation_block() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(11).put::<ProofOfFaceModule>();
+		frame_support::storage::unhashed::put_raw(
+			&crate::RecoveryAccounts::<Test>::hashed_key_for(1),
+			&(2u64, 5u64).encode(),
+		);
+
+		crate::migrations::All::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProofOfFaceModule::on_chain_storage_version(), 12);
+		assert_eq!(ProofOfFaceModule::recovery_account(1), Some(2));
+		assert_eq!(ProofOfFaceModule::pending_recovery(1), None);
 	});
 }
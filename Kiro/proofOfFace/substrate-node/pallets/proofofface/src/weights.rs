@@ -1,12 +1,12 @@
-//! Autogenerated weights for pallet_proofofface
+//! Weights for pallet_proofofface
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `proofofface-benchmarking`, CPU: `Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! ESTIMATES ONLY: every weight in this file is hand-written and none has been measured yet.
+//! Regenerate the file from `benchmarking.rs` with the command below before relying on these
+//! numbers on a production chain. `claim_migrated_identity`, `link_account` and
+//! `reassign_dispute` have no benchmark, as they need a signature the generic benchmarks
+//! cannot produce, and keep their estimates after regeneration.
 
-// Executed Command:
+// Command to regenerate:
 // ./target/production/proofofface-node
 // benchmark
 // pallet
@@ -29,10 +29,54 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_proofofface.
 pub trait WeightInfo {
-	fn register_identity() -> Weight;
+	fn register_identity(c: u32) -> Weight;
 	fn verify_identity() -> Weight;
-	fn raise_dispute() -> Weight;
-	fn vote_dispute() -> Weight;
+	fn raise_dispute(c: u32) -> Weight;
+	fn vote_on_dispute_no_resolution() -> Weight;
+	fn vote_on_dispute_resolving() -> Weight;
+	fn refresh_identity() -> Weight;
+	fn batch_register(n: u32) -> Weight;
+	fn update_evidence() -> Weight;
+	fn buy_verifications() -> Weight;
+	fn acknowledge_dispute() -> Weight;
+	fn verify_identity_miss() -> Weight;
+	fn add_evidence() -> Weight;
+	fn claim_migrated_identity() -> Weight;
+	fn set_gateway() -> Weight;
+	fn link_account() -> Weight;
+	fn unlink_account() -> Weight;
+	fn set_handle() -> Weight;
+	fn purge_identity() -> Weight;
+	fn report_spoof(c: u32) -> Weight;
+	fn add_comment() -> Weight;
+	fn merge_identities() -> Weight;
+	fn sweep_expired_disputes(n: u32) -> Weight;
+	fn add_allowed_gateway() -> Weight;
+	fn remove_allowed_gateway() -> Weight;
+	fn reassign_dispute() -> Weight;
+	fn commit_batch() -> Weight;
+	fn register_from_commitment() -> Weight;
+	fn reenroll() -> Weight;
+	fn set_halted() -> Weight;
+	fn force_resolve_dispute() -> Weight;
+	fn set_recovery() -> Weight;
+	fn recover_identity() -> Weight;
+	fn reward_voters(n: u32) -> Weight;
+	fn apply_delegated_votes(n: u32) -> Weight;
+	fn freeze_dispute() -> Weight;
+	fn unfreeze_dispute() -> Weight;
+	fn set_private() -> Weight;
+	fn finalize_dispute() -> Weight;
+	fn delegate_vote() -> Weight;
+	fn undelegate() -> Weight;
+	fn force_deactivate_hashes(n: u32) -> Weight;
+	fn set_note() -> Weight;
+	fn force_unregister() -> Weight;
+	fn attest_batch() -> Weight;
+	fn resolve_appeal() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn cancel_recovery() -> Weight;
+	fn lift_takedown() -> Weight;
 }
 
 /// Weights for pallet_proofofface using the Substrate node and recommended hardware.
@@ -40,14 +84,31 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: ProofOfFace Identities (r:1 w:1)
 	/// Proof: ProofOfFace Identities (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn register_identity() -> Weight {
+	/// Storage: ProofOfFace RegistrationsThisBlock (r:1 w:1)
+	/// Proof: ProofOfFace RegistrationsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace RegistrationDeposits (r:0 w:1)
+	/// Proof: ProofOfFace RegistrationDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace TotalIdentities (r:1 w:1)
+	/// Proof: ProofOfFace TotalIdentities (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace LastMilestone (r:1 w:1)
+	/// Proof: ProofOfFace LastMilestone (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace LastRegistered (r:0 w:1)
+	/// Proof: ProofOfFace LastRegistered (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesByHash (r:1 w:0)
+	/// Proof: ProofOfFace OpenDisputesByHash (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 100]`.
+	fn register_identity(c: u32) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
 		//  Estimated: `3593`
-		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(16_000_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// Estimated execution time (not measured): 15_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3593)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(12_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	
 	/// Storage: ProofOfFace Identities (r:1 w:1)
@@ -56,14 +117,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: ProofOfFace Verifications (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
 	/// Storage: ProofOfFace VerificationCounter (r:1 w:1)
 	/// Proof: ProofOfFace VerificationCounter (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace VerificationNonces (r:1 w:1)
+	/// Proof: ProofOfFace VerificationNonces (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityVerifiers (r:1 w:1)
+	/// Proof: ProofOfFace IdentityVerifiers (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace UniqueVerifierCount (r:1 w:1)
+	/// Proof: ProofOfFace UniqueVerifierCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
 	fn verify_identity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `178`
-		//  Estimated: `7845`
-		// Minimum execution time: 25_000_000 picoseconds.
-		Weight::from_parts(26_000_000, 7845)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `502`
+		//  Estimated: `10644`
+		// Estimated execution time (not measured): 32_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 10644)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	
 	/// Storage: ProofOfFace Identities (r:1 w:0)
@@ -72,43 +141,994 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: ProofOfFace DisputeCounter (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: ProofOfFace Disputes (r:0 w:1)
 	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(512), added: 2987, mode: MaxEncodedLen)
-	fn raise_dispute() -> Weight {
+	/// Storage: ProofOfFace OpenDisputesByHash (r:1 w:1)
+	/// Proof: ProofOfFace OpenDisputesByHash (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1000 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeJury (r:0 w:1)
+	/// Proof: ProofOfFace DisputeJury (max_values: None, max_size: Some(3218), added: 5693, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 1000]`.
+	fn raise_dispute(c: u32) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `178`
-		//  Estimated: `6089`
-		// Minimum execution time: 20_000_000 picoseconds.
+		//  Measured:  `178 + c * (324 ±0)`
+		//  Estimated: `6089 + c * (2799 ±0)`
+		// Estimated execution time (not measured): 20_000_000 picoseconds.
 		Weight::from_parts(21_000_000, 6089)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2799).saturating_mul(c.into()))
+	}
+	
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeJury (r:1 w:0)
+	/// Proof: ProofOfFace DisputeJury (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ProofOfFace DisputeVotes (r:1 w:1)
+	/// Proof: ProofOfFace DisputeVotes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeVoterOrder (r:1 w:1)
+	/// Proof: ProofOfFace DisputeVoterOrder (max_values: None, max_size: Some(8214), added: 10689, mode: MaxEncodedLen)
+	fn vote_on_dispute_no_resolution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6341`
+		// Estimated execution time (not measured): 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 6341)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeJury (r:1 w:0)
+	/// Proof: ProofOfFace DisputeJury (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ProofOfFace DisputeVotes (r:1 w:1)
+	/// Proof: ProofOfFace DisputeVotes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeVoterOrder (r:1 w:1)
+	/// Proof: ProofOfFace DisputeVoterOrder (max_values: None, max_size: Some(8214), added: 10689, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:2 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesAgainst (r:1 w:1)
+	/// Proof: ProofOfFace OpenDisputesAgainst (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn vote_on_dispute_resolving() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `687`
+		//  Estimated: `6341`
+		// Estimated execution time (not measured): 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 6341)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityExpiries (r:2 w:2)
+	/// Proof: ProofOfFace IdentityExpiries (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	fn refresh_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `22352`
+		// Estimated execution time (not measured): 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 22352)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:1)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityExpiries (r:1 w:1)
+	/// Proof: ProofOfFace IdentityExpiries (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 64]`.
+	fn batch_register(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (96 ±0)`
+		//  Estimated: `11671 + n * (2603 ±0)`
+		// Estimated execution time (not measured): 9_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 11671)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(512), added: 2987, mode: MaxEncodedLen)
+	fn update_evidence() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `3977`
+		// Estimated execution time (not measured): 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace VerificationAllowance (r:1 w:1)
+	/// Proof: ProofOfFace VerificationAllowance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn buy_verifications() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3593`
+		// Estimated execution time (not measured): 40_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 3593)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	
+
 	/// Storage: ProofOfFace Disputes (r:1 w:1)
 	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(512), added: 2987, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn acknowledge_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `477`
+		//  Estimated: `3977`
+		// Estimated execution time (not measured): 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace VerificationAllowance (r:1 w:1)
+	/// Proof: ProofOfFace VerificationAllowance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn verify_identity_miss() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3849`
+		// Estimated execution time (not measured): 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3545)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	fn add_evidence() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `885`
+		//  Estimated: `4385`
+		// Estimated execution time (not measured): 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 4385)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:1)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityExpiries (r:1 w:1)
+	/// Proof: ProofOfFace IdentityExpiries (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace RegistrationsThisBlock (r:1 w:1)
+	/// Proof: ProofOfFace RegistrationsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Not benchmarked: needs a signature the generic benchmarks cannot produce.
+	fn claim_migrated_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8171`
+		//  Estimated: `11671`
+		// Estimated execution time (not measured): 67_000_000 picoseconds.
+		Weight::from_parts(68_000_000, 11671)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ProofOfFace AllowedGateways (r:1 w:0)
+	/// Proof: ProofOfFace AllowedGateways (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	fn set_gateway() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3849`
+		// Estimated execution time (not measured): 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3849)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:2 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace LinkedToPrimary (r:1 w:1)
+	/// Proof: ProofOfFace LinkedToPrimary (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace LinkedAccounts (r:0 w:1)
+	/// Proof: ProofOfFace LinkedAccounts (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace LinkNonces (r:1 w:1)
+	/// Proof: ProofOfFace LinkNonces (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Not benchmarked: needs a signature the generic benchmarks cannot produce.
+	fn link_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2627`
+		//  Estimated: `6127`
+		// Estimated execution time (not measured): 46_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 6127)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfFace LinkedAccounts (r:1 w:1)
+	/// Proof: ProofOfFace LinkedAccounts (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace LinkedToPrimary (r:1 w:1)
+	/// Proof: ProofOfFace LinkedToPrimary (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn unlink_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `61`
+		//  Estimated: `3561`
+		// Estimated execution time (not measured): 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3561)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace HandleToAccount (r:1 w:2)
+	/// Proof: ProofOfFace HandleToAccount (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Handles (r:1 w:1)
+	/// Proof: ProofOfFace Handles (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn set_handle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2609`
+		//  Estimated: `6109`
+		// Estimated execution time (not measured): 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 6109)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesAgainst (r:1 w:0)
+	/// Proof: ProofOfFace OpenDisputesAgainst (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:0 w:1)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityExpiries (r:1 w:1)
+	/// Proof: ProofOfFace IdentityExpiries (max_values: None, max_size: Some(334), added: 2809, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Handles (r:1 w:1)
+	/// Proof: ProofOfFace Handles (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace HandleToAccount (r:0 w:1)
+	/// Proof: ProofOfFace HandleToAccount (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace RegistrationDeposits (r:1 w:1)
+	/// Proof: ProofOfFace RegistrationDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace TotalIdentities (r:1 w:1)
+	/// Proof: ProofOfFace TotalIdentities (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn purge_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3593`
+		// Estimated execution time (not measured): 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace NextDisputeId (r:1 w:1)
+	/// Proof: ProofOfFace NextDisputeId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace CreatedDisputes (r:1 w:1)
+	/// Proof: ProofOfFace CreatedDisputes (max_values: None, max_size: Some(882), added: 3357, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesAgainst (r:1 w:1)
+	/// Proof: ProofOfFace OpenDisputesAgainst (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Disputes (r:0 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(1500), added: 3975, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace FailedVerifications (r:1 w:1)
+	/// Proof: ProofOfFace FailedVerifications (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1000 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 1000]`.
+	fn report_spoof(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93 + c * (324 ±0)`
+		//  Estimated: `3593 + c * (2799 ±0)`
+		// Estimated execution time (not measured): 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 3593)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 2799).saturating_mul(c.into()))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:0)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(1500), added: 3975, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeJury (r:1 w:0)
+	/// Proof: ProofOfFace DisputeJury (max_values: None, max_size: Some(3218), added: 5693, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeVotes (r:1 w:0)
+	/// Proof: ProofOfFace DisputeVotes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeCommentCount (r:1 w:1)
+	/// Proof: ProofOfFace DisputeCommentCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeComments (r:0 w:1)
+	/// Proof: ProofOfFace DisputeComments (max_values: None, max_size: Some(574), added: 3049, mode: MaxEncodedLen)
+	fn add_comment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3593`
+		// Estimated execution time (not measured): 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:2 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesAgainst (r:1 w:0)
+	/// Proof: ProofOfFace OpenDisputesAgainst (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace MergedHashes (r:1 w:2)
+	/// Proof: ProofOfFace MergedHashes (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:0 w:2)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace LinkedAccounts (r:1 w:0)
+	/// Proof: ProofOfFace LinkedAccounts (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityExpiries (r:1 w:1)
+	/// Proof: ProofOfFace IdentityExpiries (max_values: None, max_size: Some(334), added: 2809, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Handles (r:1 w:1)
+	/// Proof: ProofOfFace Handles (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DeactivatedByDispute (r:0 w:1)
+	/// Proof: ProofOfFace DeactivatedByDispute (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace RegistrationDeposits (r:1 w:1)
+	/// Proof: ProofOfFace RegistrationDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace TotalIdentities (r:1 w:1)
+	/// Proof: ProofOfFace TotalIdentities (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn merge_identities() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3593`
+		// Estimated execution time (not measured): 39_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+
+	/// Storage: ProofOfFace SweepCursor (r:1 w:1)
+	/// Proof: ProofOfFace SweepCursor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace NextDisputeId (r:1 w:0)
+	/// Proof: ProofOfFace NextDisputeId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Disputes (r:64 w:64)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:64 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesAgainst (r:64 w:64)
+	/// Proof: ProofOfFace OpenDisputesAgainst (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn sweep_expired_disputes(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142 + n * (412 ±0)`
+		//  Estimated: `6196 + n * (5351 ±0)`
+		// Estimated execution time (not measured): 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 6196)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5351).saturating_mul(n.into()))
+	}
+
+	/// Storage: ProofOfFace AllowedGateways (r:0 w:1)
+	/// Proof: ProofOfFace AllowedGateways (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn add_allowed_gateway() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Estimated execution time (not measured): 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 1489)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace AllowedGateways (r:1 w:1)
+	/// Proof: ProofOfFace AllowedGateways (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn remove_allowed_gateway() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `46`
+		//  Estimated: `3546`
+		// Estimated execution time (not measured): 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3546)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeJury (r:1 w:0)
+	/// Proof: ProofOfFace DisputeJury (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ProofOfFace CreatedDisputes (r:2 w:2)
+	/// Proof: ProofOfFace CreatedDisputes (max_values: None, max_size: Some(882), added: 3357, mode: MaxEncodedLen)
+	/// Not benchmarked: needs a signature the generic benchmarks cannot produce.
+	fn reassign_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2636`
+		//  Estimated: `6136`
+		// Estimated execution time (not measured): 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 6136)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfFace BatchCommitments (r:0 w:1)
+	/// Proof: ProofOfFace BatchCommitments (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn commit_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time (not measured): 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace BatchCommitments (r:1 w:0)
+	/// Proof: ProofOfFace BatchCommitments (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	/// Storage: ProofOfFace Identities (r:1 w:1)
 	/// Proof: ProofOfFace Identities (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn vote_dispute() -> Weight {
+	/// Storage: ProofOfFace RegistrationsThisBlock (r:1 w:1)
+	/// Proof: ProofOfFace RegistrationsThisBlock (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace RegistrationDeposits (r:0 w:1)
+	/// Proof: ProofOfFace RegistrationDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace TotalIdentities (r:1 w:1)
+	/// Proof: ProofOfFace TotalIdentities (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace LastMilestone (r:1 w:1)
+	/// Proof: ProofOfFace LastMilestone (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn register_from_commitment() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `280`
-		//  Estimated: `5590`
-		// Minimum execution time: 18_000_000 picoseconds.
-		Weight::from_parts(19_000_000, 5590)
+		//  Measured:  `93`
+		//  Estimated: `3593`
+		// Estimated execution time (not measured): 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:2)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesAgainst (r:1 w:0)
+	/// Proof: ProofOfFace OpenDisputesAgainst (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesByHash (r:1 w:0)
+	/// Proof: ProofOfFace OpenDisputesByHash (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn reenroll() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3849`
+		// Estimated execution time (not measured): 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3849)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfFace Halted (r:0 w:1)
+	/// Proof: ProofOfFace Halted (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_halted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Estimated execution time (not measured): 5_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DeactivatedByDispute (r:0 w:1)
+	/// Proof: ProofOfFace DeactivatedByDispute (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesAgainst (r:1 w:1)
+	/// Proof: ProofOfFace OpenDisputesAgainst (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn force_resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2841`
+		//  Estimated: `6341`
+		// Estimated execution time (not measured): 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 6341)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace RecoveryAccounts (r:0 w:1)
+	/// Proof: ProofOfFace RecoveryAccounts (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace PendingRecoveries (r:0 w:1)
+	/// Proof: ProofOfFace PendingRecoveries (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn set_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3849`
+		// Estimated execution time (not measured): 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3849)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfFace RecoveryAccounts (r:1 w:1)
+	/// Proof: ProofOfFace RecoveryAccounts (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace PendingRecoveries (r:1 w:1)
+	/// Proof: ProofOfFace PendingRecoveries (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:2 w:2)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace LinkedToPrimary (r:1 w:3)
+	/// Proof: ProofOfFace LinkedToPrimary (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityExpiries (r:2 w:2)
+	/// Proof: ProofOfFace IdentityExpiries (max_values: None, max_size: Some(8206), added: 10681, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Handles (r:2 w:2)
+	/// Proof: ProofOfFace Handles (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace RegistrationDeposits (r:1 w:2)
+	/// Proof: ProofOfFace RegistrationDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn recover_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8171`
+		//  Estimated: `11671`
+		// Estimated execution time (not measured): 94_000_000 picoseconds.
+		Weight::from_parts(95_000_000, 11671)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(25_u64))
+	}
+
+	/// Storage: ProofOfFace DisputeVoterOrder (r:1 w:0)
+	/// Proof: ProofOfFace DisputeVoterOrder (max_values: None, max_size: Some(8214), added: 10689, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:256 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeVotes (r:256 w:0)
+	/// Proof: ProofOfFace DisputeVotes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Reputation (r:256 w:256)
+	/// Proof: ProofOfFace Reputation (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 256]`.
+	fn reward_voters(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113 + n * (301 ±0)`
+		//  Estimated: `11679 + n * (2799 ±0)`
+		// Estimated execution time (not measured): 4_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 11679)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2799).saturating_mul(n.into()))
+	}
+
+	/// Storage: ProofOfFace Delegators (r:1 w:0)
+	/// Proof: ProofOfFace Delegators (max_values: None, max_size: Some(2101), added: 4576, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeVotes (r:64 w:0)
+	/// Proof: ProofOfFace DisputeVotes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DelegatedVotes (r:64 w:64)
+	/// Proof: ProofOfFace DelegatedVotes (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 64]`.
+	fn apply_delegated_votes(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76 + n * (32 ±0)`
+		//  Estimated: `5566 + n * (5096 ±0)`
+		// Estimated execution time (not measured): 3_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 5566)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5096).saturating_mul(n.into()))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	fn freeze_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1851`
+		//  Estimated: `5351`
+		// Estimated execution time (not measured): 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 5351)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace SweepCursor (r:1 w:1)
+	/// Proof: ProofOfFace SweepCursor (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn unfreeze_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1851`
+		//  Estimated: `5351`
+		// Estimated execution time (not measured): 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 5351)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	fn set_private() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `289`
+		//  Estimated: `3789`
+		// Estimated execution time (not measured): 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3789)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DisputeJury (r:1 w:0)
+	/// Proof: ProofOfFace DisputeJury (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ProofOfFace DisputeVoterOrder (r:1 w:0)
+	/// Proof: ProofOfFace DisputeVoterOrder (max_values: None, max_size: Some(8214), added: 10689, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DeactivatedByDispute (r:0 w:1)
+	/// Proof: ProofOfFace DeactivatedByDispute (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesAgainst (r:1 w:1)
+	/// Proof: ProofOfFace OpenDisputesAgainst (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn finalize_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2841`
+		//  Estimated: `6341`
+		// Estimated execution time (not measured): 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 6341)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ProofOfFace VoteDelegates (r:1 w:1)
+	/// Proof: ProofOfFace VoteDelegates (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Delegators (r:2 w:2)
+	/// Proof: ProofOfFace Delegators (max_values: None, max_size: Some(2101), added: 4576, mode: MaxEncodedLen)
+	fn delegate_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6642`
+		//  Estimated: `10142`
+		// Estimated execution time (not measured): 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 10142)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ProofOfFace VoteDelegates (r:1 w:1)
+	/// Proof: ProofOfFace VoteDelegates (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Delegators (r:1 w:1)
+	/// Proof: ProofOfFace Delegators (max_values: None, max_size: Some(2101), added: 4576, mode: MaxEncodedLen)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2066`
+		//  Estimated: `5566`
+		// Estimated execution time (not measured): 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 5566)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+
+	/// Storage: ProofOfFace BiometricHashToOwner (r:256 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:256 w:256)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace TakenDown (r:0 w:256)
+	/// Proof: ProofOfFace TakenDown (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 256]`.
+	fn force_deactivate_hashes(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (412 ±0)`
+		//  Estimated: `990 + n * (2799 ±0)`
+		// Estimated execution time (not measured): 3_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 990)
+			// Standard Error: 6_000
+			.saturating_add(Weight::from_parts(13_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2799).saturating_mul(n.into()))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityNotes (r:0 w:1)
+	/// Proof: ProofOfFace IdentityNotes (max_values: None, max_size: Some(306), added: 2781, mode: MaxEncodedLen)
+	fn set_note() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `289`
+		//  Estimated: `3789`
+		// Estimated execution time (not measured): 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3789)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:0 w:1)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityExpiries (r:1 w:1)
+	/// Proof: ProofOfFace IdentityExpiries (max_values: None, max_size: Some(334), added: 2809, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace Handles (r:1 w:1)
+	/// Proof: ProofOfFace Handles (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace HandleToAccount (r:0 w:1)
+	/// Proof: ProofOfFace HandleToAccount (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace OpenDisputesAgainst (r:0 w:1)
+	/// Proof: ProofOfFace OpenDisputesAgainst (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace VerificationNonces (r:0 w:1)
+	/// Proof: ProofOfFace VerificationNonces (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace RegistrationDeposits (r:1 w:1)
+	/// Proof: ProofOfFace RegistrationDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace TotalIdentities (r:1 w:1)
+	/// Proof: ProofOfFace TotalIdentities (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn force_unregister() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3593`
+		// Estimated execution time (not measured): 25_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+
+	/// Storage: ProofOfFace Halted (r:1 w:0)
+	/// Proof: ProofOfFace Halted (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace AttestationRoots (r:1 w:1)
+	/// Proof: ProofOfFace AttestationRoots (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn attest_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13`
+		//  Estimated: `3513`
+		// Estimated execution time (not measured): 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace Disputes (r:1 w:1)
+	/// Proof: ProofOfFace Disputes (max_values: None, max_size: Some(2876), added: 5351, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace ResolvedDisputeCount (r:1 w:1)
+	/// Proof: ProofOfFace ResolvedDisputeCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace RejectedDisputeCount (r:1 w:1)
+	/// Proof: ProofOfFace RejectedDisputeCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace BiometricHashToOwner (r:1 w:0)
+	/// Proof: ProofOfFace BiometricHashToOwner (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace DeactivatedByDispute (r:1 w:1)
+	/// Proof: ProofOfFace DeactivatedByDispute (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:1)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	fn resolve_appeal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1851`
+		//  Estimated: `5351`
+		// Estimated execution time (not measured): 23_000_000 picoseconds.
+		Weight::from_parts(24_000_000, 5351)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ProofOfFace RecoveryAccounts (r:1 w:0)
+	/// Proof: ProofOfFace RecoveryAccounts (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace IdentityProofs (r:1 w:0)
+	/// Proof: ProofOfFace IdentityProofs (max_values: None, max_size: Some(324), added: 2799, mode: MaxEncodedLen)
+	/// Storage: ProofOfFace PendingRecoveries (r:1 w:1)
+	/// Proof: ProofOfFace PendingRecoveries (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn initiate_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `349`
+		//  Estimated: `3849`
+		// Estimated execution time (not measured): 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3849)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace PendingRecoveries (r:1 w:1)
+	/// Proof: ProofOfFace PendingRecoveries (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `21`
+		//  Estimated: `3521`
+		// Estimated execution time (not measured): 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ProofOfFace TakenDown (r:1 w:1)
+	/// Proof: ProofOfFace TakenDown (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn lift_takedown() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13`
+		//  Estimated: `3513`
+		// Estimated execution time (not measured): 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	fn register_identity() -> Weight {
-		Weight::from_parts(16_000_000, 3593)
+	fn register_identity(c: u32) -> Weight {
+		Weight::from_parts(15_000_000, 3593)
+			.saturating_add(Weight::from_parts(12_000, 0).saturating_mul(c.into()))
 	}
 	fn verify_identity() -> Weight {
-		Weight::from_parts(26_000_000, 7845)
+		Weight::from_parts(33_000_000, 10644)
 	}
-	fn raise_dispute() -> Weight {
+	fn raise_dispute(c: u32) -> Weight {
 		Weight::from_parts(21_000_000, 6089)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2799).saturating_mul(c.into()))
+	}
+	fn vote_on_dispute_no_resolution() -> Weight {
+		Weight::from_parts(19_000_000, 6341)
+	}
+	fn vote_on_dispute_resolving() -> Weight {
+		Weight::from_parts(32_000_000, 6341)
+	}
+	fn refresh_identity() -> Weight {
+		Weight::from_parts(23_000_000, 22352)
+	}
+	fn batch_register(n: u32) -> Weight {
+		Weight::from_parts(9_000_000, 11671)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	fn update_evidence() -> Weight {
+		Weight::from_parts(15_000_000, 3977)
+	}
+	fn buy_verifications() -> Weight {
+		Weight::from_parts(41_000_000, 3593)
+	}
+	fn acknowledge_dispute() -> Weight {
+		Weight::from_parts(16_000_000, 3977)
+	}
+	fn verify_identity_miss() -> Weight {
+		Weight::from_parts(12_000_000, 3545)
+	}
+	fn add_evidence() -> Weight {
+		Weight::from_parts(16_000_000, 4385)
+	}
+	fn claim_migrated_identity() -> Weight {
+		Weight::from_parts(68_000_000, 11671)
+	}
+	fn set_gateway() -> Weight {
+		Weight::from_parts(16_000_000, 3849)
+	}
+	fn link_account() -> Weight {
+		Weight::from_parts(47_000_000, 6127)
+	}
+	fn unlink_account() -> Weight {
+		Weight::from_parts(19_000_000, 3561)
+	}
+	fn set_handle() -> Weight {
+		Weight::from_parts(24_000_000, 6109)
+	}
+	fn purge_identity() -> Weight {
+		Weight::from_parts(24_000_000, 3593)
+	}
+	fn report_spoof(c: u32) -> Weight {
+		Weight::from_parts(32_000_000, 3593)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2799).saturating_mul(c.into()))
+	}
+	fn add_comment() -> Weight {
+		Weight::from_parts(18_000_000, 3593)
+	}
+	fn merge_identities() -> Weight {
+		Weight::from_parts(40_000_000, 3593)
+	}
+	fn sweep_expired_disputes(n: u32) -> Weight {
+		Weight::from_parts(13_000_000, 6196)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5351).saturating_mul(n.into()))
+	}
+	fn add_allowed_gateway() -> Weight {
+		Weight::from_parts(9_000_000, 1489)
+	}
+	fn remove_allowed_gateway() -> Weight {
+		Weight::from_parts(11_000_000, 3546)
+	}
+	fn reassign_dispute() -> Weight {
+		Weight::from_parts(27_000_000, 6136)
+	}
+	fn commit_batch() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+	}
+	fn register_from_commitment() -> Weight {
+		Weight::from_parts(22_000_000, 3593)
+	}
+	fn reenroll() -> Weight {
+		Weight::from_parts(19_000_000, 3849)
+	}
+	fn set_halted() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+	}
+	fn force_resolve_dispute() -> Weight {
+		Weight::from_parts(28_000_000, 6341)
+	}
+	fn set_recovery() -> Weight {
+		Weight::from_parts(12_000_000, 3849)
+	}
+	fn recover_identity() -> Weight {
+		Weight::from_parts(95_000_000, 11671)
+	}
+	fn reward_voters(n: u32) -> Weight {
+		Weight::from_parts(5_000_000, 11679)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2799).saturating_mul(n.into()))
+	}
+	fn apply_delegated_votes(n: u32) -> Weight {
+		Weight::from_parts(4_000_000, 5566)
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5096).saturating_mul(n.into()))
+	}
+	fn freeze_dispute() -> Weight {
+		Weight::from_parts(12_000_000, 5351)
+	}
+	fn unfreeze_dispute() -> Weight {
+		Weight::from_parts(13_000_000, 5351)
+	}
+	fn set_private() -> Weight {
+		Weight::from_parts(14_000_000, 3789)
+	}
+	fn finalize_dispute() -> Weight {
+		Weight::from_parts(30_000_000, 6341)
+	}
+	fn delegate_vote() -> Weight {
+		Weight::from_parts(17_000_000, 10142)
+	}
+	fn undelegate() -> Weight {
+		Weight::from_parts(14_000_000, 5566)
+	}
+	fn force_deactivate_hashes(n: u32) -> Weight {
+		Weight::from_parts(4_000_000, 990)
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2799).saturating_mul(n.into()))
+	}
+	fn set_note() -> Weight {
+		Weight::from_parts(13_000_000, 3789)
+	}
+	fn force_unregister() -> Weight {
+		Weight::from_parts(26_000_000, 3593)
+	}
+	fn attest_batch() -> Weight {
+		Weight::from_parts(12_000_000, 3513)
+	}
+	fn resolve_appeal() -> Weight {
+		Weight::from_parts(24_000_000, 5351)
+	}
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(14_000_000, 3849)
+	}
+	fn cancel_recovery() -> Weight {
+		Weight::from_parts(10_000_000, 3521)
 	}
-	fn vote_dispute() -> Weight {
-		Weight::from_parts(19_000_000, 5590)
+	fn lift_takedown() -> Weight {
+		Weight::from_parts(12_000_000, 3513)
 	}
 }
\ No newline at end of file
@@ -29,7 +29,8 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
+		StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -37,7 +38,7 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -105,7 +106,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -276,12 +277,86 @@ impl pallet_sudo::Config for Runtime {
 }
 
 /// Configure the ProofOfFace pallet in pallets/proofofface.
+parameter_types! {
+	/// Key attesting identities registered in the ink contract. Set through `system.setStorage`
+	/// before migrations open; the all-zero default matches no real key.
+	pub storage MigrationAuthority: AccountId = AccountId::new([0u8; 32]);
+	pub const ProofOfFacePalletId: PalletId = PalletId(*b"py/pofce");
+	pub const ProofOfFaceVerificationPolicy: pallet_proofofface::VerificationPolicy =
+		pallet_proofofface::VerificationPolicy::ActiveAndPublic;
+	pub const ProofOfFaceQuorumShare: Permill = Permill::zero();
+}
+
 impl pallet_proofofface::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_proofofface::weights::SubstrateWeight<Runtime>;
 	type MaxIpfsCidLength = ConstU32<100>;
+	type MinIpfsCidLength = ConstU32<32>;
 	type MaxEvidenceUrlLength = ConstU32<256>;
+	type MaxGatewayLen = ConstU32<128>;
+	type MaxHandleLen = ConstU32<32>;
+	type MaxNoteLen = ConstU32<256>;
+	type MaxEvidenceItems = ConstU32<10>;
+	type PruneEvidenceOnResolve = ConstBool<true>;
 	type Randomness = InsecureRandomnessCollectiveFlip;
+	type DefaultIdentityTtl = ConstU32<{ 365 * DAYS }>;
+	type MaxExpiriesPerBlock = ConstU32<256>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxImportBatch = ConstU32<64>;
+	type MaxTakedownBatch = ConstU32<256>;
+	type MaxQueryBatch = ConstU32<100>;
+	type MaxReputation = ConstU32<1_000>;
+	type ReputationThreshold = ConstU32<100>;
+	type OnReputationThreshold = ();
+	type HonestVoterReward = ConstU32<5>;
+	type DishonestVoterPenalty = ConstU32<2>;
+	type HashValidator = pallet_proofofface::AlwaysValid;
+	type RegistrationGate = pallet_proofofface::AllowAll;
+	type TieResolvesInFavor = ConstBool<false>;
+	type MaxCreatedDisputes = ConstU32<100>;
+	type MinDisputeAge = ConstU32<{ 10 * MINUTES }>;
+	type JurySize = ConstU32<0>;
+	type MaxJuryCandidates = ConstU32<1_000>;
+	type Currency = Balances;
+	type PricePerVerification = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type RegistrationDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type FreeVerificationTier = ConstBool<true>;
+	type SummarizeBatchVerifications = ConstBool<true>;
+	type SkipSelfVerificationEvents = ConstBool<true>;
+	type VerificationPolicy = ProofOfFaceVerificationPolicy;
+	type VerifierOrigin = frame_system::EnsureSigned<AccountId>;
+	type RequireVerificationBeforeDispute = ConstBool<false>;
+	type VerificationValidity = ConstU32<HOURS>;
+	type MigrationSignature = Signature;
+	type MigrationPublic = <Signature as Verify>::Signer;
+	type LinkSignature = Signature;
+	type LinkPublic = <Signature as Verify>::Signer;
+	type ReassignSignature = Signature;
+	type ReassignPublic = <Signature as Verify>::Signer;
+	type MaxLinkedAccounts = ConstU32<8>;
+	type MigrationAuthority = MigrationAuthority;
+	type MaxRegistrationsPerBlock = ConstU32<50>;
+	type MaxFailedVerifications = ConstU32<5>;
+	type FailedVerificationWindow = ConstU32<HOURS>;
+	type DeactivateFlaggedIdentities = ConstBool<false>;
+	type ResolutionDelay = ConstU32<HOURS>;
+	type MaxScheduledResolutions = ConstU32<256>;
+	type MaxResolutionsPerBlock = ConstU32<64>;
+	type MilestoneInterval = ConstU32<1_000>;
+	type EnforceGatewayAllowlist = ConstBool<false>;
+	type MaxCommentLen = ConstU32<512>;
+	type MaxCommentsPerDispute = ConstU32<100>;
+	type MaxVotersPerDispute = ConstU32<256>;
+	type MaxDelegators = ConstU32<64>;
+	type MinDistinctVoters = ConstU32<10>;
+	type QuorumShare = ProofOfFaceQuorumShare;
+	type RejectDisputedHashes = ConstBool<true>;
+	type DisputeVotingPeriod = ConstU32<{ 7 * DAYS }>;
+	type VotingWindow = ConstU32<{ 5 * DAYS }>;
+	type RecoveryDelay = ConstU32<{ 30 * DAYS }>;
+	type MaxSweepPerCall = ConstU32<64>;
+	type SweepReward = ConstU128<EXISTENTIAL_DEPOSIT>;
+	type PalletId = ProofOfFacePalletId;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -327,6 +402,9 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (pallet_proofofface::migrations::All<Runtime>,);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -334,6 +412,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -496,6 +575,185 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl
+		pallet_proofofface_runtime_api::ProofOfFaceApi<
+			Block,
+			AccountId,
+			BlockNumber,
+			Balance,
+			Hash,
+			pallet_proofofface::Dispute<Runtime>,
+			pallet_proofofface::BiometricProofOf<Runtime>,
+			pallet_proofofface::ConstantsOf<Runtime>,
+		> for Runtime
+	{
+		fn config_constants() -> pallet_proofofface::ConstantsOf<Runtime> {
+			ProofOfFace::config_constants()
+		}
+
+		fn can_register(
+			account: AccountId,
+			biometric_hash: Hash,
+		) -> pallet_proofofface::RegisterEligibility {
+			ProofOfFace::can_register(&account, &biometric_hash)
+		}
+
+		fn register_fee_estimate(cid_len: u32) -> Balance {
+			use codec::Encode;
+			use frame_support::{dispatch::GetDispatchInfo, traits::Get, BoundedVec};
+
+			// Longer CIDs are rejected anyway, and the length comes straight from the caller
+			let max_cid_len = <Runtime as pallet_proofofface::Config>::MaxIpfsCidLength::get();
+			let cid_len = cid_len.min(max_cid_len);
+			let call = RuntimeCall::ProofOfFace(pallet_proofofface::Call::register_identity {
+				biometric_hash: Hash::default(),
+				ipfs_cid: BoundedVec::truncate_from(sp_std::vec![0; cid_len as usize]),
+			});
+			let info = call.get_dispatch_info();
+
+			// The length fee is charged on the whole signed extrinsic. Nonce and tip are encoded
+			// compactly, so a nonzero nonce makes the real extrinsic a few bytes longer.
+			let extra: SignedExtra = (
+				frame_system::CheckNonZeroSender::<Runtime>::new(),
+				frame_system::CheckSpecVersion::<Runtime>::new(),
+				frame_system::CheckTxVersion::<Runtime>::new(),
+				frame_system::CheckGenesis::<Runtime>::new(),
+				frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(256, 0)),
+				frame_system::CheckNonce::<Runtime>::from(0),
+				frame_system::CheckWeight::<Runtime>::new(),
+				pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+			);
+			let extrinsic = UncheckedExtrinsic::new_signed(
+				call,
+				Address::Id(AccountId::new([0; 32])),
+				Signature::Sr25519(sp_core::sr25519::Signature::from_raw([0; 64])),
+				extra,
+			);
+			let len = extrinsic.encoded_size() as u32;
+			TransactionPayment::compute_fee(len, &info, 0)
+		}
+
+		fn dry_run_verify(biometric_hash: Hash) -> bool {
+			ProofOfFace::dry_run_verify(&biometric_hash)
+		}
+
+		fn hash_is_active(biometric_hash: Hash) -> bool {
+			ProofOfFace::hash_is_active(&biometric_hash)
+		}
+
+		fn active_status_of(hashes: Vec<Hash>) -> Vec<(Hash, bool)> {
+			ProofOfFace::active_status_of(hashes)
+		}
+
+		fn hash_owner_and_status(biometric_hash: Hash) -> (Option<AccountId>, bool) {
+			ProofOfFace::hash_owner_and_status(&biometric_hash)
+		}
+
+		fn verify_with_handle(biometric_hash: Hash) -> Option<(AccountId, Option<Vec<u8>>)> {
+			ProofOfFace::verify_with_handle(&biometric_hash)
+				.map(|(owner, handle)| (owner, handle.map(|handle| handle.into_inner())))
+		}
+
+		fn verifications_in_block() -> Vec<(Hash, bool)> {
+			System::read_events_no_consensus()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::ProofOfFace(pallet_proofofface::Event::VerificationPerformed(
+						_,
+						biometric_hash,
+						result,
+						_,
+					)) => Some((biometric_hash, result)),
+					_ => None,
+				})
+				.collect()
+		}
+
+		fn events_in_block() -> Vec<(Vec<u8>, Vec<u8>)> {
+			use codec::Encode;
+
+			System::read_events_no_consensus()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::ProofOfFace(event) => {
+						let name = event.variant_name().unwrap_or_default();
+						Some((name.as_bytes().to_vec(), event.encode()))
+					},
+					_ => None,
+				})
+				.collect()
+		}
+
+		fn identity_of(
+			account: AccountId,
+		) -> Option<pallet_proofofface::BiometricProofOf<Runtime>> {
+			ProofOfFace::identity_proofs(account)
+		}
+
+		fn identities_of(
+			accounts: Vec<AccountId>,
+		) -> Vec<(AccountId, Option<pallet_proofofface::BiometricProofOf<Runtime>>)> {
+			ProofOfFace::identities_of(accounts)
+		}
+
+		fn registered_at(account: AccountId) -> Option<BlockNumber> {
+			ProofOfFace::registered_at(&account)
+		}
+
+		fn ipfs_cid_of(account: AccountId) -> Option<Vec<u8>> {
+			ProofOfFace::ipfs_cid_of(&account)
+		}
+
+		fn disputes_created_by(account: AccountId) -> Vec<u64> {
+			ProofOfFace::disputes_created_by(&account)
+		}
+
+		fn creator_dispute_counts(accounts: Vec<AccountId>) -> Vec<(AccountId, u32)> {
+			ProofOfFace::creator_dispute_counts(accounts)
+		}
+
+		fn disputes_by_status(
+			status: pallet_proofofface::DisputeStatus,
+			start: u64,
+			limit: u32,
+		) -> Vec<(u64, pallet_proofofface::Dispute<Runtime>)> {
+			ProofOfFace::disputes_by_status(status, start, limit)
+		}
+
+		fn dispute_lean(dispute_id: u64) -> Option<(i64, bool)> {
+			ProofOfFace::dispute_lean(dispute_id)
+		}
+
+		fn open_disputes_against(account: AccountId) -> u32 {
+			ProofOfFace::open_disputes_against(&account)
+		}
+
+		fn dispute_status_counts() -> (u32, u32, u32) {
+			ProofOfFace::dispute_status_counts()
+		}
+
+		fn linked_accounts(primary: AccountId) -> Vec<AccountId> {
+			ProofOfFace::linked_accounts(&primary)
+		}
+
+		fn dispute_comments(
+			dispute_id: u64,
+			start: u32,
+			limit: u32,
+		) -> Vec<(u32, AccountId, Vec<u8>)> {
+			ProofOfFace::dispute_comments(dispute_id, start, limit)
+				.into_iter()
+				.map(|(index, author, text)| (index, author, text.into_inner()))
+				.collect()
+		}
+
+		fn dispute_voters(
+			dispute_id: u64,
+			start: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, bool)> {
+			ProofOfFace::dispute_voters(dispute_id, start, limit)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (